@@ -13,8 +13,7 @@ fn main() {
     // Example 1: Simple daily task
     println!("1. Simple Daily Task:");
     let daily = Periodicity::daily().unwrap();
-    println!("   Created: {:?}", daily.rep_unit);
-    println!("   Occurrences per unit: {:?}\n", daily.rep_per_unit);
+    println!("   Schedule: {}\n", daily);
     
     // Example 2: The user's example - 13th and 24th of Jan & Feb
     println!("2. User's Example - 13th and 24th of January and February:");
@@ -25,6 +24,8 @@ fn main() {
         .build()
         .unwrap();
     
+    println!("   Schedule: {}", user_example);
+    
     // Test some dates
     let jan_13 = Utc.with_ymd_and_hms(2026, 1, 13, 10, 0, 0).unwrap();
     let jan_24 = Utc.with_ymd_and_hms(2026, 1, 24, 10, 0, 0).unwrap();
@@ -85,17 +86,14 @@ fn main() {
         .build()
         .unwrap();
     
-    println!("   Repetitions per day: {:?}", complex.rep_per_unit);
-    println!("   Has day constraint: {}", complex.constraints.day_constraint.is_some());
-    println!("   Has month constraint: {}\n", complex.constraints.month_constraint.is_some());
+    println!("   Schedule: {}\n", complex);
     
     // Example 7: One-time task
     println!("7. One-Time Task (Christmas 2026):");
     let christmas = Utc.with_ymd_and_hms(2026, 12, 25, 0, 0, 0).unwrap();
     let unique = Periodicity::unique(christmas).unwrap();
     
-    println!("   Repetition unit: {:?}", unique.rep_unit);
-    println!("   Has special pattern: {}\n", unique.special_pattern.is_some());
+    println!("   Schedule: {}\n", unique);
     
     // Example 8: With timeframe
     println!("8. Daily Task with Timeframe (Feb 2026 only):");
@@ -108,11 +106,41 @@ fn main() {
         .build()
         .unwrap();
     
+    println!("   Schedule: {}", timeframe_task);
+    
     let in_feb = Utc.with_ymd_and_hms(2026, 2, 15, 10, 0, 0).unwrap();
     let in_march = Utc.with_ymd_and_hms(2026, 3, 15, 10, 0, 0).unwrap();
     
     println!("   Feb 15 in timeframe: {}", timeframe_task.is_within_timeframe(&in_feb));
     println!("   Mar 15 in timeframe: {}\n", timeframe_task.is_within_timeframe(&in_march));
     
+    // Example 9: Typed as a phrase
+    println!("9. Typed: \"every other Tuesday and Thursday in March\"");
+    let typed: Periodicity = "every other Tuesday and Thursday in March".parse().unwrap();
+    println!("   Schedule: {}", typed);
+    match "the 2nd and 4th Tusday".parse::<Periodicity>() {
+        Ok(_) => println!("   (parsed)\n"),
+        Err(error) => println!("   Typo: {}\n", error),
+    }
+    
+    // Example 10: Alternatives
+    println!("10. The 1st and 15th, or the Last Friday:");
+    let payroll = PeriodicityBuilder::new()
+        .daily(1)
+        .on_month_days(vec![1, 15])
+        .or()
+        .on_nth_weekdays(vec![NthWeekdayOfMonth::last(Weekday::Fri)])
+        .build()
+        .unwrap();
+    
+    println!("   Schedule: {}", payroll);
+    let may = payroll.occurrences_between(
+        Utc.with_ymd_and_hms(2026, 5, 1, 0, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap(),
+        Weekday::Mon,
+    );
+    let days: Vec<_> = may.map(|date| date.format("%-d").to_string()).collect();
+    println!("   In May 2026: {}\n", days.join(", "));
+    
     println!("=== All examples completed successfully! ===");
 }
@@ -0,0 +1,183 @@
+use std::fmt;
+use rusqlite::ErrorCode;
+use crate::domain::entities::user::{Password, PasswordError, PasswordHash, Timezone, User, UserValidationError};
+use crate::infrastructure::sqlite::{StoreError, UserId, UserRepository};
+
+// ========================================================================
+// ACCOUNTS
+// Registration and login over the user repository
+// ========================================================================
+
+/// Creates an account, storing only the argon2 hash of `password`
+///
+/// The new user gets the default week start, year start and day start.
+pub fn register(
+    users: &UserRepository,
+    username: String,
+    email: String,
+    password: &Password,
+    timezone: Timezone,
+) -> Result<(UserId, User), AccountError> {
+    // Validated before the (slow) hashing
+    let mut user = User::new(username, email, PasswordHash::locked(), timezone)?;
+    if users.find_by_username(user.username())?.is_some() {
+        return Err(AccountError::UsernameTaken(user.username().to_string()));
+    }
+    user.set_password_hash(password.hash()?);
+
+    match users.create(&user) {
+        Ok(id) => Ok((id, user)),
+        // Taken between the lookup and the insert
+        Err(StoreError::Sqlite(error)) if error.sqlite_error_code() == Some(ErrorCode::ConstraintViolation) => {
+            Err(AccountError::UsernameTaken(user.username().to_string()))
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Returns the user if `password` matches their stored hash
+///
+/// Unknown usernames and wrong passwords fail the same way, and take the same
+/// time: an unknown username is checked against `PasswordHash::locked()`.
+pub fn authenticate(
+    users: &UserRepository,
+    username: &str,
+    password: &Password,
+) -> Result<(UserId, User), AccountError> {
+    match users.find_by_username(username)? {
+        Some((id, user)) if user.password_hash().verify(password) => Ok((id, user)),
+        Some(_) => Err(AccountError::InvalidCredentials),
+        None => {
+            PasswordHash::locked().verify(password);
+            Err(AccountError::InvalidCredentials)
+        }
+    }
+}
+
+// ========================================================================
+// ACCOUNT ERRORS
+// ========================================================================
+
+#[derive(Debug)]
+pub enum AccountError {
+    /// Another account already has this username
+    UsernameTaken(String),
+
+    /// Unknown username or wrong password (deliberately not told apart)
+    InvalidCredentials,
+
+    /// Username or email rejected by the domain rules
+    User(UserValidationError),
+
+    Password(PasswordError),
+
+    Store(StoreError),
+}
+
+impl fmt::Display for AccountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountError::UsernameTaken(username) => write!(f, "Username '{}' is already taken", username),
+            AccountError::InvalidCredentials => write!(f, "Invalid username or password"),
+            AccountError::User(error) => write!(f, "{}", error),
+            AccountError::Password(error) => write!(f, "{}", error),
+            AccountError::Store(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for AccountError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AccountError::User(error) => Some(error),
+            AccountError::Password(error) => Some(error),
+            AccountError::Store(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<UserValidationError> for AccountError {
+    fn from(error: UserValidationError) -> Self {
+        AccountError::User(error)
+    }
+}
+
+impl From<PasswordError> for AccountError {
+    fn from(error: PasswordError) -> Self {
+        AccountError::Password(error)
+    }
+}
+
+impl From<StoreError> for AccountError {
+    fn from(error: StoreError) -> Self {
+        AccountError::Store(error)
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::sqlite::open_in_memory;
+
+    fn paris() -> Timezone {
+        Timezone::new("Europe/Paris".to_string()).unwrap()
+    }
+
+    fn password(text: &str) -> Password {
+        Password::attempt(text.to_string())
+    }
+
+    /// Stores "bob", whose account has no usable password
+    fn add_locked_bob(users: &UserRepository) {
+        let bob = User::new("bob".to_string(), "bob@example.com".to_string(), PasswordHash::locked(), paris()).unwrap();
+        users.create(&bob).unwrap();
+    }
+
+    #[test]
+    fn test_register_then_authenticate() {
+        let conn = open_in_memory().unwrap();
+        let users = UserRepository::new(&conn);
+        let secret = Password::new("correct horse battery staple".to_string()).unwrap();
+
+        let (id, user) = register(&users, "alice".to_string(), "alice@example.com".to_string(), &secret, paris())
+            .unwrap();
+        assert!(user.password_hash().as_str().starts_with("$argon2id$"));
+
+        let (found_id, found) = authenticate(&users, "alice", &secret).unwrap();
+        assert_eq!(found_id, id);
+        assert_eq!(found.email(), "alice@example.com");
+
+        assert!(matches!(
+            authenticate(&users, "alice", &password("Correct horse battery staple")),
+            Err(AccountError::InvalidCredentials)
+        ));
+    }
+
+    #[test]
+    fn test_unknown_user_and_locked_account() {
+        let conn = open_in_memory().unwrap();
+        let users = UserRepository::new(&conn);
+        add_locked_bob(&users);
+
+        assert!(matches!(authenticate(&users, "carol", &password("anything")), Err(AccountError::InvalidCredentials)));
+        assert!(matches!(authenticate(&users, "bob", &password("")), Err(AccountError::InvalidCredentials)));
+    }
+
+    #[test]
+    fn test_username_taken_or_invalid() {
+        let conn = open_in_memory().unwrap();
+        let users = UserRepository::new(&conn);
+        add_locked_bob(&users);
+
+        let result = register(&users, "bob".to_string(), "other@example.com".to_string(), &password("whatever"), paris());
+        assert!(matches!(result, Err(AccountError::UsernameTaken(name)) if name == "bob"));
+
+        let result = register(&users, "bob smith".to_string(), "bob@example.com".to_string(), &password("whatever"), paris());
+        assert!(matches!(result, Err(AccountError::User(UserValidationError::InvalidUsernameCharacter { .. }))));
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use chrono::{Days, NaiveDate, Utc};
+use tsadaash::domain::entities::task::task_occurrence::OccurrenceStatus;
+use tsadaash::domain::entities::user::User;
+use tsadaash::domain::services::{build_agenda_with_history, AgendaEntry};
+use tsadaash::infrastructure::sqlite::{TaskId, TaskOccurrenceRepository, TaskRepository};
+use super::prompts;
+use super::session::Session;
+use super::CliError;
+
+// ========================================================================
+// AGENDA COMMANDS
+// ========================================================================
+
+/// Length of `agenda` without `--to`
+const DEFAULT_AGENDA_DAYS: u64 = 7;
+
+/// What's due on the user's current logical day
+pub fn today(session: &Session) -> Result<(), CliError> {
+    let today = session.user.logical_date(&Utc::now());
+    show(session, today, today)
+}
+
+/// What's due from `from` (default: today) to `to` (default: a week), both included
+pub fn agenda(session: &Session, from: Option<NaiveDate>, to: Option<NaiveDate>) -> Result<(), CliError> {
+    let first = from.unwrap_or_else(|| session.user.logical_date(&Utc::now()));
+    let last = match to {
+        Some(last) => last,
+        None => first.checked_add_days(Days::new(DEFAULT_AGENDA_DAYS - 1)).unwrap_or(first),
+    };
+    if last < first {
+        return Err(CliError::Usage(format!("--to {} is before the first day {}", last, first)));
+    }
+    show(session, first, last)
+}
+
+/// Prints the occurrences of the active tasks due on the logical days `first..=last`
+///
+/// Occurrences already stored (with their completions) replace the generated ones.
+/// Completion-relative tasks are scheduled from their stored completions.
+fn show(session: &Session, first: NaiveDate, last: NaiveDate) -> Result<(), CliError> {
+    let user = &session.user;
+    let from = user.day_start_instant(first);
+    let to = user.day_start_instant(last.checked_add_days(Days::new(1)).unwrap_or(last));
+
+    let tasks = TaskRepository::new(&session.conn).list_active_between(session.user_id, from, to)?;
+    let stored = TaskOccurrenceRepository::new(&session.conn);
+    let mut history = HashMap::new();
+    for (id, task) in &tasks {
+        if task.periodicity().after_completion.is_some() {
+            let occurrences = stored.list_for_task(*id)?.into_iter().map(|(_, occurrence)| occurrence).collect::<Vec<_>>();
+            history.insert(*id, occurrences);
+        }
+    }
+    let mut entries = build_agenda_with_history(&tasks, user, from, to, |id| {
+        history.get(id).map(Vec::as_slice).unwrap_or(&[])
+    })?;
+
+    for entry in &mut entries {
+        if let Some((_, occurrence)) = stored.find_by_window(entry.key, entry.occurrence.window_start())? {
+            entry.occurrence = occurrence;
+        }
+    }
+
+    if entries.is_empty() {
+        println!("Nothing due.");
+        return Ok(());
+    }
+    let mut day = None;
+    for entry in &entries {
+        if day != Some(entry.day) {
+            day = Some(entry.day);
+            println!("{}", entry.day.format("%A %-d %B %Y"));
+        }
+        println!("  {}", entry_line(entry, user));
+    }
+    Ok(())
+}
+
+/// "#2  Medication (urgent)  from 08:00  [x] [ ]  in progress 50%  OVERDUE"
+fn entry_line(entry: &AgendaEntry<TaskId>, user: &User) -> String {
+    let occurrence = &entry.occurrence;
+    let mut line = format!(
+        "#{:<3} {} ({})",
+        entry.key.0,
+        entry.task.title(),
+        prompts::priority_label(entry.task.priority())
+    );
+
+    if let Some(time) = entry.not_before() {
+        line.push_str(&format!("  from {}", time.format("%H:%M")));
+    }
+    let last_day = user.logical_date(&occurrence.window_end());
+    if last_day != entry.day {
+        line.push_str(&format!("  by {}", last_day.format("%a %-d %b")));
+    }
+    if let Some(nominal) = occurrence.nominal_date() {
+        line.push_str(&format!("  moved from {}", nominal.format("%a %-d %b")));
+    }
+
+    let reps: Vec<_> = occurrence
+        .repetitions()
+        .iter()
+        .map(|rep| if rep.is_completed() { "[x]" } else { "[ ]" })
+        .collect();
+    line.push_str(&format!(
+        "  {}  {} {:.0}%",
+        reps.join(" "),
+        status_label(occurrence.status()),
+        occurrence.progress() * 100.0
+    ));
+
+    if occurrence.is_overdue() {
+        line.push_str("  OVERDUE");
+    }
+    line
+}
+
+fn status_label(status: OccurrenceStatus) -> &'static str {
+    match status {
+        OccurrenceStatus::NotStarted => "not started",
+        OccurrenceStatus::InProgress => "in progress",
+        OccurrenceStatus::Completed => "done",
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveTime, TimeZone};
+    use tsadaash::domain::builders::PeriodicityBuilder;
+    use tsadaash::domain::entities::task::periodicity::OccurrenceTimingSettings;
+    use tsadaash::domain::entities::task::{Periodicity, RollPolicy, Task, TaskPriority};
+    use tsadaash::domain::entities::user::{PasswordHash, Timezone};
+    use tsadaash::domain::services::build_agenda;
+
+    fn user() -> User {
+        User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new("Etc/UTC".to_string()).unwrap(),
+        ).unwrap()
+    }
+
+    fn task(title: &str, periodicity: Periodicity) -> Task {
+        let created = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        Task::with_timestamps(title.to_string(), periodicity, created, created).unwrap()
+    }
+
+    fn day(d: u32) -> chrono::DateTime<Utc> {
+        Utc.with_ymd_and_hms(2020, 3, d, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_entry_line_shows_reps_progress_and_overdue() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(2)
+            .with_occurrence_settings(OccurrenceTimingSettings {
+                duration: None,
+                not_before: NaiveTime::from_hms_opt(8, 0, 0),
+                best_before: None,
+                rep_timing_settings: None,
+            })
+            .build()
+            .unwrap();
+        let mut task = task("Medication", periodicity);
+        task.set_priority(TaskPriority::Urgent);
+        let tasks = vec![(TaskId(2), task)];
+
+        let mut entries = build_agenda(&tasks, &user(), day(2), day(3)).unwrap();
+        entries[0].occurrence.mark_rep_complete(0).unwrap();
+
+        // 2020 is long gone: not completed means overdue
+        assert_eq!(
+            entry_line(&entries[0], &user()),
+            "#2   Medication (urgent)  from 08:00  [x] [ ]  in progress 50%  OVERDUE"
+        );
+
+        entries[0].occurrence.mark_all_complete();
+        assert_eq!(
+            entry_line(&entries[0], &user()),
+            "#2   Medication (urgent)  from 08:00  [x] [x]  done 100%"
+        );
+    }
+
+    #[test]
+    fn test_entry_line_shows_end_of_longer_windows() {
+        let tasks = vec![(TaskId(5), task("Laundry", Periodicity::weekly().unwrap()))];
+
+        // Monday March 2nd 2020: the week runs until Sunday the 8th
+        let entries = build_agenda(&tasks, &user(), day(2), day(3)).unwrap();
+        assert_eq!(
+            entry_line(&entries[0], &user()),
+            "#5   Laundry (medium)  by Sun 8 Mar  [ ]  not started 0%  OVERDUE"
+        );
+    }
+
+    #[test]
+    fn test_entry_line_shows_moved_occurrences() {
+        let rent = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1])
+            .with_roll_policy(RollPolicy::PreviousBusinessDay)
+            .build()
+            .unwrap();
+        let tasks = vec![(TaskId(7), task("Rent", rent))];
+
+        // Sunday March 1st 2020 moves to Friday February 28th
+        let friday = Utc.with_ymd_and_hms(2020, 2, 28, 0, 0, 0).unwrap();
+        let entries = build_agenda(&tasks, &user(), friday, day(3)).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entry_line(&entries[0], &user()),
+            "#7   Rent (medium)  moved from Sun 1 Mar  [ ]  not started 0%  OVERDUE"
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use std::path::PathBuf;
+use chrono::NaiveDate;
+use tsadaash::infrastructure::sqlite::TaskId;
+use super::CliError;
+
+// ========================================================================
+// COMMAND LINE
+// ========================================================================
+
+pub const USAGE: &str = "\
+Usage: tsadaash [--db <path>] [--user <name>] [<command>]
+
+Commands:
+  (none)             Interactive menu
+  today              What's due today, with rep progress
+  agenda [--from <date>] [--to <date>]
+                     What's due from --from (default today) to --to (default a week), YYYY-MM-DD
+  create             Create a task (schedule, then title and description in your editor)
+  list [--all]       List tasks (archived ones only with --all)
+  edit [<id>]        Edit a task's title and description
+  pause [<id>]       Pause a task (no occurrences until resumed)
+  resume [<id>]      Resume a paused task
+  archive [<id>]     Archive a task
+  export [--format ics] [--output <path>]
+                     Write the active tasks and their completions to a calendar file
+                     (default: tsadaash.ics)
+  import <path>      Create tasks from the VTODOs and VEVENTs of a calendar file,
+                     reporting the components it skips
+  why [<id>] [<date>]
+                     Why a task is or isn't due on a day (YYYY-MM-DD): each rule
+                     of its schedule, passed or failed, with the values computed
+  help               Show this message
+
+Options:
+  --db <path>        Database file (default: $TSADAASH_DB, then ~/.tsadaash.db)
+  --user <name>      Account to log into, or to sign up if it doesn't exist
+                     (default: $TSADAASH_USER, or ask)
+
+Holidays the schedule wizard offers to skip are read from $TSADAASH_HOLIDAYS,
+else ~/.tsadaash-holidays: a [name] line per calendar, then one YYYY-MM-DD per line.
+
+Commands taking an <id> ask which task when it is omitted.";
+
+/// What to run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Menu,
+    Help,
+    Today,
+    Agenda { from: Option<NaiveDate>, to: Option<NaiveDate> },
+    Create,
+    List { all: bool },
+    Edit(Option<TaskId>),
+    Pause(Option<TaskId>),
+    Resume(Option<TaskId>),
+    Archive(Option<TaskId>),
+    Export { format: ExportFormat, output: Option<PathBuf> },
+    Import(PathBuf),
+    Why { task: Option<TaskId>, date: Option<NaiveDate> },
+}
+
+/// File formats `export` writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// iCalendar (RFC 5545): one VTODO per task
+    Ics,
+}
+
+/// A parsed command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    pub db_path: Option<PathBuf>,
+    pub username: Option<String>,
+    pub command: Command,
+}
+
+/// Parses the arguments that follow the program name
+///
+/// Global options may appear anywhere; the first other argument names the command.
+pub fn parse(args: Vec<String>) -> Result<Invocation, CliError> {
+    let mut db_path = None;
+    let mut username = None;
+    let mut words = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--db" => db_path = Some(PathBuf::from(option_value(&mut args, "--db")?)),
+            "--user" => username = Some(option_value(&mut args, "--user")?),
+            "-h" | "--help" => words.insert(0, "help".to_string()),
+            _ => words.push(arg),
+        }
+    }
+
+    let mut words = words.into_iter();
+    let command = match words.next().as_deref() {
+        None => Command::Menu,
+        Some("help") => Command::Help,
+        Some("today") => Command::Today,
+        Some("agenda") => agenda(&mut words)?,
+        Some("create") => Command::Create,
+        Some("list") => {
+            let all = match words.next().as_deref() {
+                None => false,
+                Some("--all") => true,
+                Some(other) => return Err(unexpected(other)),
+            };
+            Command::List { all }
+        }
+        Some("edit") => Command::Edit(task_id(words.next())?),
+        Some("pause") => Command::Pause(task_id(words.next())?),
+        Some("resume") => Command::Resume(task_id(words.next())?),
+        Some("archive") => Command::Archive(task_id(words.next())?),
+        Some("export") => export(&mut words)?,
+        Some("import") => match words.next() {
+            Some(path) => Command::Import(PathBuf::from(path)),
+            None => return Err(CliError::Usage("import needs a file".to_string())),
+        },
+        Some("why") => why(&mut words)?,
+        Some(other) => return Err(CliError::Usage(format!("Unknown command '{}'", other))),
+    };
+
+    match words.next() {
+        Some(extra) => Err(unexpected(&extra)),
+        None => Ok(Invocation { db_path, username, command }),
+    }
+}
+
+fn option_value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, CliError> {
+    args.next()
+        .filter(|value| !value.starts_with("--"))
+        .ok_or_else(|| CliError::Usage(format!("{} needs a value", option)))
+}
+
+/// `agenda [--from <date>] [--to <date>]`
+fn agenda(words: &mut impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let (mut from, mut to) = (None, None);
+    while let Some(word) = words.next() {
+        match word.as_str() {
+            "--from" => from = Some(date(&option_value(words, "--from")?)?),
+            "--to" => to = Some(date(&option_value(words, "--to")?)?),
+            other => return Err(unexpected(other)),
+        }
+    }
+    if let (Some(from), Some(to)) = (from, to) {
+        if to < from {
+            return Err(CliError::Usage(format!("--to {} is before --from {}", to, from)));
+        }
+    }
+    Ok(Command::Agenda { from, to })
+}
+
+/// `export [--format ics] [--output <path>]`
+fn export(words: &mut impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let (mut format, mut output) = (ExportFormat::Ics, None);
+    while let Some(word) = words.next() {
+        match word.as_str() {
+            "--format" => {
+                format = match option_value(words, "--format")?.as_str() {
+                    "ics" => ExportFormat::Ics,
+                    other => return Err(CliError::Usage(format!("Unknown export format '{}' (supported: ics)", other))),
+                }
+            }
+            "--output" => output = Some(PathBuf::from(option_value(words, "--output")?)),
+            other => return Err(unexpected(other)),
+        }
+    }
+    Ok(Command::Export { format, output })
+}
+
+/// `why [<id>] [<date>]`: a first argument that is a date is the date
+fn why(words: &mut impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut words = words.peekable();
+    let task = match words.peek() {
+        Some(word) if !word.contains('-') => task_id(words.next())?,
+        _ => None,
+    };
+    let date = words.next().map(|word| date(&word)).transpose()?;
+    Ok(Command::Why { task, date })
+}
+
+fn date(text: &str) -> Result<NaiveDate, CliError> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .map_err(|_| CliError::Usage(format!("'{}' is not a date (YYYY-MM-DD)", text)))
+}
+
+/// Optional task id argument ("12" or "#12")
+fn task_id(arg: Option<String>) -> Result<Option<TaskId>, CliError> {
+    arg.map(|text| {
+        text.trim_start_matches('#')
+            .parse::<i64>()
+            .map(TaskId)
+            .map_err(|_| CliError::Usage(format!("'{}' is not a task id", text)))
+    })
+    .transpose()
+}
+
+fn unexpected(arg: &str) -> CliError {
+    CliError::Usage(format!("Unexpected argument '{}'", arg))
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_line(line: &str) -> Result<Invocation, CliError> {
+        parse(line.split_whitespace().map(String::from).collect())
+    }
+
+    #[test]
+    fn test_no_arguments_opens_menu() {
+        let invocation = parse_line("").unwrap();
+        assert_eq!(invocation.command, Command::Menu);
+        assert_eq!(invocation.db_path, None);
+        assert_eq!(invocation.username, None);
+    }
+
+    #[test]
+    fn test_commands_and_task_ids() {
+        assert_eq!(parse_line("create").unwrap().command, Command::Create);
+        assert_eq!(parse_line("list").unwrap().command, Command::List { all: false });
+        assert_eq!(parse_line("list --all").unwrap().command, Command::List { all: true });
+        assert_eq!(parse_line("pause 3").unwrap().command, Command::Pause(Some(TaskId(3))));
+        assert_eq!(parse_line("resume #3").unwrap().command, Command::Resume(Some(TaskId(3))));
+        assert_eq!(parse_line("archive").unwrap().command, Command::Archive(None));
+        assert_eq!(parse_line("edit 7").unwrap().command, Command::Edit(Some(TaskId(7))));
+        assert_eq!(parse_line("--help").unwrap().command, Command::Help);
+    }
+
+    #[test]
+    fn test_today_and_agenda() {
+        let day = |d: u32| NaiveDate::from_ymd_opt(2026, 2, d);
+
+        assert_eq!(parse_line("today").unwrap().command, Command::Today);
+        assert_eq!(parse_line("agenda").unwrap().command, Command::Agenda { from: None, to: None });
+        assert_eq!(
+            parse_line("agenda --from 2026-02-07 --to 2026-02-09").unwrap().command,
+            Command::Agenda { from: day(7), to: day(9) }
+        );
+        assert_eq!(
+            parse_line("agenda --to 2026-02-09").unwrap().command,
+            Command::Agenda { from: None, to: day(9) }
+        );
+        assert!(matches!(parse_line("agenda --from 07/02/2026"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("agenda --from 2026-02-09 --to 2026-02-07"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("agenda --from"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("today 2026-02-07"), Err(CliError::Usage(_))));
+    }
+
+    #[test]
+    fn test_export() {
+        assert_eq!(parse_line("export").unwrap().command, Command::Export { format: ExportFormat::Ics, output: None });
+        assert_eq!(
+            parse_line("export --format ics --output /tmp/tasks.ics").unwrap().command,
+            Command::Export { format: ExportFormat::Ics, output: Some(PathBuf::from("/tmp/tasks.ics")) }
+        );
+        assert!(matches!(parse_line("export --format csv"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("export --output"), Err(CliError::Usage(_))));
+    }
+
+    #[test]
+    fn test_import() {
+        assert_eq!(parse_line("import work.ics").unwrap().command, Command::Import(PathBuf::from("work.ics")));
+        assert!(matches!(parse_line("import"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("import a.ics b.ics"), Err(CliError::Usage(_))));
+    }
+
+    #[test]
+    fn test_why() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 10);
+        assert_eq!(parse_line("why").unwrap().command, Command::Why { task: None, date: None });
+        assert_eq!(parse_line("why 3").unwrap().command, Command::Why { task: Some(TaskId(3)), date: None });
+        assert_eq!(parse_line("why #3 2026-03-10").unwrap().command, Command::Why { task: Some(TaskId(3)), date: day });
+        assert_eq!(parse_line("why 2026-03-10").unwrap().command, Command::Why { task: None, date: day });
+        assert!(matches!(parse_line("why 3 tomorrow"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("why 3 2026-03-10 extra"), Err(CliError::Usage(_))));
+    }
+
+    #[test]
+    fn test_global_options_anywhere() {
+        let invocation = parse_line("list --db /tmp/t.db --user alice --all").unwrap();
+        assert_eq!(invocation.command, Command::List { all: true });
+        assert_eq!(invocation.db_path, Some(PathBuf::from("/tmp/t.db")));
+        assert_eq!(invocation.username.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        assert!(matches!(parse_line("frobnicate"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("pause soon"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("pause 1 2"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("list --db"), Err(CliError::Usage(_))));
+        assert!(matches!(parse_line("--user --db x"), Err(CliError::Usage(_))));
+    }
+}
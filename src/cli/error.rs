@@ -0,0 +1,128 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use inquire::InquireError;
+use tsadaash::application::AccountError;
+use tsadaash::domain::entities::task::{TaskOccurrenceValidationError, TaskValidationError};
+use tsadaash::domain::entities::user::TimezoneError;
+use tsadaash::infrastructure::holidays::HolidayFileError;
+use tsadaash::infrastructure::ical::IcsReadError;
+use tsadaash::infrastructure::sqlite::{StoreError, TaskId};
+
+// ========================================================================
+// CLI ERRORS
+// ========================================================================
+
+#[derive(Debug)]
+pub enum CliError {
+    /// Bad command line (unknown command, missing or extra argument)
+    Usage(String),
+
+    /// The user pressed Esc or Ctrl-C at a prompt
+    Cancelled,
+
+    /// The terminal couldn't run a prompt (not a TTY, editor failed, ...)
+    Prompt(InquireError),
+
+    Store(StoreError),
+
+    Account(AccountError),
+
+    Task(TaskValidationError),
+
+    Occurrence(TaskOccurrenceValidationError),
+
+    Timezone(TimezoneError),
+
+    /// No task with this id belongs to the current user
+    UnknownTask(TaskId),
+
+    /// An output file couldn't be written
+    Write { path: PathBuf, error: io::Error },
+
+    /// An input file couldn't be read
+    Read { path: PathBuf, error: io::Error },
+
+    /// An imported file isn't an iCalendar object
+    Calendar { path: PathBuf, error: IcsReadError },
+
+    /// The holiday file couldn't be read or parsed
+    Holidays(HolidayFileError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{} (see `tsadaash help`)", message),
+            CliError::Cancelled => write!(f, "Cancelled"),
+            CliError::Prompt(error) => write!(f, "Prompt failed: {}", error),
+            CliError::Store(error) => write!(f, "{}", error),
+            CliError::Account(error) => write!(f, "{}", error),
+            CliError::Task(error) => write!(f, "{}", error),
+            CliError::Occurrence(error) => write!(f, "{}", error),
+            CliError::Timezone(error) => write!(f, "{}", error),
+            CliError::UnknownTask(id) => write!(f, "No task #{}", id),
+            CliError::Write { path, error } => write!(f, "Can't write {}: {}", path.display(), error),
+            CliError::Read { path, error } => write!(f, "Can't read {}: {}", path.display(), error),
+            CliError::Calendar { path, error } => write!(f, "{}: {}", path.display(), error),
+            CliError::Holidays(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for CliError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CliError::Prompt(error) => Some(error),
+            CliError::Store(error) => Some(error),
+            CliError::Account(error) => Some(error),
+            CliError::Task(error) => Some(error),
+            CliError::Occurrence(error) => Some(error),
+            CliError::Timezone(error) => Some(error),
+            CliError::Write { error, .. } => Some(error),
+            CliError::Read { error, .. } => Some(error),
+            CliError::Calendar { error, .. } => Some(error),
+            CliError::Holidays(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<InquireError> for CliError {
+    fn from(error: InquireError) -> Self {
+        match error {
+            InquireError::OperationCanceled | InquireError::OperationInterrupted => CliError::Cancelled,
+            other => CliError::Prompt(other),
+        }
+    }
+}
+
+impl From<StoreError> for CliError {
+    fn from(error: StoreError) -> Self {
+        CliError::Store(error)
+    }
+}
+
+impl From<AccountError> for CliError {
+    fn from(error: AccountError) -> Self {
+        CliError::Account(error)
+    }
+}
+
+impl From<TaskValidationError> for CliError {
+    fn from(error: TaskValidationError) -> Self {
+        CliError::Task(error)
+    }
+}
+
+impl From<TaskOccurrenceValidationError> for CliError {
+    fn from(error: TaskOccurrenceValidationError) -> Self {
+        CliError::Occurrence(error)
+    }
+}
+
+impl From<TimezoneError> for CliError {
+    fn from(error: TimezoneError) -> Self {
+        CliError::Timezone(error)
+    }
+}
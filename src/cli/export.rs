@@ -0,0 +1,44 @@
+use std::fs;
+use std::path::PathBuf;
+use chrono::Utc;
+use tsadaash::infrastructure::ical::{write_calendar, CalendarTask};
+use tsadaash::infrastructure::sqlite::{TaskOccurrenceRepository, TaskRepository};
+use super::session::Session;
+use super::{CliError, ExportFormat};
+
+// ========================================================================
+// EXPORT COMMAND
+// ========================================================================
+
+/// File written by `export` without `--output`
+const DEFAULT_ICS_PATH: &str = "tsadaash.ics";
+
+/// Writes the active tasks, with their stored completions, to a calendar file
+pub fn export(session: &Session, format: ExportFormat, output: Option<PathBuf>) -> Result<(), CliError> {
+    let tasks: Vec<_> = TaskRepository::new(&session.conn)
+        .list_for_user(session.user_id)?
+        .into_iter()
+        .filter(|(_, task)| task.is_active())
+        .collect();
+
+    let stored = TaskOccurrenceRepository::new(&session.conn);
+    let mut entries = Vec::with_capacity(tasks.len());
+    for (id, task) in &tasks {
+        let occurrences = stored.list_for_task(*id)?.into_iter().map(|(_, occurrence)| occurrence).collect();
+        entries.push(CalendarTask { key: *id, task, occurrences });
+    }
+
+    let (path, text, expanded) = match format {
+        ExportFormat::Ics => {
+            let calendar = write_calendar(&entries, &session.user, Utc::now());
+            (output.unwrap_or_else(|| PathBuf::from(DEFAULT_ICS_PATH)), calendar.text, calendar.expanded)
+        }
+    };
+    fs::write(&path, text).map_err(|error| CliError::Write { path: path.clone(), error })?;
+
+    println!("Exported {} task(s) to {}", entries.len(), path.display());
+    for (id, error) in &expanded {
+        println!("  #{}: written as dates for a year around today ({})", id, error);
+    }
+    Ok(())
+}
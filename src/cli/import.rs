@@ -0,0 +1,34 @@
+use std::fs;
+use std::path::Path;
+use tsadaash::infrastructure::ical::read_calendar;
+use tsadaash::infrastructure::sqlite::TaskRepository;
+use super::session::Session;
+use super::CliError;
+
+// ========================================================================
+// IMPORT COMMAND
+// ========================================================================
+
+/// Creates a task for each VTODO / VEVENT of a calendar file, then reports
+/// the components it skipped and why
+pub fn import(session: &Session, path: &Path) -> Result<(), CliError> {
+    let text = fs::read_to_string(path).map_err(|error| CliError::Read { path: path.to_path_buf(), error })?;
+    let import = read_calendar(&text, &session.user)
+        .map_err(|error| CliError::Calendar { path: path.to_path_buf(), error })?;
+
+    let repository = TaskRepository::new(&session.conn);
+    for imported in &import.tasks {
+        repository.create(session.user_id, &imported.task)?;
+    }
+
+    println!("Imported {} task(s) from {}", import.tasks.len(), path.display());
+
+    if !import.skipped.is_empty() {
+        println!("Skipped {} component(s):", import.skipped.len());
+        for skipped in &import.skipped {
+            let summary = skipped.summary.as_deref().map(|s| format!(" '{}'", s)).unwrap_or_default();
+            println!("  line {} {}{}: {}", skipped.line, skipped.kind, summary, skipped.reason);
+        }
+    }
+    Ok(())
+}
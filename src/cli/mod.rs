@@ -0,0 +1,85 @@
+pub mod error;
+pub use error::CliError;
+
+pub mod args;
+pub use args::{Command, ExportFormat, Invocation};
+
+pub mod prompts;
+pub mod session;
+pub mod periodicity_wizard;
+pub mod tasks;
+pub mod agenda;
+pub mod export;
+pub mod import;
+pub mod why;
+
+use session::Session;
+use prompts::Choice;
+
+// ========================================================================
+// ENTRY POINT
+// ========================================================================
+
+/// Runs the command line (without the program name)
+pub fn run(args: Vec<String>) -> Result<(), CliError> {
+    let Invocation { db_path, username, command } = args::parse(args)?;
+    if command == Command::Help {
+        println!("{}", args::USAGE);
+        return Ok(());
+    }
+
+    let session = Session::start(db_path, username)?;
+    match command {
+        Command::Menu => menu(&session),
+        other => execute(&session, other),
+    }
+}
+
+fn execute(session: &Session, command: Command) -> Result<(), CliError> {
+    match command {
+        Command::Menu | Command::Help => Ok(()),
+        Command::Today => agenda::today(session),
+        Command::Agenda { from, to } => agenda::agenda(session, from, to),
+        Command::Create => tasks::create(session),
+        Command::List { all } => tasks::list(session, all),
+        Command::Edit(id) => tasks::edit(session, id),
+        Command::Pause(id) => tasks::pause(session, id),
+        Command::Resume(id) => tasks::resume(session, id),
+        Command::Archive(id) => tasks::archive(session, id),
+        Command::Export { format, output } => export::export(session, format, output),
+        Command::Import(path) => import::import(session, &path),
+        Command::Why { task, date } => why::why(session, task, date),
+    }
+}
+
+/// Interactive loop over the commands until the user quits
+///
+/// Esc inside a command returns to the menu; Esc at the menu quits.
+fn menu(session: &Session) -> Result<(), CliError> {
+    println!("Hello {}!", session.user.username());
+    loop {
+        let choices = vec![
+            Choice::new("Today", Some(Command::Today)),
+            Choice::new("Agenda for the week", Some(Command::Agenda { from: None, to: None })),
+            Choice::new("List tasks", Some(Command::List { all: false })),
+            Choice::new("Why is a task due or not on a day?", Some(Command::Why { task: None, date: None })),
+            Choice::new("Create a task", Some(Command::Create)),
+            Choice::new("Edit a task", Some(Command::Edit(None))),
+            Choice::new("Pause a task", Some(Command::Pause(None))),
+            Choice::new("Resume a task", Some(Command::Resume(None))),
+            Choice::new("Archive a task", Some(Command::Archive(None))),
+            Choice::new("Export to a calendar file", Some(Command::Export { format: ExportFormat::Ics, output: None })),
+            Choice::new("Quit", None),
+        ];
+        let command = match prompts::select("What next?", choices) {
+            Ok(Some(command)) => command,
+            Ok(None) | Err(CliError::Cancelled) => return Ok(()),
+            Err(error) => return Err(error),
+        };
+        match execute(session, command) {
+            Ok(()) | Err(CliError::Cancelled) => {}
+            Err(CliError::Prompt(error)) => return Err(CliError::Prompt(error)),
+            Err(error) => println!("✗ {}", error),
+        }
+    }
+}
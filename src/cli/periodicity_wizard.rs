@@ -0,0 +1,433 @@
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc};
+use tsadaash::domain::builders::PeriodicityBuilder;
+use tsadaash::domain::entities::task::{HolidayCalendar, MonthWeekPosition, NthWeekdayOfMonth, Periodicity, PhraseError, RollPolicy};
+use tsadaash::domain::entities::task::periodicity::RepetitionUnit;
+use tsadaash::domain::entities::user::User;
+use tsadaash::domain::validators::periodicity_validator::ValidationError;
+use tsadaash::infrastructure::holidays::{load_holiday_calendars, HolidayFileError};
+use super::prompts::{self, Choice};
+use super::CliError;
+
+// ========================================================================
+// PERIODICITY WIZARD
+// Walks the user through the PeriodicityBuilder choices
+// ========================================================================
+//
+// Every combination is offered; the builder's validation decides. When it
+// rejects the answers, the reason is shown and the user can start over.
+// A valid schedule that never or rarely fires is shown with its warnings,
+// and kept only if the user confirms.
+
+/// Asks how often a task repeats and returns the validated Periodicity
+pub fn ask(user: &User) -> Result<Periodicity, CliError> {
+    loop {
+        match ask_once(user)? {
+            Ok(periodicity) => {
+                let warnings = periodicity.warnings(user.day_start_instant(user.logical_date(&Utc::now())), user.week_start());
+                if warnings.is_empty() {
+                    return Ok(periodicity);
+                }
+                for warning in &warnings {
+                    println!("⚠ {}", warning);
+                }
+                let keep = inquire::Confirm::new("Keep this schedule anyway?").with_default(false).prompt()?;
+                if keep {
+                    return Ok(periodicity);
+                }
+            }
+            Err(error) => {
+                println!("✗ {}", error);
+                let retry = inquire::Confirm::new("Start the schedule over?").with_default(true).prompt()?;
+                if !retry {
+                    return Err(CliError::Cancelled);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Repeating,
+    Once,
+    OnDates,
+    Typed,
+}
+
+fn ask_once(user: &User) -> Result<Result<Periodicity, ValidationError>, CliError> {
+    let kind = prompts::select(
+        "Schedule:",
+        vec![
+            Choice::new("Repeats", Kind::Repeating),
+            Choice::new("Once, on a date", Kind::Once),
+            Choice::new("On a list of dates", Kind::OnDates),
+            Choice::new("Type it (\"every other Tuesday\")", Kind::Typed),
+        ],
+    )?;
+
+    Ok(match kind {
+        Kind::Once => {
+            let date = prompts::date("Date:", user.week_start(), None)?;
+            PeriodicityBuilder::new().unique(user.day_start_instant(date)).build()
+        }
+        Kind::OnDates => PeriodicityBuilder::new().custom_dates(ask_dates(user)?).and_then(PeriodicityBuilder::build),
+        Kind::Repeating => ask_repeating(user)?.build(),
+        Kind::Typed => ask_phrase(user)?,
+    })
+}
+
+/// Reads a phrase like "the first Monday in January and July"
+///
+/// Text the parser doesn't understand is underlined and asked again;
+/// validation errors go back to `ask` like the other choices.
+fn ask_phrase(user: &User) -> Result<Result<Periodicity, ValidationError>, CliError> {
+    let mut text = String::new();
+    loop {
+        text = inquire::Text::new("Schedule:")
+            .with_help_message("e.g. every other Tuesday, the 13th and 24th in January, 3 times a day until 2026-06-30")
+            .with_initial_value(&text)
+            .prompt()?;
+        match Periodicity::from_phrase(&text) {
+            Ok(mut periodicity) => {
+                // "Every N ..." patterns count from the first day of the schedule, else from today
+                // (a timeframe given only by "until" opens on 1900-01-01)
+                let start = periodicity.timeframe.map(|(start, _)| start).filter(|start| start.year() > 1900);
+                periodicity.reference_date = Some(start.unwrap_or_else(|| user.day_start_instant(user.logical_date(&Utc::now()))));
+                return Ok(Ok(periodicity));
+            }
+            Err(PhraseError::Invalid(error)) => return Ok(Err(error)),
+            Err(error) => {
+                println!("✗ {}", underline(&text, &error));
+            }
+        }
+    }
+}
+
+/// The error, then the phrase with carets under the part it is about
+fn underline(text: &str, error: &PhraseError) -> String {
+    let span = error.span().unwrap_or(text.len()..text.len());
+    let offset = text[..span.start].chars().count();
+    let width = text[span].chars().count().max(1);
+    format!("{}\n  {}\n  {}{}", error, text, " ".repeat(offset), "^".repeat(width))
+}
+
+fn ask_dates(user: &User) -> Result<Vec<DateTime<Utc>>, CliError> {
+    let mut dates = Vec::new();
+    loop {
+        let date = prompts::date(&format!("Date #{}:", dates.len() + 1), user.week_start(), None)?;
+        dates.push(user.day_start_instant(date));
+        if !inquire::Confirm::new("Add another date?").with_default(true).prompt()? {
+            return Ok(dates);
+        }
+    }
+}
+
+fn ask_repeating(user: &User) -> Result<PeriodicityBuilder, CliError> {
+    let unit = prompts::select(
+        "Repeats per:",
+        vec![
+            Choice::new("day", PeriodicityBuilder::daily as fn(PeriodicityBuilder, u8) -> PeriodicityBuilder),
+            Choice::new("week", PeriodicityBuilder::weekly),
+            Choice::new("month", PeriodicityBuilder::monthly),
+            Choice::new("year", PeriodicityBuilder::yearly),
+        ],
+    )?;
+    let reps = prompts::number("How many times per period?", 1u8, 1, 100)?;
+    let mut builder = unit(PeriodicityBuilder::new(), reps);
+
+    loop {
+        builder = ask_days(builder)?;
+        builder = ask_weeks(builder)?;
+        builder = ask_months(builder)?;
+        builder = ask_years(builder)?;
+        // "The 1st and 15th, or the last Friday"
+        if !inquire::Confirm::new("Also on other days (add an alternative set of filters)?").with_default(false).prompt()? {
+            break;
+        }
+        builder = builder.or();
+    }
+    builder = ask_after_completion(builder)?;
+
+    let (builder, start) = ask_timeframe(builder, user)?;
+    let builder = ask_exceptions(builder, user)?;
+    // "Every N ..." patterns count from the first day of the schedule
+    let anchor = start.unwrap_or_else(|| user.day_start_instant(user.logical_date(&Utc::now())));
+    Ok(builder.with_reference_date(anchor))
+}
+
+fn ask_days(builder: PeriodicityBuilder) -> Result<PeriodicityBuilder, CliError> {
+    #[derive(Clone, Copy)]
+    enum DayFilter {
+        Any,
+        EveryN,
+        Weekdays,
+        MonthDays,
+        MonthDaysFromEnd,
+        NthWeekdays,
+    }
+
+    let days = prompts::select(
+        "On which days?",
+        vec![
+            Choice::new("any day", DayFilter::Any),
+            Choice::new("every N days", DayFilter::EveryN),
+            Choice::new("on weekdays...", DayFilter::Weekdays),
+            Choice::new("on days of the month...", DayFilter::MonthDays),
+            Choice::new("on days counted from the month's end...", DayFilter::MonthDaysFromEnd),
+            Choice::new("on nth weekdays of the month (e.g. 2nd Tuesday)...", DayFilter::NthWeekdays),
+        ],
+    )?;
+
+    Ok(match days {
+        DayFilter::Any => builder,
+        DayFilter::EveryN => builder.every_n_days(prompts::number("Every how many days?", 2u16, 1, 366)?),
+        DayFilter::Weekdays => builder.on_weekdays(prompts::multi_select("Weekdays:", prompts::weekday_choices())?),
+        DayFilter::MonthDays => builder.on_month_days(prompts::number_list(
+            "Days of the month:",
+            "e.g. 1, 15 or 1-7",
+            1,
+            31,
+        )?),
+        DayFilter::MonthDaysFromEnd => builder.on_month_days_from_end(prompts::number_list(
+            "Days from the end:",
+            "1 = last day, 2 = second-to-last, ...",
+            1,
+            31,
+        )?),
+        DayFilter::NthWeekdays => builder.on_nth_weekdays(ask_nth_weekdays()?),
+    })
+}
+
+fn ask_nth_weekdays() -> Result<Vec<NthWeekdayOfMonth>, CliError> {
+    let positions = prompts::multi_select(
+        "Which occurrence in the month?",
+        vec![
+            Choice::new("1st", MonthWeekPosition::FromFirst(0)),
+            Choice::new("2nd", MonthWeekPosition::FromFirst(1)),
+            Choice::new("3rd", MonthWeekPosition::FromFirst(2)),
+            Choice::new("4th", MonthWeekPosition::FromFirst(3)),
+            Choice::new("last", MonthWeekPosition::FromLast(0)),
+            Choice::new("second-to-last", MonthWeekPosition::FromLast(1)),
+        ],
+    )?;
+    let weekdays = prompts::multi_select("Of which weekdays?", prompts::weekday_choices())?;
+
+    Ok(positions
+        .iter()
+        .flat_map(|&position| weekdays.iter().map(move |&weekday| NthWeekdayOfMonth { weekday, position }))
+        .collect())
+}
+
+fn ask_weeks(builder: PeriodicityBuilder) -> Result<PeriodicityBuilder, CliError> {
+    #[derive(Clone, Copy)]
+    enum WeekFilter {
+        Any,
+        EveryN,
+        OfMonth,
+        OfMonthFromEnd,
+        OfYear,
+        Iso,
+        EvenIso,
+        OddIso,
+    }
+
+    let weeks = prompts::select(
+        "In which weeks?",
+        vec![
+            Choice::new("any week", WeekFilter::Any),
+            Choice::new("every N weeks", WeekFilter::EveryN),
+            Choice::new("in weeks of the month...", WeekFilter::OfMonth),
+            Choice::new("in weeks counted from the month's end...", WeekFilter::OfMonthFromEnd),
+            Choice::new("in weeks of the year...", WeekFilter::OfYear),
+            Choice::new("in ISO weeks...", WeekFilter::Iso),
+            Choice::new("in even ISO weeks", WeekFilter::EvenIso),
+            Choice::new("in odd ISO weeks", WeekFilter::OddIso),
+        ],
+    )?;
+
+    Ok(match weeks {
+        WeekFilter::Any => builder,
+        WeekFilter::EveryN => builder.every_n_weeks(prompts::number("Every how many weeks?", 2u8, 1, 52)?),
+        WeekFilter::OfMonth => builder.on_weeks_of_month(prompts::number_list("Weeks of the month:", "1-5", 1, 5)?),
+        WeekFilter::OfMonthFromEnd => builder.on_weeks_of_month_from_end(prompts::number_list(
+            "Weeks from the end:",
+            "1 = last week, 2 = second-to-last, ...",
+            1,
+            5,
+        )?),
+        WeekFilter::OfYear => builder.on_weeks_of_year(prompts::number_list(
+            "Weeks of the year:",
+            "1-53, week 1 has at least 4 days of the year",
+            1,
+            53,
+        )?),
+        WeekFilter::Iso => builder.on_iso_weeks(prompts::number_list("ISO weeks:", "1-53", 1, 53)?),
+        WeekFilter::EvenIso => builder.in_even_iso_weeks(),
+        WeekFilter::OddIso => builder.in_odd_iso_weeks(),
+    })
+}
+
+fn ask_months(builder: PeriodicityBuilder) -> Result<PeriodicityBuilder, CliError> {
+    #[derive(Clone, Copy)]
+    enum MonthFilter {
+        Any,
+        EveryN,
+        Specific,
+    }
+
+    let months = prompts::select(
+        "In which months?",
+        vec![
+            Choice::new("any month", MonthFilter::Any),
+            Choice::new("every N months", MonthFilter::EveryN),
+            Choice::new("in months...", MonthFilter::Specific),
+        ],
+    )?;
+
+    Ok(match months {
+        MonthFilter::Any => builder,
+        MonthFilter::EveryN => builder.every_n_months(prompts::number("Every how many months?", 2u8, 1, 12)?),
+        MonthFilter::Specific => builder.in_months(prompts::multi_select("Months:", prompts::month_choices())?),
+    })
+}
+
+fn ask_years(builder: PeriodicityBuilder) -> Result<PeriodicityBuilder, CliError> {
+    #[derive(Clone, Copy)]
+    enum YearFilter {
+        Any,
+        EveryN,
+    }
+
+    let years = prompts::select(
+        "In which years?",
+        vec![Choice::new("any year", YearFilter::Any), Choice::new("every N years", YearFilter::EveryN)],
+    )?;
+
+    Ok(match years {
+        YearFilter::Any => builder,
+        YearFilter::EveryN => builder.every_n_years(prompts::number("Every how many years?", 2u8, 1, 100)?),
+    })
+}
+
+/// "Water the plants 4 days after the last watering"
+fn ask_after_completion(builder: PeriodicityBuilder) -> Result<PeriodicityBuilder, CliError> {
+    if !inquire::Confirm::new("Count the next time from when it was last done?").with_default(false).prompt()? {
+        return Ok(builder);
+    }
+    let unit = prompts::select(
+        "Due again after some:",
+        vec![
+            Choice::new("days", RepetitionUnit::Day),
+            Choice::new("weeks", RepetitionUnit::Week),
+            Choice::new("months", RepetitionUnit::Month),
+            Choice::new("years", RepetitionUnit::Year),
+        ],
+    )?;
+    let interval = prompts::number("How many?", 1u16, 1, 1000)?;
+    Ok(builder.after_completion(interval, unit))
+}
+
+/// Returns the builder with its timeframe, and the timeframe's start if one was given
+fn ask_timeframe(
+    builder: PeriodicityBuilder,
+    user: &User,
+) -> Result<(PeriodicityBuilder, Option<DateTime<Utc>>), CliError> {
+    #[derive(Clone, Copy)]
+    enum Timeframe {
+        Always,
+        From,
+        Until,
+        Between,
+    }
+
+    let timeframe = prompts::select(
+        "For how long?",
+        vec![
+            Choice::new("no limit", Timeframe::Always),
+            Choice::new("starting on a date", Timeframe::From),
+            Choice::new("until a date", Timeframe::Until),
+            Choice::new("between two dates", Timeframe::Between),
+        ],
+    )?;
+
+    // The last day is included: the timeframe ends when the next day starts
+    let end_of = |date: NaiveDate| user.day_start_instant(date.checked_add_days(Days::new(1)).unwrap_or(date));
+
+    Ok(match timeframe {
+        Timeframe::Always => (builder, None),
+        Timeframe::From => {
+            let start = user.day_start_instant(prompts::date("First day:", user.week_start(), None)?);
+            (builder.starting_from(start), Some(start))
+        }
+        Timeframe::Until => {
+            let last = prompts::date("Last day:", user.week_start(), None)?;
+            (builder.until(end_of(last)), None)
+        }
+        Timeframe::Between => {
+            let first = prompts::date("First day:", user.week_start(), None)?;
+            let last = prompts::date("Last day:", user.week_start(), Some(first))?;
+            let start = user.day_start_instant(first);
+            (builder.between(start, end_of(last)), Some(start))
+        }
+    })
+}
+
+/// Days the schedule skips: calendars of the holiday file, whether to move
+/// occurrences off holidays and weekends instead, then specific dates
+fn ask_exceptions(mut builder: PeriodicityBuilder, user: &User) -> Result<PeriodicityBuilder, CliError> {
+    let calendars = holiday_calendars()?;
+    if !calendars.is_empty() && inquire::Confirm::new("Skip holidays?").with_default(false).prompt()? {
+        let choices = calendars
+            .into_iter()
+            .map(|calendar| Choice::new(format!("{} ({} days)", calendar.name, calendar.dates.len()), calendar))
+            .collect();
+        for calendar in prompts::multi_select("Holiday calendars:", choices)? {
+            builder = builder.skip_holidays(calendar);
+        }
+    }
+
+    if inquire::Confirm::new("Move occurrences off weekends and holidays instead of skipping them?")
+        .with_default(false)
+        .prompt()?
+    {
+        let policy = prompts::select("Move them to:", vec![
+            Choice::new("the previous business day", RollPolicy::PreviousBusinessDay),
+            Choice::new("the next business day", RollPolicy::NextBusinessDay),
+            Choice::new("the nearest business day", RollPolicy::NearestBusinessDay),
+        ])?;
+        builder = builder.with_roll_policy(policy);
+    }
+
+    if inquire::Confirm::new("Skip specific dates?").with_default(false).prompt()? {
+        loop {
+            builder = builder.except(vec![prompts::date("Date to skip:", user.week_start(), None)?]);
+            if !inquire::Confirm::new("Skip another date?").with_default(false).prompt()? {
+                break;
+            }
+        }
+    }
+    Ok(builder)
+}
+
+/// Calendars of the holiday file (none when there is no file)
+fn holiday_calendars() -> Result<Vec<HolidayCalendar>, CliError> {
+    match load_holiday_calendars(&holidays_path()) {
+        Ok(calendars) => Ok(calendars),
+        Err(HolidayFileError::Read { error, .. }) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(CliError::Holidays(error)),
+    }
+}
+
+/// `$TSADAASH_HOLIDAYS`, else `~/.tsadaash-holidays`
+fn holidays_path() -> PathBuf {
+    if let Some(path) = env::var_os("TSADAASH_HOLIDAYS") {
+        return PathBuf::from(path);
+    }
+    match env::var_os("HOME") {
+        Some(home) => PathBuf::from(home).join(".tsadaash-holidays"),
+        None => PathBuf::from("tsadaash-holidays"),
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::{Month, NaiveDate, Weekday};
+use inquire::validator::Validation;
+use inquire::{CustomType, DateSelect, Editor, MultiSelect, Select, Text};
+use tsadaash::domain::entities::task::{TaskPriority, TaskStatus};
+use super::CliError;
+
+// ========================================================================
+// PROMPT HELPERS
+// Thin wrappers over inquire shared by the commands
+// ========================================================================
+
+/// A labelled option for `select` / `multi_select`
+#[derive(Debug, Clone)]
+pub struct Choice<T> {
+    pub label: String,
+    pub value: T,
+}
+
+impl<T> Choice<T> {
+    pub fn new(label: impl Into<String>, value: T) -> Self {
+        Self { label: label.into(), value }
+    }
+}
+
+impl<T> fmt::Display for Choice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// Asks for exactly one of `choices`
+pub fn select<T>(message: &str, choices: Vec<Choice<T>>) -> Result<T, CliError> {
+    Ok(Select::new(message, choices).prompt()?.value)
+}
+
+/// Asks for one or more of `choices`
+pub fn multi_select<T>(message: &str, choices: Vec<Choice<T>>) -> Result<Vec<T>, CliError> {
+    let picked = MultiSelect::new(message, choices)
+        .with_validator(|picked: &[inquire::list_option::ListOption<&Choice<T>>]| {
+            Ok(if picked.is_empty() {
+                Validation::Invalid("Pick at least one".into())
+            } else {
+                Validation::Valid
+            })
+        })
+        .prompt()?;
+    Ok(picked.into_iter().map(|choice| choice.value).collect())
+}
+
+/// Asks for a number in `min..=max`
+pub fn number<T>(message: &str, default: T, min: T, max: T) -> Result<T, CliError>
+where
+    T: FromStr + ToString + PartialOrd + Copy + Clone + fmt::Display + 'static,
+{
+    let range = format!("Between {} and {}", min, max);
+    Ok(CustomType::<T>::new(message)
+        .with_default(default)
+        .with_error_message("Enter a whole number")
+        .with_validator(move |value: &T| {
+            Ok(if *value < min || *value > max {
+                Validation::Invalid(range.clone().into())
+            } else {
+                Validation::Valid
+            })
+        })
+        .prompt()?)
+}
+
+/// Asks for a list of numbers like "1, 15, 20-22", each in `min..=max`
+pub fn number_list(message: &str, help: &str, min: u8, max: u8) -> Result<Vec<u8>, CliError> {
+    let text = Text::new(message)
+        .with_help_message(help)
+        .with_validator(move |text: &str| {
+            Ok(match parse_number_list(text, min, max) {
+                Ok(_) => Validation::Valid,
+                Err(reason) => Validation::Invalid(reason.into()),
+            })
+        })
+        .prompt()?;
+    // Validated above
+    Ok(parse_number_list(&text, min, max).unwrap_or_default())
+}
+
+/// Asks for a calendar date, weeks laid out from `week_start`
+pub fn date(message: &str, week_start: Weekday, min: Option<NaiveDate>) -> Result<NaiveDate, CliError> {
+    let mut prompt = DateSelect::new(message).with_week_start(week_start);
+    if let Some(min) = min {
+        prompt = prompt.with_min_date(min).with_starting_date(min);
+    }
+    Ok(prompt.prompt()?)
+}
+
+pub fn weekday_choices() -> Vec<Choice<Weekday>> {
+    [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun]
+        .into_iter()
+        .map(|day| Choice::new(weekday_name(day), day))
+        .collect()
+}
+
+pub fn month_choices() -> Vec<Choice<Month>> {
+    (1..=12u8)
+        .filter_map(|n| Month::try_from(n).ok())
+        .map(|month| Choice::new(month.name(), month))
+        .collect()
+}
+
+pub fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+pub fn status_label(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Active => "active",
+        TaskStatus::Paused => "paused",
+        TaskStatus::Archived => "archived",
+    }
+}
+
+pub fn priority_label(priority: TaskPriority) -> &'static str {
+    match priority {
+        TaskPriority::Low => "low",
+        TaskPriority::Medium => "medium",
+        TaskPriority::High => "high",
+        TaskPriority::Urgent => "urgent",
+    }
+}
+
+pub fn priority(default: TaskPriority) -> Result<TaskPriority, CliError> {
+    let priorities = [TaskPriority::Low, TaskPriority::Medium, TaskPriority::High, TaskPriority::Urgent];
+    let choices = priorities.iter().map(|&p| Choice::new(priority_label(p), p)).collect();
+    let start = priorities.iter().position(|&p| p == default).unwrap_or(0);
+    Ok(Select::new("Priority:", choices).with_starting_cursor(start).prompt()?.value)
+}
+
+// ── TASK TEXT ───────────────────────────────────────────────
+
+const TASK_TEXT_HELP: &str = "\
+# First line: title. Everything after the blank line: description.
+# Lines starting with '#' are ignored.";
+
+/// Opens the user's editor on "title, blank line, description"
+///
+/// Returns the title and the description (`None` when left empty).
+pub fn task_text(title: &str, description: Option<&str>) -> Result<(String, Option<String>), CliError> {
+    let template = format!("{}\n\n{}\n{}\n", title, description.unwrap_or(""), TASK_TEXT_HELP);
+    let text = Editor::new("Title and description:")
+        .with_predefined_text(&template)
+        .with_file_extension(".md")
+        .with_validator(|text: &str| {
+            Ok(if parse_task_text(text).0.is_empty() {
+                Validation::Invalid("The first line (title) can't be empty".into())
+            } else {
+                Validation::Valid
+            })
+        })
+        .prompt()?;
+    Ok(parse_task_text(&text))
+}
+
+/// Splits editor text into title and description (see `task_text`)
+pub fn parse_task_text(text: &str) -> (String, Option<String>) {
+    let mut lines = text.lines().filter(|line| !line.starts_with('#'));
+    let title = lines.by_ref().map(str::trim).find(|line| !line.is_empty()).unwrap_or("").to_string();
+    let description = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+    (title, Some(description).filter(|d| !d.is_empty()))
+}
+
+/// Parses "1, 15, 20-22" (commas or spaces, inclusive ranges), sorted and deduplicated
+pub fn parse_number_list(text: &str, min: u8, max: u8) -> Result<Vec<u8>, String> {
+    let mut numbers = Vec::new();
+    for item in text.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty()) {
+        let (first, last) = match item.split_once('-') {
+            Some((a, b)) => (parse_bounded(a, min, max)?, parse_bounded(b, min, max)?),
+            None => {
+                let n = parse_bounded(item, min, max)?;
+                (n, n)
+            }
+        };
+        if first > last {
+            return Err(format!("'{}' is a backwards range", item));
+        }
+        numbers.extend(first..=last);
+    }
+    if numbers.is_empty() {
+        return Err("Enter at least one number".to_string());
+    }
+    numbers.sort_unstable();
+    numbers.dedup();
+    Ok(numbers)
+}
+
+fn parse_bounded(text: &str, min: u8, max: u8) -> Result<u8, String> {
+    match text.trim().parse::<u8>() {
+        Ok(n) if (min..=max).contains(&n) => Ok(n),
+        _ => Err(format!("'{}' is not a number from {} to {}", text.trim(), min, max)),
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number_list() {
+        assert_eq!(parse_number_list("1, 15 31", 1, 31), Ok(vec![1, 15, 31]));
+        assert_eq!(parse_number_list("20-22,1,21", 1, 31), Ok(vec![1, 20, 21, 22]));
+        assert!(parse_number_list("", 1, 31).is_err());
+        assert!(parse_number_list("0", 1, 31).is_err());
+        assert!(parse_number_list("32", 1, 31).is_err());
+        assert!(parse_number_list("5-3", 1, 31).is_err());
+        assert!(parse_number_list("first", 1, 31).is_err());
+    }
+
+    #[test]
+    fn test_parse_task_text() {
+        let text = format!("  Water the plants \n\nKitchen first.\n\nThen the balcony.\n{}\n", TASK_TEXT_HELP);
+        let (title, description) = parse_task_text(&text);
+        assert_eq!(title, "Water the plants");
+        assert_eq!(description.as_deref(), Some("Kitchen first.\n\nThen the balcony."));
+
+        let (title, description) = parse_task_text(&format!("Stretch\n\n\n{}", TASK_TEXT_HELP));
+        assert_eq!(title, "Stretch");
+        assert_eq!(description, None);
+
+        assert_eq!(parse_task_text("# only comments\n\n").0, "");
+    }
+}
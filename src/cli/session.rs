@@ -0,0 +1,149 @@
+use std::env;
+use std::path::PathBuf;
+use rusqlite::Connection;
+use inquire::PasswordDisplayMode;
+use tsadaash::application::{authenticate, register, AccountError};
+use tsadaash::domain::entities::user::{Password, Timezone, TimezoneError, User};
+use tsadaash::infrastructure::sqlite::{self, UserId, UserRepository};
+use super::prompts::{self, Choice};
+use super::CliError;
+
+// ========================================================================
+// SESSION
+// The open database and the profile the commands act for
+// ========================================================================
+
+pub struct Session {
+    pub conn: Connection,
+    pub user_id: UserId,
+    pub user: User,
+}
+
+impl Session {
+    /// Opens the database and logs in
+    ///
+    /// `username` (from `--user` or `$TSADAASH_USER`) logs into that account,
+    /// or signs it up if it doesn't exist. Otherwise the user chooses between
+    /// logging in and signing up (signing up when there are no accounts yet).
+    pub fn start(db_path: Option<PathBuf>, username: Option<String>) -> Result<Self, CliError> {
+        let conn = sqlite::open(db_path.unwrap_or_else(default_db_path))?;
+        let username = username.or_else(|| env::var("TSADAASH_USER").ok());
+
+        let users = UserRepository::new(&conn);
+        let (user_id, user) = match username {
+            Some(name) => match users.find_by_username(&name)? {
+                Some(_) => login(&users, Some(name))?,
+                None => signup(&users, Some(name))?,
+            },
+            None if users.list()?.is_empty() => signup(&users, None)?,
+            None => {
+                let choices = vec![Choice::new("Log in", true), Choice::new("Sign up", false)];
+                match prompts::select("Account:", choices)? {
+                    true => login(&users, None)?,
+                    false => signup(&users, None)?,
+                }
+            }
+        };
+
+        Ok(Self { conn, user_id, user })
+    }
+}
+
+/// `$TSADAASH_DB`, else `~/.tsadaash.db`, else `./tsadaash.db`
+fn default_db_path() -> PathBuf {
+    if let Some(path) = env::var_os("TSADAASH_DB") {
+        return PathBuf::from(path);
+    }
+    match env::var_os("HOME") {
+        Some(home) => PathBuf::from(home).join(".tsadaash.db"),
+        None => PathBuf::from("tsadaash.db"),
+    }
+}
+
+/// Wrong passwords allowed before `login` gives up
+const LOGIN_ATTEMPTS: usize = 3;
+
+/// Asks for a username (unless given) and password, with a few attempts
+fn login(users: &UserRepository, username: Option<String>) -> Result<(UserId, User), CliError> {
+    let username = match username {
+        Some(name) => name,
+        None => inquire::Text::new("Username:").prompt()?.trim().to_string(),
+    };
+    for attempt in 1..=LOGIN_ATTEMPTS {
+        let password = inquire::Password::new(&format!("Password for {}:", username))
+            .with_display_mode(PasswordDisplayMode::Masked)
+            .without_confirmation()
+            .prompt()?;
+        match authenticate(users, &username, &Password::attempt(password)) {
+            Err(AccountError::InvalidCredentials) if attempt < LOGIN_ATTEMPTS => {
+                println!("✗ {}", AccountError::InvalidCredentials);
+            }
+            result => return Ok(result?),
+        }
+    }
+    Err(AccountError::InvalidCredentials.into())
+}
+
+/// Asks for the details of a new account and registers it
+fn signup(users: &UserRepository, username: Option<String>) -> Result<(UserId, User), CliError> {
+    println!("Creating a new account.");
+    let mut username = match username {
+        Some(name) => name,
+        None => inquire::Text::new("Username:").prompt()?,
+    };
+    let mut email = inquire::Text::new("Email:").prompt()?;
+    let password = new_password()?;
+    let timezone = timezone()?;
+
+    // Rejected username or email: ask for both again, keeping the rest
+    let (id, mut user) = loop {
+        match register(users, username, email.clone(), &password, timezone.clone()) {
+            Err(error @ (AccountError::UsernameTaken(_) | AccountError::User(_))) => {
+                println!("✗ {}", error);
+                username = inquire::Text::new("Username:").prompt()?;
+                email = inquire::Text::new("Email:").with_initial_value(&email).prompt()?;
+            }
+            result => break result?,
+        }
+    };
+    user.set_week_start(prompts::select("Weeks start on:", prompts::weekday_choices())?);
+    users.update(id, &user)?;
+    Ok((id, user))
+}
+
+/// Asks for a password (masked, typed twice) until it meets the length rules
+fn new_password() -> Result<Password, CliError> {
+    loop {
+        let text = inquire::Password::new("Password:")
+            .with_display_mode(PasswordDisplayMode::Masked)
+            .with_custom_confirmation_message("Password (again):")
+            .with_custom_confirmation_error_message("The passwords don't match")
+            .with_help_message(&format!("At least {} characters", Password::MIN_LENGTH))
+            .prompt()?;
+        match Password::new(text) {
+            Ok(password) => return Ok(password),
+            Err(error) => println!("{}", error),
+        }
+    }
+}
+
+/// Asks for an IANA timezone until one resolves, offering the closest match
+fn timezone() -> Result<Timezone, CliError> {
+    loop {
+        let text = inquire::Text::new("Timezone:")
+            .with_help_message("IANA name, e.g. Europe/Paris or America/New_York")
+            .prompt()?;
+        match Timezone::resolve(text.trim().to_string()) {
+            Ok(timezone) => return Ok(timezone),
+            Err(TimezoneError::UnknownZone { suggestion: Some(suggestion), .. }) => {
+                let accept = inquire::Confirm::new(&format!("Did you mean {}?", suggestion))
+                    .with_default(true)
+                    .prompt()?;
+                if accept {
+                    return Ok(Timezone::resolve(suggestion)?);
+                }
+            }
+            Err(error) => println!("{}", error),
+        }
+    }
+}
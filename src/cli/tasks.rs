@@ -0,0 +1,139 @@
+use tsadaash::domain::entities::task::{Task, TaskPriority, TaskStatus};
+use tsadaash::infrastructure::sqlite::{TaskId, TaskRepository};
+use super::prompts::{self, Choice};
+use super::session::Session;
+use super::{periodicity_wizard, CliError};
+
+// ========================================================================
+// TASK COMMANDS
+// ========================================================================
+
+/// Asks for a schedule, then title and description, and stores the new task
+pub fn create(session: &Session) -> Result<(), CliError> {
+    let periodicity = periodicity_wizard::ask(&session.user)?;
+    let (title, description) = prompts::task_text("", None)?;
+
+    let mut task = Task::new(title, periodicity)?;
+    task.set_description(description)?;
+    task.set_priority(prompts::priority(TaskPriority::default())?);
+
+    let id = TaskRepository::new(&session.conn).create(session.user_id, &task)?;
+    println!("Created task #{}: {} ({})", id, task.title(), task.periodicity());
+    Ok(())
+}
+
+/// Prints the user's tasks, archived ones only when `all`
+pub fn list(session: &Session, all: bool) -> Result<(), CliError> {
+    let tasks: Vec<_> = TaskRepository::new(&session.conn)
+        .list_for_user(session.user_id)?
+        .into_iter()
+        .filter(|(_, task)| all || task.status() != TaskStatus::Archived)
+        .collect();
+
+    if tasks.is_empty() {
+        println!("No tasks yet. Create one with `tsadaash create`.");
+        return Ok(());
+    }
+    for (id, task) in &tasks {
+        println!("{}", task_line(*id, task));
+        println!("      {}", task.periodicity());
+    }
+    Ok(())
+}
+
+/// Edits the title and description in the user's editor
+pub fn edit(session: &Session, id: Option<TaskId>) -> Result<(), CliError> {
+    let Some((id, mut task)) = pick(session, id, "Edit which task?", |_| true)? else {
+        return Ok(());
+    };
+    let (title, description) = prompts::task_text(task.title(), task.description())?;
+    task.set_title(title)?;
+    task.set_description(description)?;
+    task.set_priority(prompts::priority(task.priority())?);
+
+    TaskRepository::new(&session.conn).update(id, &task)?;
+    println!("Updated {}", task_line(id, &task));
+    Ok(())
+}
+
+pub fn pause(session: &Session, id: Option<TaskId>) -> Result<(), CliError> {
+    change_status(session, id, "Pause which task?", TaskStatus::Active, Task::pause)
+}
+
+pub fn resume(session: &Session, id: Option<TaskId>) -> Result<(), CliError> {
+    change_status(session, id, "Resume which task?", TaskStatus::Paused, Task::resume)
+}
+
+pub fn archive(session: &Session, id: Option<TaskId>) -> Result<(), CliError> {
+    let Some((id, mut task)) = pick(session, id, "Archive which task?", |task| task.status() != TaskStatus::Archived)? else {
+        return Ok(());
+    };
+    task.archive();
+    TaskRepository::new(&session.conn).update(id, &task)?;
+    println!("{}", task_line(id, &task));
+    Ok(())
+}
+
+// ── HELPERS ─────────────────────────────────────────────────
+
+/// Applies a `Task` state method; `from` is the status it acts on
+fn change_status(
+    session: &Session,
+    id: Option<TaskId>,
+    question: &str,
+    from: TaskStatus,
+    change: fn(&mut Task),
+) -> Result<(), CliError> {
+    let Some((id, mut task)) = pick(session, id, question, |task| task.status() == from)? else {
+        return Ok(());
+    };
+    if task.status() != from {
+        println!("Task #{} is {}, nothing to do", id, prompts::status_label(task.status()));
+        return Ok(());
+    }
+    change(&mut task);
+    TaskRepository::new(&session.conn).update(id, &task)?;
+    println!("{}", task_line(id, &task));
+    Ok(())
+}
+
+/// Loads the task `id` of the current user, or lets the user choose among
+/// the tasks accepted by `offer` when no id was given (`None`: nothing to choose from)
+pub fn pick(
+    session: &Session,
+    id: Option<TaskId>,
+    question: &str,
+    offer: impl Fn(&Task) -> bool,
+) -> Result<Option<(TaskId, Task)>, CliError> {
+    let tasks = TaskRepository::new(&session.conn);
+
+    if let Some(id) = id {
+        if tasks.owner(id)? != Some(session.user_id) {
+            return Err(CliError::UnknownTask(id));
+        }
+        return tasks.find(id)?.map(|task| Some((id, task))).ok_or(CliError::UnknownTask(id));
+    }
+
+    let choices: Vec<_> = tasks
+        .list_for_user(session.user_id)?
+        .into_iter()
+        .filter(|(_, task)| offer(task))
+        .map(|(id, task)| Choice::new(task_line(id, &task), (id, task)))
+        .collect();
+    if choices.is_empty() {
+        println!("No matching tasks.");
+        return Ok(None);
+    }
+    prompts::select(question, choices).map(Some)
+}
+
+/// "#3  [paused]  (high)  Water the plants"
+pub fn task_line(id: TaskId, task: &Task) -> String {
+    format!(
+        "#{:<3} [{}]  ({})  {}",
+        id.0,
+        prompts::status_label(task.status()),
+        prompts::priority_label(task.priority()),
+        task.title()
+    )
+}
@@ -0,0 +1,200 @@
+use chrono::{Duration, NaiveDate};
+use tsadaash::domain::entities::task::{Check, ComputedValue, English, PeriodicityWording, RollPolicy, Rule, TaskStatus};
+use tsadaash::domain::entities::user::User;
+use tsadaash::infrastructure::sqlite::{TaskId, TaskOccurrenceRepository};
+use super::prompts;
+use super::session::Session;
+use super::tasks::{pick, task_line};
+use super::CliError;
+
+// ========================================================================
+// WHY COMMAND
+// ========================================================================
+
+/// Prints why a task is or isn't due on one of the user's logical days
+pub fn why(session: &Session, id: Option<TaskId>, date: Option<NaiveDate>) -> Result<(), CliError> {
+    let Some((id, task)) = pick(session, id, "Which task?", |_| true)? else {
+        return Ok(());
+    };
+    let user = &session.user;
+    let date = match date {
+        Some(date) => date,
+        None => prompts::date("Which day?", user.week_start(), None)?,
+    };
+
+    let periodicity = task.periodicity();
+    let explanation = periodicity.explain_for(&user.day_start_instant(date), user);
+    println!("{}", task_line(id, &task));
+    println!("  {}: {}", date.format("%A %-d %B %Y"), if explanation.matches { "due" } else { "not due" });
+    for check in &explanation.checks {
+        // Constraints of a union are listed under their set
+        let nested = !periodicity.alternatives.is_empty()
+            && matches!(check.rule, Rule::Day(_) | Rule::Week(_) | Rule::Month(_) | Rule::Year(_));
+        let indent = if nested { "    " } else { "  " };
+        println!("{}{} {}", indent, if check.passed { "✓" } else { "✗" }, check_line(check, user));
+    }
+
+    if task.status() != TaskStatus::Active {
+        println!("  ✗ the task is {}: only active tasks are scheduled", prompts::status_label(task.status()));
+    }
+    if periodicity.after_completion.is_some() {
+        let last_completed = TaskOccurrenceRepository::new(&session.conn)
+            .list_for_task(id)?
+            .iter()
+            .filter(|(_, occurrence)| occurrence.is_completed())
+            .filter_map(|(_, occurrence)| occurrence.last_completed_at())
+            .max();
+        match periodicity.next_due_for(last_completed.as_ref(), user) {
+            Some(due) => println!("  Counted from the last completion: next due {}", user.logical_date(&due).format("%A %-d %B %Y")),
+            None => println!("  Counted from the last completion: not due again"),
+        }
+    }
+    Ok(())
+}
+
+/// "on the 13th of the month — day 14 of the month"
+fn check_line(check: &Check, user: &User) -> String {
+    let rule = match &check.rule {
+        Rule::Timeframe { start, end } => format!(
+            "from {} to {}",
+            user.logical_date(start).format("%-d %B %Y"),
+            // The end is excluded
+            user.logical_date(&(*end - Duration::seconds(1))).format("%-d %B %Y"),
+        ),
+        Rule::SpecialPattern(pattern) => English.special_pattern(pattern),
+        Rule::ConstraintSet(0) => "all of:".to_string(),
+        Rule::ConstraintSet(_) => "or all of:".to_string(),
+        Rule::Day(constraint) => English.day_constraint(constraint).unwrap_or_else(|| "every day".to_string()),
+        Rule::Week(constraint) => English.week_constraint(constraint).unwrap_or_else(|| "every week".to_string()),
+        Rule::Month(constraint) => English.month_constraint(constraint).unwrap_or_else(|| "every month".to_string()),
+        Rule::Year(constraint) => English.year_constraint(constraint).unwrap_or_else(|| "every year".to_string()),
+        Rule::Exceptions(RollPolicy::Skip) => "not an excluded day".to_string(),
+        Rule::Exceptions(_) => "stays on this day (not excluded, not moved)".to_string(),
+        Rule::RolledIn(_) => "occurrences moved here from neighbouring days".to_string(),
+    };
+    let values: Vec<_> = check.values.iter().map(|value| value_text(value, user)).collect();
+    if values.is_empty() {
+        rule
+    } else {
+        format!("{} — {}", rule, values.join(", "))
+    }
+}
+
+fn value_text(value: &ComputedValue, user: &User) -> String {
+    let day = |date: &NaiveDate| date.format("%a %-d %b %Y").to_string();
+    match value {
+        ComputedValue::Weekday(weekday) => prompts::weekday_name(*weekday).to_string(),
+        ComputedValue::DayOfMonth(n) => format!("day {} of the month", n),
+        ComputedValue::DaysBeforeMonthEnd(0) => "the last day of the month".to_string(),
+        ComputedValue::DaysBeforeMonthEnd(n) => format!("{} days before the end of the month", n),
+        ComputedValue::NthWeekday { from_first, from_last } => {
+            format!("weekday #{} of the month, #{} from the end", from_first + 1, from_last + 1)
+        }
+        ComputedValue::WeekOfMonth { from_first, from_last } => {
+            let week = |n: &Option<u8>| n.map(|n| format!("#{}", n + 1)).unwrap_or_else(|| "none (neighbouring month)".to_string());
+            format!("week {} of the month, {} from the end", week(from_first), week(from_last))
+        }
+        ComputedValue::WeekOfYear { year, week } => format!("week #{} of {}", week + 1, year),
+        ComputedValue::DaysSinceReference { reference, days } => format!("{} since {}", count(*days, "day"), day(reference)),
+        ComputedValue::WeeksSinceReference { reference, weeks } => format!("{} since the week of {}", count(*weeks, "week"), day(reference)),
+        ComputedValue::MonthsSinceReference { reference, months } => format!("{} since {}", count(*months, "month"), day(reference)),
+        ComputedValue::YearsSinceReference { reference, years } => format!("{} since {}", count(*years, "year"), day(reference)),
+        ComputedValue::Month(month) => month.name().to_string(),
+        ComputedValue::Year(year) => year.to_string(),
+        ComputedValue::ScheduledAt(date) => format!("scheduled at {}", user.local_datetime(date).format("%H:%M")),
+        ComputedValue::ExcludedDate => "an excluded date".to_string(),
+        ComputedValue::Holiday(name) => format!("{} holiday", name),
+        ComputedValue::Weekend => "a weekend day".to_string(),
+        ComputedValue::MovedTo(date) => format!("moved to {}", day(date)),
+        ComputedValue::MovedFrom(date) => format!("from {}", day(date)),
+    }
+}
+
+/// "1 week", "-3 weeks"
+fn count(n: i64, unit: &str) -> String {
+    format!("{} {}{}", n, unit, if n.abs() == 1 { "" } else { "s" })
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc, Weekday};
+    use tsadaash::domain::builders::PeriodicityBuilder;
+    use tsadaash::domain::entities::task::{HolidayCalendar, NthWeekdayOfMonth, Periodicity};
+    use tsadaash::domain::entities::user::{PasswordHash, Timezone};
+
+    fn user() -> User {
+        User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new("Etc/UTC".to_string()).unwrap(),
+        ).unwrap()
+    }
+
+    fn lines(periodicity: &Periodicity, date: (i32, u32, u32)) -> Vec<String> {
+        let user = user();
+        let day = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        periodicity.explain_for(&user.day_start_instant(day), &user).checks.iter().map(|check| check_line(check, &user)).collect()
+    }
+
+    #[test]
+    fn test_check_lines_show_computed_values() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::second(Weekday::Tue)])
+            .every_n_weeks(2)
+            .with_reference_date(Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(
+            lines(&periodicity, (2026, 3, 10)),
+            vec![
+                "on the second Tuesday of the month — Tuesday, weekday #2 of the month, #4 from the end".to_string(),
+                "every 2 weeks — 1 week since the week of Mon 2 Mar 2026".to_string(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_check_lines_show_constraint_sets() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1])
+            .or()
+            .on_weekdays(vec![Weekday::Fri])
+            .build()
+            .unwrap();
+        assert_eq!(
+            lines(&periodicity, (2026, 3, 6)),
+            vec![
+                "all of:".to_string(),
+                "on the 1st of the month — day 6 of the month".to_string(),
+                "or all of:".to_string(),
+                "on Fridays — Friday".to_string(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_check_lines_show_holidays_and_moves() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days_from_end(vec![1])
+            .skip_holidays(HolidayCalendar::new("office".to_string(), vec![NaiveDate::from_ymd_opt(2026, 3, 31).unwrap()]).unwrap())
+            .with_roll_policy(RollPolicy::PreviousBusinessDay)
+            .build()
+            .unwrap();
+        assert_eq!(
+            lines(&periodicity, (2026, 3, 31)),
+            vec![
+                "on the last day of the month — the last day of the month".to_string(),
+                "stays on this day (not excluded, not moved) — office holiday, moved to Mon 30 Mar 2026".to_string(),
+            ],
+        );
+    }
+}
@@ -1,9 +1,10 @@
-use chrono::{DateTime, Utc, Weekday, Month, TimeZone};
+use chrono::{DateTime, NaiveDate, Utc, Weekday, Month, TimeZone};
 use crate::domain::validators::periodicity_validator;
 use crate::domain::entities::task::periodicity::{OccurrenceTimingSettings, NthWeekdayOfMonth, RepetitionUnit};
 use crate::domain::entities::task::{
     DayConstraint, MonthConstraint, MonthWeekPosition, Periodicity, PeriodicityConstraints,
     SpecialPattern, WeekConstraint, YearConstraint, CustomDates, UniqueDate,
+    PeriodicityExceptions, HolidayCalendar, RollPolicy, AfterCompletion,
 };
 
 // ========================================================================
@@ -36,8 +37,12 @@ pub struct PeriodicityBuilder {
     week_constraint: Option<WeekConstraint>,
     month_constraint: Option<MonthConstraint>,
     year_constraint: Option<YearConstraint>,
+    /// Sets closed by `or`, in order
+    previous_sets: Vec<PeriodicityConstraints>,
     timeframe: Option<(DateTime<Utc>, DateTime<Utc>)>,
     special_pattern: Option<SpecialPattern>,
+    after_completion: Option<AfterCompletion>,
+    exceptions: PeriodicityExceptions,
     reference_date: Option<DateTime<Utc>>,
 }
 
@@ -58,8 +63,11 @@ impl PeriodicityBuilder {
             week_constraint: None,
             month_constraint: None,
             year_constraint: None,
+            previous_sets: Vec::new(),
             timeframe: None,
             special_pattern: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         }
     }
@@ -173,6 +181,35 @@ impl PeriodicityBuilder {
         self
     }
     
+    /// Occurs on specific weeks of the year (1-53), starting on the user's week start
+    /// (week 1 is the first with at least 4 days in the year)
+    pub fn on_weeks_of_year(mut self, weeks: Vec<u8>) -> Self {
+        // Convert 1-indexed to 0-indexed
+        let zero_indexed: Vec<u8> = weeks.into_iter().map(|w| w.saturating_sub(1)).collect();
+        self.week_constraint = Some(WeekConstraint::SpecificWeeksOfYear(zero_indexed));
+        self
+    }
+    
+    /// Occurs on specific ISO 8601 weeks (1-53)
+    pub fn on_iso_weeks(mut self, weeks: Vec<u8>) -> Self {
+        // Convert 1-indexed to 0-indexed
+        let zero_indexed: Vec<u8> = weeks.into_iter().map(|w| w.saturating_sub(1)).collect();
+        self.week_constraint = Some(WeekConstraint::SpecificIsoWeeks(zero_indexed));
+        self
+    }
+    
+    /// Occurs in even ISO weeks (W02, W04, ...)
+    pub fn in_even_iso_weeks(mut self) -> Self {
+        self.week_constraint = Some(WeekConstraint::EvenIsoWeeks);
+        self
+    }
+    
+    /// Occurs in odd ISO weeks (W01, W03, ...)
+    pub fn in_odd_iso_weeks(mut self) -> Self {
+        self.week_constraint = Some(WeekConstraint::OddIsoWeeks);
+        self
+    }
+    
     // ────────────────────────────────────────────────────────
     // MONTH CONSTRAINT SETTERS
     // ────────────────────────────────────────────────────────
@@ -217,6 +254,52 @@ impl PeriodicityBuilder {
         self
     }
     
+    // ────────────────────────────────────────────────────────
+    // ALTERNATIVES
+    // ────────────────────────────────────────────────────────
+    
+    /// Closes the day/week/month/year constraints set so far and starts an
+    /// alternative set: the task occurs on days either set allows
+    /// 
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::builders::periodicity_builder::PeriodicityBuilder;
+    /// use chrono::{TimeZone, Utc, Weekday};
+    /// 
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // The 1st and 15th of the month, or Fridays
+    /// let periodicity = PeriodicityBuilder::new()
+    ///     .daily(1)
+    ///     .on_month_days(vec![1, 15])
+    ///     .or()
+    ///     .on_weekdays(vec![Weekday::Fri])
+    ///     .build()?;
+    /// 
+    /// let days = periodicity.occurrences_between(
+    ///     Utc.with_ymd_and_hms(2026, 5, 1, 0, 0, 0).unwrap(),
+    ///     Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap(),
+    ///     Weekday::Mon,
+    /// );
+    /// // Fridays 1, 8, 15, 22 and 29 May: the 1st and 15th occur once
+    /// assert_eq!(days.count(), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or(mut self) -> Self {
+        let constraints = self.take_constraints();
+        self.previous_sets.push(constraints);
+        self
+    }
+    
+    fn take_constraints(&mut self) -> PeriodicityConstraints {
+        PeriodicityConstraints {
+            day_constraint: self.day_constraint.take(),
+            week_constraint: self.week_constraint.take(),
+            month_constraint: self.month_constraint.take(),
+            year_constraint: self.year_constraint.take(),
+        }
+    }
+    
     // ────────────────────────────────────────────────────────
     // SPECIAL PATTERN SETTERS
     // ────────────────────────────────────────────────────────
@@ -236,6 +319,37 @@ impl PeriodicityBuilder {
         Ok(self)
     }
     
+    // ────────────────────────────────────────────────────────
+    // COMPLETION-RELATIVE RECURRENCE
+    // ────────────────────────────────────────────────────────
+    
+    /// Counts the next occurrence from the last completion
+    /// (`interval` days, weeks, months or years after it)
+    /// 
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::builders::periodicity_builder::PeriodicityBuilder;
+    /// use tsadaash::domain::entities::task::periodicity::RepetitionUnit;
+    /// use chrono::NaiveDate;
+    /// 
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // Water the plants 4 days after the last watering
+    /// let watering = PeriodicityBuilder::new()
+    ///     .daily(1)
+    ///     .after_completion(4, RepetitionUnit::Day)
+    ///     .build()?;
+    /// 
+    /// let watered = NaiveDate::from_ymd_opt(2026, 6, 29).unwrap();
+    /// let rule = watering.after_completion.unwrap();
+    /// assert_eq!(rule.due_after(watered), NaiveDate::from_ymd_opt(2026, 7, 3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn after_completion(mut self, interval: u16, unit: RepetitionUnit) -> Self {
+        self.after_completion = Some(AfterCompletion { interval, unit });
+        self
+    }
+    
     // ────────────────────────────────────────────────────────
     // TIMEFRAME SETTERS
     // ────────────────────────────────────────────────────────
@@ -260,6 +374,70 @@ impl PeriodicityBuilder {
         self
     }
     
+    // ────────────────────────────────────────────────────────
+    // EXCEPTIONS
+    // ────────────────────────────────────────────────────────
+    
+    /// Excludes specific calendar days (adds to previous calls)
+    /// 
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::builders::periodicity_builder::PeriodicityBuilder;
+    /// use chrono::{NaiveDate, TimeZone, Utc, Weekday};
+    /// 
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let christmas = NaiveDate::from_ymd_opt(2026, 12, 25).unwrap();
+    /// let periodicity = PeriodicityBuilder::new()
+    ///     .daily(1)
+    ///     .except(vec![christmas])
+    ///     .build()?;
+    /// 
+    /// let date = Utc.with_ymd_and_hms(2026, 12, 25, 9, 0, 0).unwrap();
+    /// assert!(!periodicity.matches_constraints(&date, Weekday::Mon));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn except(mut self, dates: Vec<NaiveDate>) -> Self {
+        self.exceptions.dates.extend(dates);
+        self.exceptions.dates.sort();
+        self.exceptions.dates.dedup();
+        self
+    }
+    
+    /// Excludes every date of a holiday calendar (see `infrastructure::holidays`)
+    pub fn skip_holidays(mut self, calendar: HolidayCalendar) -> Self {
+        self.exceptions.holidays.push(calendar);
+        self
+    }
+    
+    /// Moves occurrences off holidays and weekends instead of skipping them
+    /// 
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::builders::periodicity_builder::PeriodicityBuilder;
+    /// use tsadaash::domain::entities::task::RollPolicy;
+    /// use chrono::{TimeZone, Utc, Weekday};
+    /// 
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // Rent is due on the 1st, or the Friday before when it's a weekend
+    /// let rent = PeriodicityBuilder::new()
+    ///     .daily(1)
+    ///     .on_month_days(vec![1])
+    ///     .with_roll_policy(RollPolicy::PreviousBusinessDay)
+    ///     .build()?;
+    /// 
+    /// let friday = Utc.with_ymd_and_hms(2026, 7, 31, 0, 0, 0).unwrap();
+    /// let saturday = Utc.with_ymd_and_hms(2026, 8, 1, 0, 0, 0).unwrap();
+    /// assert!(rent.matches_constraints(&friday, Weekday::Mon));
+    /// assert!(!rent.matches_constraints(&saturday, Weekday::Mon));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_roll_policy(mut self, policy: RollPolicy) -> Self {
+        self.exceptions.roll = policy;
+        self
+    }
+    
     // ────────────────────────────────────────────────────────
     // REFERENCE DATE
     // ────────────────────────────────────────────────────────
@@ -314,19 +492,23 @@ impl PeriodicityBuilder {
     // ────────────────────────────────────────────────────────
     
     /// Builds and validates the Periodicity instance
-    pub fn build(self) -> Result<Periodicity, periodicity_validator::ValidationError> {
+    pub fn build(mut self) -> Result<Periodicity, periodicity_validator::ValidationError> {
+        // The first set is the main one, the others are alternatives
+        let last = self.take_constraints();
+        let mut alternatives = std::mem::take(&mut self.previous_sets);
+        alternatives.push(last);
+        let constraints = alternatives.remove(0);
+        
         let periodicity = Periodicity {
             rep_unit: self.rep_unit.unwrap_or(RepetitionUnit::None),
             rep_per_unit: self.rep_per_unit,
             occurrence_settings: self.occurrence_settings,
-            constraints: PeriodicityConstraints {
-                day_constraint: self.day_constraint,
-                week_constraint: self.week_constraint,
-                month_constraint: self.month_constraint,
-                year_constraint: self.year_constraint,
-            },
+            constraints,
+            alternatives,
             timeframe: self.timeframe,
             special_pattern: self.special_pattern,
+            after_completion: self.after_completion,
+            exceptions: self.exceptions,
             reference_date: self.reference_date,
         };
         
@@ -487,6 +669,24 @@ mod tests {
         assert!(periodicity.constraints.month_constraint.is_some());
     }
     
+    #[test]
+    fn test_builder_or_starts_an_alternative_set() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon])
+            .in_months(vec![Month::January])
+            .or()
+            .on_month_days(vec![1])
+            .build()
+            .unwrap();
+        
+        assert_eq!(periodicity.constraints.day_constraint, Some(DayConstraint::SpecificDaysWeek(vec![Weekday::Mon])));
+        assert_eq!(periodicity.alternatives, vec![PeriodicityConstraints {
+            day_constraint: Some(DayConstraint::SpecificDaysMonthFromFirst(vec![0])),
+            ..Default::default()
+        }]);
+    }
+    
     #[test]
     fn test_convenience_daily() {
         let periodicity = Periodicity::daily().unwrap();
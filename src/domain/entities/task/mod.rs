@@ -11,8 +11,30 @@ pub use periodicity::{
     SpecialPattern,
     CustomDates,
     UniqueDate,
+    PeriodicityExceptions,
+    HolidayCalendar,
+    RollPolicy,
+    AfterCompletion,
 };
 
+pub mod periodicity_json;
+pub use periodicity_json::{PeriodicityJsonError, PERIODICITY_JSON_VERSION};
+
+pub mod periodicity_describe;
+pub use periodicity_describe::{English, PeriodicityWording};
+
+pub mod periodicity_explain;
+pub use periodicity_explain::{Check, ComputedValue, Explanation, Rule};
+
+pub mod periodicity_phrase;
+pub use periodicity_phrase::PhraseError;
+
+pub mod periodicity_rrule;
+pub use periodicity_rrule::{RecurrenceRule, RRuleExportError, RRuleImportError, UnsupportedRulePart};
+
+#[cfg(feature = "serde")]
+mod periodicity_serde;
+
 pub mod task;
 pub use task::{
     Task,
@@ -38,6 +38,20 @@ impl OccurenceRep {
         }
     }
 
+    /// Rebuilds a repetition from persisted state
+    /// A repetition is completed iff `completed_at` is set
+    pub fn restore(
+        rep_index: u8,
+        completed_at: Option<DateTime<Utc>>,
+        notes: Option<String>,
+    ) -> Result<Self, TaskOccurrenceValidationError> {
+        let mut rep = Self::new(rep_index);
+        rep.completed = completed_at.is_some();
+        rep.completed_at = completed_at;
+        rep.set_notes(notes)?;
+        Ok(rep)
+    }
+
     // ── GETTERS ─────────────────────────────────────────────
 
     pub fn rep_index(&self) -> u8 {
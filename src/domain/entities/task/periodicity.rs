@@ -1,5 +1,7 @@
-use chrono::{DateTime, NaiveTime, Datelike, Month, NaiveDate, Utc, Weekday};
+use chrono::{DateTime, Days, Duration, NaiveTime, Datelike, Month, Months, NaiveDate, TimeZone, Utc, Weekday};
+use crate::domain::entities::user::User;
 use crate::domain::validators::periodicity_validator::{ValidationError, validate_periodicity};
+use crate::domain::validators::periodicity_satisfiability::{ValidationWarning, check_satisfiability};
 
 // ========================================================================
 // CORE REPETITION SETTINGS
@@ -119,6 +121,24 @@ pub enum WeekConstraint {
     /// 0 = last week, 1 = second-to-last, etc.
     /// Must contain 1-5 unique values
     SpecificWeeksOfMonthFromLast(Vec<u8>),
+    
+    /// Specific weeks of the year (0-52), weeks starting on the user's week_start
+    /// 0 = week 1, the first week with at least 4 days in the year (ISO 8601
+    /// rule; with a Monday week start these are the ISO weeks)
+    /// Must contain 1-53 unique values
+    SpecificWeeksOfYear(Vec<u8>),
+    
+    /// Specific ISO 8601 weeks (0-52), whatever the user's week_start
+    /// 0 = W01, 52 = W53 (only in years with 53 ISO weeks)
+    /// Must contain 1-53 unique values
+    SpecificIsoWeeks(Vec<u8>),
+    
+    /// Even ISO week numbers (W02, W04, ..., W52)
+    /// Unlike EveryNWeeks(2), restarts each ISO year: W53 and the next W01 are both odd
+    EvenIsoWeeks,
+    
+    /// Odd ISO week numbers (W01, W03, ..., W53)
+    OddIsoWeeks,
 }
 
 // ========================================================================
@@ -207,6 +227,183 @@ pub struct PeriodicityConstraints {
     pub year_constraint: Option<YearConstraint>,
 }
 
+// ========================================================================
+// EXCEPTIONS
+// Calendar days on which the periodicity doesn't fire
+// ========================================================================
+
+/// Named set of holidays (e.g. "fr-public"), as read from a holiday file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    pub name: String,
+
+    /// Holiday dates (sorted, unique)
+    pub dates: Vec<NaiveDate>,
+}
+
+impl HolidayCalendar {
+    pub fn new(name: String, mut dates: Vec<NaiveDate>) -> Result<Self, ValidationError> {
+        if name.trim().is_empty() {
+            return Err(ValidationError::InvalidValue {
+                field: "HolidayCalendar.name".into(),
+                value: name,
+                reason: "Must not be empty".into(),
+            });
+        }
+        dates.sort();
+        dates.dedup();
+        Ok(Self { name, dates })
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.dates.contains(&date)
+    }
+}
+
+/// What happens to an occurrence that falls on a holiday or, unless
+/// skipped, a weekend
+///
+/// Business days are Monday to Friday, minus holidays and excluded dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RollPolicy {
+    /// The occurrence is dropped on holidays; weekends are ordinary days
+    #[default]
+    Skip,
+    /// Moved back to the last business day before it
+    PreviousBusinessDay,
+    /// Moved forward to the first business day after it
+    NextBusinessDay,
+    /// Moved to the closest business day (the earlier one on a tie)
+    NearestBusinessDay,
+}
+
+/// Days excluded from a periodicity
+///
+/// Dates are calendar days: they are compared with the UTC date in
+/// `matches_constraints` and with the user's logical date in
+/// `matches_constraints_for`. Holiday calendars are copied in with their
+/// dates, so a stored periodicity doesn't depend on the holiday file.
+///
+/// Explicit dates always drop the occurrence; holidays drop or move it
+/// depending on `roll`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeriodicityExceptions {
+    /// Explicitly excluded dates (sorted, unique)
+    pub dates: Vec<NaiveDate>,
+
+    /// Holiday calendars whose dates are all excluded
+    pub holidays: Vec<HolidayCalendar>,
+
+    /// Skip holidays, or move occurrences off holidays and weekends
+    pub roll: RollPolicy,
+}
+
+impl PeriodicityExceptions {
+    /// How far an occurrence may move; further away it is dropped
+    pub const MAX_ROLL_DAYS: i64 = 31;
+
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty() && self.holidays.iter().all(|calendar| calendar.dates.is_empty())
+    }
+
+    /// Checks if a calendar day is excluded
+    pub fn excludes(&self, date: NaiveDate) -> bool {
+        self.dates.contains(&date)
+            || self.holidays.iter().any(|calendar| calendar.contains(date))
+    }
+
+    /// Checks if a calendar day is a weekday that isn't excluded
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.excludes(date)
+    }
+
+    /// Day an occurrence scheduled on `nominal` actually happens, if any
+    ///
+    /// # Examples
+    /// ```
+    /// use tsadaash::domain::entities::task::periodicity::{PeriodicityExceptions, RollPolicy};
+    /// use chrono::NaiveDate;
+    ///
+    /// let exceptions = PeriodicityExceptions { roll: RollPolicy::PreviousBusinessDay, ..Default::default() };
+    /// let saturday = NaiveDate::from_ymd_opt(2026, 8, 1).unwrap();
+    /// assert_eq!(exceptions.roll(saturday), NaiveDate::from_ymd_opt(2026, 7, 31));
+    /// ```
+    pub fn roll(&self, nominal: NaiveDate) -> Option<NaiveDate> {
+        if self.dates.contains(&nominal) {
+            return None;
+        }
+        let business = |days: i64| {
+            let date = nominal.checked_add_signed(Duration::days(days))?;
+            self.is_business_day(date).then_some(date)
+        };
+        let mut steps = 0..=Self::MAX_ROLL_DAYS;
+        match self.roll {
+            RollPolicy::Skip => (!self.excludes(nominal)).then_some(nominal),
+            RollPolicy::PreviousBusinessDay => steps.find_map(|days| business(-days)),
+            RollPolicy::NextBusinessDay => steps.find_map(business),
+            RollPolicy::NearestBusinessDay => steps.find_map(|days| business(-days).or_else(|| business(days))),
+        }
+    }
+
+    /// Checks if some nominal day accepted by `is_nominal` happens on `date`
+    ///
+    /// Only the non-business days next to `date` can roll onto it, so the
+    /// search stops at the first business day on each side.
+    pub fn receives(&self, date: NaiveDate, is_nominal: impl Fn(NaiveDate) -> bool) -> bool {
+        let rolls_here = |nominal: NaiveDate| is_nominal(nominal) && self.roll(nominal) == Some(date);
+        if rolls_here(date) {
+            return true;
+        }
+        if self.roll == RollPolicy::Skip || !self.is_business_day(date) {
+            return false;
+        }
+        let side = |direction: i64| {
+            (1..=Self::MAX_ROLL_DAYS)
+                .map_while(|days| date.checked_add_signed(Duration::days(direction * days)))
+                .take_while(|nominal| !self.is_business_day(*nominal))
+                .any(rolls_here)
+        };
+        side(1) || side(-1)
+    }
+}
+
+// ========================================================================
+// COMPLETION-RELATIVE RECURRENCE
+// Next occurrence counted from the last completion, not the calendar
+// ========================================================================
+
+/// "Water the plants 4 days after the last watering"
+///
+/// The next occurrence is due `interval` units after the logical day of the
+/// last completion. The periodicity's constraints still filter the due day
+/// (it moves forward to the first day they allow), while `rep_unit` keeps
+/// giving the size of the occurrence window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AfterCompletion {
+    /// Units between a completion and the next due day (at least 1)
+    pub interval: u16,
+
+    /// Day, Week, Month or Year
+    pub unit: RepetitionUnit,
+}
+
+impl AfterCompletion {
+    /// Earliest day the next occurrence is due after a completion on `completed`
+    ///
+    /// Months are calendar months: a completion on January 31st plus one
+    /// month is due on the last day of February.
+    pub fn due_after(&self, completed: NaiveDate) -> Option<NaiveDate> {
+        let n = self.interval as u32;
+        match self.unit {
+            RepetitionUnit::Day => completed.checked_add_days(Days::new(n as u64)),
+            RepetitionUnit::Week => completed.checked_add_days(Days::new(7 * n as u64)),
+            RepetitionUnit::Month => completed.checked_add_months(Months::new(n)),
+            RepetitionUnit::Year => completed.checked_add_months(Months::new(12 * n)),
+            RepetitionUnit::None => None,
+        }
+    }
+}
+
 // ========================================================================
 // OCCURRENCE TIMING SETTINGS
 // Settings to apply to each occurrence generated by the periodicity
@@ -310,6 +507,7 @@ pub struct RepTimingSettings {
 /// # Design Philosophy
 /// - `rep_unit` + `rep_per_unit`: HOW OFTEN (frequency)
 /// - `constraints`: WHEN IT CAN HAPPEN (filters)
+/// - `alternatives`: OR WHEN ANOTHER SET OF FILTERS ALLOWS IT
 /// - `timeframe`: OVERALL VALIDITY PERIOD
 /// 
 /// # Examples
@@ -326,8 +524,11 @@ pub struct RepTimingSettings {
 ///         month_constraint: Some(MonthConstraint::SpecificMonths(vec![Month::January])),
 ///         ..Default::default()
 ///     },
+///     alternatives: Vec::new(),
 ///     timeframe: None,
 ///     special_pattern: None,
+///     after_completion: None,
+///     exceptions: PeriodicityExceptions::default(),
 ///     reference_date: None,
 /// };
 /// # assert_eq!(periodicity.rep_unit, RepetitionUnit::Day);
@@ -354,6 +555,11 @@ pub struct Periodicity {
     /// All constraints are combined with AND logic
     pub constraints: PeriodicityConstraints,
     
+    /// Other constraint sets the task can also occur on (OR logic)
+    /// A day matches when `constraints` or any of these sets match it;
+    /// empty for a single set
+    pub alternatives: Vec<PeriodicityConstraints>,
+    
     // ── TIME BOUNDARIES ──────────────────────────────────────
     
     /// Optional validity period for this periodicity
@@ -366,6 +572,20 @@ pub struct Periodicity {
     /// When set, rep_unit must be RepetitionUnit::None
     pub special_pattern: Option<SpecialPattern>,
     
+    // ── COMPLETION-RELATIVE ──────────────────────────────────
+    
+    /// Counts the next occurrence from the last completion instead of the
+    /// calendar (see `next_due_for`)
+    /// Calendar expansion (`occurrences_between`, `matches_constraints`)
+    /// ignores it and lists the days the constraints allow
+    pub after_completion: Option<AfterCompletion>,
+    
+    // ── EXCEPTIONS ───────────────────────────────────────────
+    
+    /// Days on which the task never occurs (explicit dates and holidays)
+    /// Applies to regular constraints and special patterns alike
+    pub exceptions: PeriodicityExceptions,
+    
     // ── REFERENCE DATE ───────────────────────────────────────
     
     /// Reference date for EveryN* rolling patterns (EveryNDays, EveryNWeeks, etc.)
@@ -385,6 +605,16 @@ pub enum SpecialPattern {
     Unique(UniqueDate),
 }
 
+impl SpecialPattern {
+    /// All dates of the pattern, sorted
+    pub fn dates(&self) -> &[DateTime<Utc>] {
+        match self {
+            SpecialPattern::Custom(custom) => &custom.dates,
+            SpecialPattern::Unique(unique) => std::slice::from_ref(&unique.date),
+        }
+    }
+}
+
 // ========================================================================
 // IMPLEMENTATION HELPERS
 // ========================================================================
@@ -396,13 +626,19 @@ impl Periodicity {
         validate_periodicity(self)
     }
     
+    /// Reports a valid periodicity that never or rarely fires from `from` on
+    /// (see `check_satisfiability`); empty when it looks fine
+    pub fn warnings(&self, from: DateTime<Utc>, week_start: Weekday) -> Vec<ValidationWarning> {
+        check_satisfiability(self, from, week_start)
+    }
+    
     /// Gets the effective reference date for EveryN* constraint calculations
     /// 
     /// # Rules (in priority order):
     /// 1. If reference_date is set (from TaskOccurrence), use it
     /// 2. If timeframe.start_inclusive is set, use it
     /// 3. Use the provided current_date as fallback
-    fn get_effective_reference_date(&self, current_date: &DateTime<Utc>) -> DateTime<Utc> {
+    pub(super) fn get_effective_reference_date(&self, current_date: &DateTime<Utc>) -> DateTime<Utc> {
         // Rule 1: Explicit reference date (set from TaskOccurrence layer)
         if let Some(ref_date) = self.reference_date {
             return ref_date;
@@ -420,39 +656,95 @@ impl Periodicity {
     /// Checks if a specific date matches this periodicity's constraints
     /// Does NOT account for timeframe - call is_within_timeframe separately
     /// 
+    /// Constraints are read on the UTC calendar date; use
+    /// `matches_constraints_for` to evaluate them in a user's local calendar.
+    /// With a roll policy, this is the day an occurrence happens on once
+    /// moved, not the day it was scheduled for.
+    /// 
     /// # Parameters
     /// - `date`: The date to check
     /// - `week_start`: First day of the week (from User calendar settings)
     pub fn matches_constraints(&self, date: &DateTime<Utc>, week_start: Weekday) -> bool {
+        self.matches_constraints_from(date, week_start, date)
+    }
+    
+    /// Checks if an instant matches this periodicity's constraints in the
+    /// user's logical calendar (timezone + `day_start`)
+    /// Does NOT account for timeframe - call is_within_timeframe separately
+    /// 
+    /// Unlike `matches_constraints`, a completion at 02:00 with day_start = 05:00
+    /// is evaluated against the previous day.
+    pub fn matches_constraints_for(&self, instant: &DateTime<Utc>, user: &User) -> bool {
+        let date = user.logical_date(instant);
+        
+        // Special patterns match on the logical day of each date
+        if let Some(pattern) = &self.special_pattern {
+            return self.exceptions.receives(date, |day| pattern.dates().iter().any(|d| user.logical_date(d) == day));
+        }
+        
+        let reference = user.logical_date(&self.get_effective_reference_date(instant));
+        self.exceptions.receives(date, |day| self.matches_calendar_date(day, user.week_start(), reference))
+    }
+    
+    /// Same as `matches_constraints`, but with an explicit fallback anchor for
+    /// EveryN* patterns (rule 3 of `get_effective_reference_date`).
+    /// 
+    /// Expansion uses the first date of the range as anchor, so that
+    /// "every 3 days" without a reference date still yields every third day.
+    fn matches_constraints_from(
+        &self,
+        date: &DateTime<Utc>,
+        week_start: Weekday,
+        fallback_reference: &DateTime<Utc>,
+    ) -> bool {
         // Handle special patterns first
         if let Some(pattern) = &self.special_pattern {
-            return match pattern {
-                SpecialPattern::Custom(custom) => custom.dates.contains(date),
-                SpecialPattern::Unique(unique) => unique.date == *date,
-            };
+            return pattern.dates().iter().any(|d| {
+                let nominal = d.date_naive();
+                self.exceptions.roll(nominal).map(|day| *d + day.signed_duration_since(nominal)) == Some(*date)
+            });
         }
         
+        let reference = self.get_effective_reference_date(fallback_reference).date_naive();
+        self.exceptions.receives(date.date_naive(), |day| self.matches_calendar_date(day, week_start, reference))
+    }
+    
+    /// Checks the regular constraints against a calendar date, before
+    /// exceptions and roll policy
+    /// 
+    /// `reference` is the calendar date EveryN* intervals are counted from.
+    pub(super) fn matches_calendar_date(&self, date: NaiveDate, week_start: Weekday, reference: NaiveDate) -> bool {
+        self.constraint_sets().any(|constraints| Self::matches_constraint_set(date, constraints, week_start, reference))
+    }
+    
+    /// The main constraint set followed by the alternatives
+    pub fn constraint_sets(&self) -> impl Iterator<Item = &PeriodicityConstraints> {
+        std::iter::once(&self.constraints).chain(&self.alternatives)
+    }
+    
+    /// Checks one constraint set (AND of its constraints)
+    fn matches_constraint_set(date: NaiveDate, constraints: &PeriodicityConstraints, week_start: Weekday, reference: NaiveDate) -> bool {
         // Check each constraint
-        if let Some(day) = &self.constraints.day_constraint {
-            if !self.matches_day_constraint(date, day) {
+        if let Some(day) = &constraints.day_constraint {
+            if !Self::matches_day_constraint(date, day, reference) {
                 return false;
             }
         }
         
-        if let Some(week) = &self.constraints.week_constraint {
-            if !self.matches_week_constraint(date, week, week_start) {
+        if let Some(week) = &constraints.week_constraint {
+            if !Self::matches_week_constraint(date, week, week_start, reference) {
                 return false;
             }
         }
         
-        if let Some(month) = &self.constraints.month_constraint {
-            if !self.matches_month_constraint(date, month) {
+        if let Some(month) = &constraints.month_constraint {
+            if !Self::matches_month_constraint(date, month, reference) {
                 return false;
             }
         }
         
-        if let Some(year) = &self.constraints.year_constraint {
-            if !self.matches_year_constraint(date, year) {
+        if let Some(year) = &constraints.year_constraint {
+            if !Self::matches_year_constraint(date, year, reference) {
                 return false;
             }
         }
@@ -470,12 +762,11 @@ impl Periodicity {
     
     // ── PRIVATE CONSTRAINT MATCHERS ──────────────────────────
     
-    fn matches_day_constraint(&self, date: &DateTime<Utc>, constraint: &DayConstraint) -> bool {
+    pub(super) fn matches_day_constraint(date: NaiveDate, constraint: &DayConstraint, reference: NaiveDate) -> bool {
         match constraint {
             DayConstraint::EveryDay => true,
             DayConstraint::EveryNDays(n) => {
-                let ref_date = self.get_effective_reference_date(date);
-                let days_diff = (*date - ref_date).num_days().abs();
+                let days_diff = (date - reference).num_days().abs();
                 (days_diff % (*n as i64)) == 0
             }
             DayConstraint::SpecificDaysWeek(weekdays) => {
@@ -486,8 +777,7 @@ impl Periodicity {
                 days.contains(&(day_of_month as u8))
             }
             DayConstraint::SpecificDaysMonthFromLast(days) => {
-                let naive_date = date.naive_utc().date();
-                let last_day = Self::last_day_of_month(naive_date);
+                let last_day = Self::last_day_of_month(date);
                 let days_from_end = last_day - date.day();
                 days.contains(&(days_from_end as u8))
             }
@@ -501,10 +791,10 @@ impl Periodicity {
                     
                     match pattern.position {
                         MonthWeekPosition::FromFirst(n) => {
-                            Self::is_nth_weekday_from_first(date, weekday, n)
+                            Self::is_nth_weekday_from_first(date, n)
                         }
                         MonthWeekPosition::FromLast(n) => {
-                            Self::is_nth_weekday_from_last(date, weekday, n)
+                            Self::is_nth_weekday_from_last(date, n)
                         }
                     }
                 })
@@ -512,14 +802,17 @@ impl Periodicity {
         }
     }
     
-    fn matches_week_constraint(&self, date: &DateTime<Utc>, constraint: &WeekConstraint, week_start: Weekday) -> bool {
+    pub(super) fn matches_week_constraint(
+        date: NaiveDate,
+        constraint: &WeekConstraint,
+        week_start: Weekday,
+        reference: NaiveDate,
+    ) -> bool {
         match constraint {
             WeekConstraint::EveryWeek => true,
             WeekConstraint::EveryNWeeks(n) => {
-                let ref_date = self.get_effective_reference_date(date);
-                
                 // Get the start of the week for both dates (respecting week_start)
-                let ref_week_start = Self::get_week_start(&ref_date, week_start);
+                let ref_week_start = Self::get_week_start(reference, week_start);
                 let date_week_start = Self::get_week_start(date, week_start);
                 
                 // Calculate weeks difference
@@ -529,7 +822,7 @@ impl Periodicity {
                 (weeks_diff % (*n as i64)) == 0
             }
             WeekConstraint::SpecificWeeksOfMonthFromFirst(weeks) => {
-                let week_of_month = Self::week_of_month_from_first(date, week_start);
+                let week_of_month = Self::week_of_month_from_first(&date, week_start);
                 // 255 means invalid (belongs to different month)
                 if week_of_month == 255 {
                     return false;
@@ -537,25 +830,34 @@ impl Periodicity {
                 weeks.contains(&week_of_month)
             }
             WeekConstraint::SpecificWeeksOfMonthFromLast(weeks) => {
-                let week_of_month = Self::week_of_month_from_last(date, week_start);
+                let week_of_month = Self::week_of_month_from_last(&date, week_start);
                 // 255 means invalid (belongs to different month)
                 if week_of_month == 255 {
                     return false;
                 }
                 weeks.contains(&week_of_month)
             }
+            WeekConstraint::SpecificWeeksOfYear(weeks) => {
+                let (_, week) = Self::week_of_year(date, week_start);
+                weeks.contains(&week)
+            }
+            WeekConstraint::SpecificIsoWeeks(weeks) => {
+                let (_, week) = Self::week_of_year(date, Weekday::Mon);
+                weeks.contains(&week)
+            }
+            // 0-indexed: W01 is index 0
+            WeekConstraint::EvenIsoWeeks => Self::week_of_year(date, Weekday::Mon).1 % 2 == 1,
+            WeekConstraint::OddIsoWeeks => Self::week_of_year(date, Weekday::Mon).1.is_multiple_of(2),
         }
     }
     
-    fn matches_month_constraint(&self, date: &DateTime<Utc>, constraint: &MonthConstraint) -> bool {
+    pub(super) fn matches_month_constraint(date: NaiveDate, constraint: &MonthConstraint, reference: NaiveDate) -> bool {
         match constraint {
             MonthConstraint::EveryMonth => true,
             MonthConstraint::EveryNMonths(n) => {
-                let ref_date = self.get_effective_reference_date(date);
-                
                 // Calculate months difference
-                let years_diff = date.year() - ref_date.year();
-                let months_diff = (years_diff * 12) + (date.month() as i32 - ref_date.month() as i32);
+                let years_diff = date.year() - reference.year();
+                let months_diff = (years_diff * 12) + (date.month() as i32 - reference.month() as i32);
                 
                 (months_diff.abs() % (*n as i32)) == 0
             }
@@ -566,12 +868,11 @@ impl Periodicity {
         }
     }
     
-    fn matches_year_constraint(&self, date: &DateTime<Utc>, constraint: &YearConstraint) -> bool {
+    pub(super) fn matches_year_constraint(date: NaiveDate, constraint: &YearConstraint, reference: NaiveDate) -> bool {
         match constraint {
             YearConstraint::EveryYear => true,
             YearConstraint::EveryNYears(n) => {
-                let ref_date = self.get_effective_reference_date(date);
-                let years_diff = (date.year() - ref_date.year()).abs();
+                let years_diff = (date.year() - reference.year()).abs();
                 (years_diff % (*n as i32)) == 0
             }
             YearConstraint::SpecificYears(years) => {
@@ -581,27 +882,19 @@ impl Periodicity {
     }
     
     // ── HELPER FUNCTIONS ─────────────────────────────────────
-        /// Get the start of the week for a given date, based on week_start setting
-    /// Returns a DateTime at 00:00:00 on the week_start day
-    fn get_week_start(date: &DateTime<Utc>, week_start: Weekday) -> DateTime<Utc> {
+
+    /// Get the start of the week for a given date, based on week_start setting
+    pub(super) fn get_week_start(date: NaiveDate, week_start: Weekday) -> NaiveDate {
         let current_weekday = date.weekday();
         
         // Calculate days to go back to reach week_start
         let days_back = (current_weekday.num_days_from_monday() + 7 
             - week_start.num_days_from_monday()) % 7;
         
-        let week_start_date = if days_back == 0 {
-            date.date_naive()
-        } else {
-            date.date_naive() - chrono::Duration::days(days_back as i64)
-        };
-        
-        DateTime::from_naive_utc_and_offset(
-            week_start_date.and_hms_opt(0, 0, 0).unwrap(),
-            Utc
-        )
+        date - chrono::Duration::days(days_back as i64)
     }
-        fn last_day_of_month(date: NaiveDate) -> u32 {
+
+    pub(super) fn last_day_of_month(date: NaiveDate) -> u32 {
         NaiveDate::from_ymd_opt(
             date.year(),
             date.month() + 1,
@@ -613,15 +906,14 @@ impl Periodicity {
         .day()
     }
     
-    fn is_nth_weekday_from_first(date: &DateTime<Utc>, _weekday: Weekday, n: u8) -> bool {
+    fn is_nth_weekday_from_first(date: NaiveDate, n: u8) -> bool {
         let day = date.day();
         let occurrence = (day - 1) / 7;
         occurrence == n as u32
     }
     
-    fn is_nth_weekday_from_last(date: &DateTime<Utc>, _weekday: Weekday, n: u8) -> bool {
-        let naive_date = date.naive_utc().date();
-        let last_day = Self::last_day_of_month(naive_date);
+    fn is_nth_weekday_from_last(date: NaiveDate, n: u8) -> bool {
+        let last_day = Self::last_day_of_month(date);
         let days_from_end = last_day - date.day();
         let occurrence = days_from_end / 7;
         occurrence == n as u32
@@ -643,7 +935,7 @@ impl Periodicity {
     /// - Feb 9-15 (Mon-Sun): Week 1
     /// - Feb 16-22 (Mon-Sun): Week 2
     /// - Feb 23-Mar 1 (Mon-Sun): Week 3 (overflow attached to February)
-    pub fn week_of_month_from_first(date: &DateTime<Utc>, week_start: Weekday) -> u8 {
+    pub fn week_of_month_from_first(date: &impl Datelike, week_start: Weekday) -> u8 {
         let year = date.year();
         let month = date.month();
         let day = date.day();
@@ -687,13 +979,12 @@ impl Periodicity {
     /// - Feb 9-15 (Mon-Sun): Week 2  
     /// - Feb 16-22 (Mon-Sun): Week 1
     /// - Feb 23-28 (Mon-Sat): Week 0 (last week, incomplete in Feb but completes in March)
-    fn week_of_month_from_last(date: &DateTime<Utc>, week_start: Weekday) -> u8 {
+    pub(super) fn week_of_month_from_last(date: &impl Datelike, week_start: Weekday) -> u8 {
         let year = date.year();
         let month = date.month();
         let day = date.day();
         
-        let naive_date = date.naive_utc().date();
-        let last_day = Self::last_day_of_month(naive_date);
+        let last_day = Self::last_day_of_month(NaiveDate::from_ymd_opt(year, month, 1).unwrap());
         let last_date = NaiveDate::from_ymd_opt(year, month, last_day).unwrap();
         let last_weekday = last_date.weekday();
         
@@ -739,6 +1030,29 @@ impl Periodicity {
         (days_before_last_week_end / 7) as u8
     }
     
+    /// Week of the year of a date (0-indexed) and the year it counts in
+    /// 
+    /// Weeks start on `week_start`. Week 0 is the first week with at least
+    /// 4 days in the year (the one holding 4 January), so early January days
+    /// can be in the last week of the previous year and late December days in
+    /// week 0 of the next. With `Weekday::Mon` these are the ISO 8601 weeks.
+    pub fn week_of_year(date: NaiveDate, week_start: Weekday) -> (i32, u8) {
+        let start = Self::get_week_start(date, week_start);
+        // A week belongs to the year holding its 4th day
+        let year = (start + Duration::days(3)).year();
+        let first_week = NaiveDate::from_ymd_opt(year, 1, 4)
+            .map(|fourth| Self::get_week_start(fourth, week_start))
+            .unwrap_or(start);
+        (year, ((start - first_week).num_days() / 7) as u8)
+    }
+    
+    /// Number of weeks (52 or 53) in a year, counted as in `week_of_year`
+    pub fn weeks_in_year(year: i32, week_start: Weekday) -> u8 {
+        // 28 December is always in the last week of its year
+        NaiveDate::from_ymd_opt(year, 12, 28)
+            .map_or(52, |date| Self::week_of_year(date, week_start).1 + 1)
+    }
+    
     /// Get the total number of complete weeks in a month based on week_start
     /// This is useful for validation and understanding month structure
     pub fn weeks_in_month(year: i32, month: u32, week_start: Weekday) -> u8 {
@@ -766,3 +1080,1095 @@ impl Periodicity {
         ((days_from_first_week_start / 7) + 1) as u8
     }
 }
+
+// ========================================================================
+// OCCURRENCE EXPANSION
+// Lazily enumerates the dates matching a periodicity
+// ========================================================================
+
+/// Earliest date `previous_before` searches back to (same bound as SpecificYears)
+fn expansion_floor() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap()
+}
+
+/// Date (exclusive) `next_after` searches forward to (same bound as SpecificYears)
+fn expansion_ceiling() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2201, 1, 1, 0, 0, 0).unwrap()
+}
+
+impl Periodicity {
+    /// Lazily enumerates the dates matching this periodicity in `[start, end)`
+    /// 
+    /// # Expansion Rules
+    /// - Candidates are `start`, `start + 1 day`, `start + 2 days`, ... (time of day of `start` is kept)
+    /// - A candidate is yielded when it matches the constraints AND is within the timeframe
+    /// - Special patterns yield their own dates that fall within the range instead
+    /// - Dates whose UTC calendar day is in `exceptions` are skipped, or moved
+    ///   by the roll policy (a date just outside the range may move into it)
+    /// - EveryN* patterns without reference_date or timeframe are anchored on `start`
+    /// 
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::entities::task::periodicity::Periodicity;
+    /// use chrono::{TimeZone, Utc, Weekday};
+    /// 
+    /// let periodicity = Periodicity::on_weekdays(vec![Weekday::Mon, Weekday::Fri]).unwrap();
+    /// let start = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2026, 2, 8, 0, 0, 0).unwrap();
+    /// 
+    /// let dates: Vec<_> = periodicity.occurrences_between(start, end, Weekday::Mon).collect();
+    /// assert_eq!(dates, vec![
+    ///     Utc.with_ymd_and_hms(2026, 2, 2, 0, 0, 0).unwrap(), // Monday
+    ///     Utc.with_ymd_and_hms(2026, 2, 6, 0, 0, 0).unwrap(), // Friday
+    /// ]);
+    /// ```
+    pub fn occurrences_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        week_start: Weekday,
+    ) -> Occurrences<'_> {
+        Occurrences::new(self, start, end, week_start, start)
+    }
+    
+    /// Lazily enumerates, in the user's local calendar, the days matching this
+    /// periodicity whose start falls in `[start, end)`
+    /// 
+    /// # Expansion Rules
+    /// - Candidates are the user's logical days (timezone + `day_start`)
+    /// - Each matching day is yielded as the UTC instant it begins, so the
+    ///   UTC offset follows DST transitions
+    /// - Weekday/day/month/year constraints are read on the local date, so
+    ///   "Mondays" never fires on Sunday evening in America/Los_Angeles
+    /// - Special patterns and timeframe behave like `occurrences_between`
+    /// - Days in `exceptions` (local dates) are skipped or moved, as above
+    /// - EveryN* patterns without reference_date or timeframe are anchored on `start`
+    /// 
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::entities::task::periodicity::Periodicity;
+    /// use tsadaash::domain::entities::user::{PasswordHash, Timezone, User};
+    /// use chrono::{TimeZone, Utc, Weekday};
+    /// 
+    /// let user = User::new(
+    ///     "user".to_string(),
+    ///     "user@example.com".to_string(),
+    ///     PasswordHash::locked(),
+    ///     Timezone::new("America/Los_Angeles".to_string()).unwrap(),
+    /// ).unwrap();
+    /// let periodicity = Periodicity::on_weekdays(vec![Weekday::Mon]).unwrap();
+    /// let start = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2026, 2, 8, 0, 0, 0).unwrap();
+    /// 
+    /// let dates: Vec<_> = periodicity.occurrences_between_for(start, end, &user).collect();
+    /// // Monday Feb 2, 00:00 in Los Angeles
+    /// assert_eq!(dates, vec![Utc.with_ymd_and_hms(2026, 2, 2, 8, 0, 0).unwrap()]);
+    /// ```
+    pub fn occurrences_between_for<'a>(
+        &'a self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        user: &'a User,
+    ) -> Occurrences<'a> {
+        Occurrences::in_calendar(self, start, end, user)
+    }
+    
+    /// Returns the first matching date strictly after `date`
+    /// 
+    /// Candidates are whole days after `date` (same time of day), so the result
+    /// can be fed back in to walk the schedule. Returns None when the periodicity
+    /// never fires again (timeframe over, special dates exhausted, or nothing
+    /// before year 2200).
+    pub fn next_after(&self, date: &DateTime<Utc>, week_start: Weekday) -> Option<DateTime<Utc>> {
+        if self.special_pattern.is_some() {
+            return Occurrences::new(self, *date, expansion_ceiling(), week_start, *date).find(|d| d > date);
+        }
+        
+        let start = *date + Duration::days(1);
+        Occurrences::new(self, start, expansion_ceiling(), week_start, *date).next()
+    }
+    
+    /// Returns the last matching date strictly before `date`
+    /// 
+    /// Mirror of `next_after`: candidates are whole days before `date`, and the
+    /// search stops at year 1900.
+    pub fn previous_before(&self, date: &DateTime<Utc>, week_start: Weekday) -> Option<DateTime<Utc>> {
+        if self.special_pattern.is_some() {
+            return Occurrences::new(self, expansion_floor(), *date, week_start, *date).next_back();
+        }
+        
+        let floor = expansion_floor();
+        if *date <= floor {
+            return None;
+        }
+        let start = *date - Duration::days((*date - floor).num_days());
+        Occurrences::new(self, start, *date, week_start, *date).next_back()
+    }
+    
+    /// Returns the start of the user's first logical day on which a
+    /// completion-relative periodicity is due
+    /// 
+    /// The earliest day is `after_completion` units after the logical day of
+    /// `last_completed`; the result is the first day from there that the
+    /// constraints, timeframe and exceptions allow. Without a completion the
+    /// count starts at the reference date, or else the timeframe start.
+    /// Returns None for calendar-anchored periodicities, when nothing anchors
+    /// the first occurrence, or when no day before year 2200 is allowed.
+    pub fn next_due_for(&self, last_completed: Option<&DateTime<Utc>>, user: &User) -> Option<DateTime<Utc>> {
+        let rule = self.after_completion?;
+        let earliest = match last_completed {
+            Some(completed) => rule.due_after(user.logical_date(completed))?,
+            None => {
+                let anchor = self.reference_date.or(self.timeframe.map(|(start, _)| start))?;
+                user.logical_date(&anchor)
+            }
+        };
+        self.occurrences_between_for(user.day_start_instant(earliest), expansion_ceiling(), user).next()
+    }
+}
+
+/// Iterator over the dates matching a Periodicity
+/// 
+/// Created by [`Periodicity::occurrences_between`] and
+/// [`Periodicity::occurrences_between_for`]. Dates are yielded in
+/// chronological order; iterate with `.rev()` for reverse order.
+/// 
+/// Candidates are the scheduled (nominal) days; each is moved by the roll
+/// policy before the range check. Rolling keeps the order of the days, so
+/// occurrences moved onto the same day are yielded once.
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    periodicity: &'a Periodicity,
+    week_start: Weekday,
+    
+    /// Fallback anchor for EveryN* patterns
+    anchor: DateTime<Utc>,
+    
+    /// User whose local calendar is expanded (None = UTC calendar days)
+    calendar: Option<&'a User>,
+    
+    /// Requested range (start_inclusive, end_exclusive)
+    range: (DateTime<Utc>, DateTime<Utc>),
+    
+    cursor: Cursor<'a>,
+    
+    /// Last dates yielded from the front and from the back
+    yielded: (Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+}
+
+#[derive(Debug, Clone)]
+enum Cursor<'a> {
+    /// Remaining dates of a special pattern
+    Dates(std::slice::Iter<'a, DateTime<Utc>>),
+    
+    /// Remaining day candidates (front, back), both inclusive
+    /// None once front and back have crossed
+    Days(Option<(DateTime<Utc>, DateTime<Utc>)>),
+    
+    /// Remaining logical days of the user's calendar (front, back), both inclusive
+    LocalDays(Option<(NaiveDate, NaiveDate)>),
+}
+
+impl<'a> Occurrences<'a> {
+    fn new(
+        periodicity: &'a Periodicity,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        week_start: Weekday,
+        anchor: DateTime<Utc>,
+    ) -> Self {
+        let reach = Self::roll_reach(periodicity);
+        let cursor = match &periodicity.special_pattern {
+            Some(pattern) => Cursor::Dates(pattern.dates().iter()),
+            None => Cursor::Days(Self::day_candidates(periodicity, start - reach, end + reach)),
+        };
+        
+        Self {
+            periodicity,
+            week_start,
+            anchor,
+            calendar: None,
+            range: (start, end),
+            cursor,
+            yielded: (None, None),
+        }
+    }
+    
+    /// Expansion over the user's logical days
+    fn in_calendar(
+        periodicity: &'a Periodicity,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        user: &'a User,
+    ) -> Self {
+        let reach = Self::roll_reach(periodicity);
+        let cursor = match &periodicity.special_pattern {
+            Some(pattern) => Cursor::Dates(pattern.dates().iter()),
+            None => Cursor::LocalDays(
+                Self::local_day_candidates(start, end, user).map(|(front, back)| (front - reach, back + reach)),
+            ),
+        };
+        
+        Self {
+            periodicity,
+            week_start: user.week_start(),
+            anchor: start,
+            calendar: Some(user),
+            range: (start, end),
+            cursor,
+            yielded: (None, None),
+        }
+    }
+    
+    /// How far outside the range scheduled days can be and still move into it
+    fn roll_reach(periodicity: &Periodicity) -> Duration {
+        match periodicity.exceptions.roll {
+            RollPolicy::Skip => Duration::zero(),
+            _ => Duration::days(PeriodicityExceptions::MAX_ROLL_DAYS),
+        }
+    }
+    
+    /// First and last logical days starting in `[start, end)`
+    fn local_day_candidates(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        user: &User,
+    ) -> Option<(NaiveDate, NaiveDate)> {
+        let mut front = user.logical_date(&start);
+        if user.day_start_instant(front) < start {
+            front = front.succ_opt()?;
+        }
+        let mut back = user.logical_date(&end);
+        if user.day_start_instant(back) >= end {
+            back = back.pred_opt()?;
+        }
+        (front <= back).then_some((front, back))
+    }
+    
+    /// First and last day candidates of `[start, end)`, clipped to the timeframe
+    fn day_candidates(
+        periodicity: &Periodicity,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let mut front = start;
+        let mut end = end;
+        
+        if let Some((timeframe_start, timeframe_end)) = periodicity.timeframe {
+            // Skip whole days until the timeframe starts (keeps the time of day of `start`)
+            if front < timeframe_start {
+                let mut days = (timeframe_start - front).num_days();
+                if front + Duration::days(days) < timeframe_start {
+                    days += 1;
+                }
+                front += Duration::days(days);
+            }
+            end = end.min(timeframe_end);
+        }
+        
+        if front >= end {
+            return None;
+        }
+        
+        let mut days = (end - front).num_days();
+        if front + Duration::days(days) >= end {
+            days -= 1;
+        }
+        Some((front, front + Duration::days(days)))
+    }
+    
+    /// Checks a day candidate and returns when it happens, with its nominal day
+    fn day_match(&self, candidate: DateTime<Utc>) -> Option<(DateTime<Utc>, NaiveDate)> {
+        let reference = self.periodicity.get_effective_reference_date(&self.anchor).date_naive();
+        let nominal = candidate.date_naive();
+        if !self.periodicity.matches_calendar_date(nominal, self.week_start, reference) {
+            return None;
+        }
+        let day = self.periodicity.exceptions.roll(nominal)?;
+        let date = candidate + day.signed_duration_since(nominal);
+        self.is_in_range(&date).then_some((date, nominal))
+    }
+    
+    /// Checks a logical day and returns the instant it happens on (the start
+    /// of the day it is moved to), with the logical day itself
+    fn local_match(&self, nominal: NaiveDate) -> Option<(DateTime<Utc>, NaiveDate)> {
+        let user = self.calendar?;
+        let reference = user.logical_date(&self.periodicity.get_effective_reference_date(&self.anchor));
+        if !self.periodicity.matches_calendar_date(nominal, user.week_start(), reference)
+            || !self.periodicity.is_within_timeframe(&user.day_start_instant(nominal))
+        {
+            return None;
+        }
+        let day = self.periodicity.exceptions.roll(nominal)?;
+        let instant = user.day_start_instant(day);
+        self.is_in_range(&instant).then_some((instant, nominal))
+    }
+    
+    /// Checks a special pattern date against the timeframe and exceptions, on
+    /// its logical day when expanding a user's calendar
+    fn date_match(&self, date: &DateTime<Utc>) -> Option<(DateTime<Utc>, NaiveDate)> {
+        if !self.periodicity.is_within_timeframe(date) {
+            return None;
+        }
+        let nominal = self.calendar.map_or_else(|| date.date_naive(), |user| user.logical_date(date));
+        let day = self.periodicity.exceptions.roll(nominal)?;
+        let date = *date + day.signed_duration_since(nominal);
+        self.is_in_range(&date).then_some((date, nominal))
+    }
+    
+    fn is_in_range(&self, date: &DateTime<Utc>) -> bool {
+        *date >= self.range.0 && *date < self.range.1
+    }
+    
+    /// Next date with the day it was scheduled for (the same day unless the
+    /// roll policy moved it)
+    pub fn next_with_nominal(&mut self) -> Option<(DateTime<Utc>, NaiveDate)> {
+        while let Some(found) = self.front_match() {
+            if self.yielded.0 == Some(found.0) {
+                continue;
+            }
+            if self.yielded.1.is_some_and(|back| found.0 >= back) {
+                self.cursor = Cursor::Days(None);
+                return None;
+            }
+            self.yielded.0 = Some(found.0);
+            return Some(found);
+        }
+        None
+    }
+    
+    /// Mirror of `next_with_nominal`, from the back
+    pub fn next_back_with_nominal(&mut self) -> Option<(DateTime<Utc>, NaiveDate)> {
+        while let Some(found) = self.back_match() {
+            if self.yielded.1 == Some(found.0) {
+                continue;
+            }
+            if self.yielded.0.is_some_and(|front| found.0 <= front) {
+                self.cursor = Cursor::Days(None);
+                return None;
+            }
+            self.yielded.1 = Some(found.0);
+            return Some(found);
+        }
+        None
+    }
+    
+    /// Advances the cursor to the next matching candidate from the front
+    fn front_match(&mut self) -> Option<(DateTime<Utc>, NaiveDate)> {
+        match &mut self.cursor {
+            Cursor::Dates(_) => {
+                while let Cursor::Dates(dates) = &mut self.cursor {
+                    let date = dates.next()?;
+                    if let Some(found) = self.date_match(date) {
+                        return Some(found);
+                    }
+                }
+                None
+            }
+            Cursor::Days(_) => {
+                while let Cursor::Days(Some((front, back))) = self.cursor {
+                    self.cursor = Cursor::Days(
+                        (front < back).then(|| (front + Duration::days(1), back)),
+                    );
+                    if let Some(found) = self.day_match(front) {
+                        return Some(found);
+                    }
+                }
+                None
+            }
+            Cursor::LocalDays(_) => {
+                while let Cursor::LocalDays(Some((front, back))) = self.cursor {
+                    self.cursor = Cursor::LocalDays(
+                        (front < back).then(|| (front + Duration::days(1), back)),
+                    );
+                    if let Some(found) = self.local_match(front) {
+                        return Some(found);
+                    }
+                }
+                None
+            }
+        }
+    }
+    
+    /// Advances the cursor to the next matching candidate from the back
+    fn back_match(&mut self) -> Option<(DateTime<Utc>, NaiveDate)> {
+        match &mut self.cursor {
+            Cursor::Dates(_) => {
+                while let Cursor::Dates(dates) = &mut self.cursor {
+                    let date = dates.next_back()?;
+                    if let Some(found) = self.date_match(date) {
+                        return Some(found);
+                    }
+                }
+                None
+            }
+            Cursor::Days(_) => {
+                while let Cursor::Days(Some((front, back))) = self.cursor {
+                    self.cursor = Cursor::Days(
+                        (front < back).then(|| (front, back - Duration::days(1))),
+                    );
+                    if let Some(found) = self.day_match(back) {
+                        return Some(found);
+                    }
+                }
+                None
+            }
+            Cursor::LocalDays(_) => {
+                while let Cursor::LocalDays(Some((front, back))) = self.cursor {
+                    self.cursor = Cursor::LocalDays(
+                        (front < back).then(|| (front, back - Duration::days(1))),
+                    );
+                    if let Some(found) = self.local_match(back) {
+                        return Some(found);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = DateTime<Utc>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_nominal().map(|(date, _)| date)
+    }
+}
+
+impl DoubleEndedIterator for Occurrences<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_back_with_nominal().map(|(date, _)| date)
+    }
+}
+
+impl std::iter::FusedIterator for Occurrences<'_> {}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::builders::PeriodicityBuilder;
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn user_with_day_start(timezone: &str, hour: u32) -> User {
+        use crate::domain::entities::user::{PasswordHash, Timezone};
+        let mut user = User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new(timezone.to_string()).unwrap(),
+        ).unwrap();
+        user.set_day_start(NaiveTime::from_hms_opt(hour, 0, 0).unwrap());
+        user
+    }
+
+    // ── matches_constraints_for ─────────────────────────────
+
+    #[test]
+    fn test_matches_for_user_shifts_by_day_start() {
+        let periodicity = Periodicity::on_days_of_month(vec![7]).unwrap();
+        let user = user_with_day_start("Europe/London", 5);
+
+        // Feb 8 02:00 counts toward Feb 7
+        let night = Utc.with_ymd_and_hms(2026, 2, 8, 2, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints_for(&night, &user));
+        assert!(!periodicity.matches_constraints(&night, Weekday::Mon));
+
+        // Feb 7 04:00 still belongs to Feb 6
+        let early = Utc.with_ymd_and_hms(2026, 2, 7, 4, 0, 0).unwrap();
+        assert!(!periodicity.matches_constraints_for(&early, &user));
+    }
+
+    #[test]
+    fn test_matches_for_user_month_boundary() {
+        let periodicity = PeriodicityBuilder::new()
+            .monthly(1)
+            .in_months(vec![Month::January])
+            .build()
+            .unwrap();
+        let user = user_with_day_start("Europe/London", 5);
+
+        // Feb 1 03:00 is still the last logical day of January
+        let instant = Utc.with_ymd_and_hms(2026, 2, 1, 3, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints_for(&instant, &user));
+    }
+
+    #[test]
+    fn test_matches_for_user_special_pattern() {
+        let periodicity = Periodicity::unique(Utc.with_ymd_and_hms(2026, 2, 7, 12, 0, 0).unwrap()).unwrap();
+        let user = user_with_day_start("Europe/London", 5);
+
+        let completion = Utc.with_ymd_and_hms(2026, 2, 8, 1, 30, 0).unwrap();
+        assert!(periodicity.matches_constraints_for(&completion, &user));
+        assert!(!periodicity.matches_constraints_for(&utc(2026, 2, 9), &user));
+    }
+
+    // ── occurrences_between_for ─────────────────────────────
+
+    #[test]
+    fn test_local_occurrences_use_local_weekday() {
+        let periodicity = Periodicity::on_weekdays(vec![Weekday::Mon]).unwrap();
+        let user = user_with_day_start("America/Los_Angeles", 0);
+
+        // Monday 03:00 UTC is Sunday evening in Los Angeles
+        let monday_utc = Utc.with_ymd_and_hms(2026, 2, 9, 3, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&monday_utc, Weekday::Mon));
+        assert!(!periodicity.matches_constraints_for(&monday_utc, &user));
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between_for(utc(2026, 2, 8), utc(2026, 2, 10), &user)
+            .collect();
+        assert_eq!(dates, vec![Utc.with_ymd_and_hms(2026, 2, 9, 8, 0, 0).unwrap()]);
+    }
+
+    #[test]
+    fn test_local_occurrences_across_paris_spring_forward() {
+        let periodicity = Periodicity::daily().unwrap();
+        let user = user_with_day_start("Europe/Paris", 0);
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between_for(utc(2026, 3, 28), utc(2026, 3, 31), &user)
+            .collect();
+        assert_eq!(dates, vec![
+            Utc.with_ymd_and_hms(2026, 3, 28, 23, 0, 0).unwrap(), // Mar 29, UTC+1
+            Utc.with_ymd_and_hms(2026, 3, 29, 22, 0, 0).unwrap(), // Mar 30, UTC+2
+            Utc.with_ymd_and_hms(2026, 3, 30, 22, 0, 0).unwrap(), // Mar 31
+        ]);
+    }
+
+    #[test]
+    fn test_local_occurrences_across_paris_fall_back() {
+        let periodicity = Periodicity::on_weekdays(vec![Weekday::Sun]).unwrap();
+        let user = user_with_day_start("Europe/Paris", 8);
+
+        // Clocks go back on Sunday 2026-10-25: 08:00 is UTC+2 before, UTC+1 after
+        let dates: Vec<_> = periodicity
+            .occurrences_between_for(utc(2026, 10, 17), utc(2026, 11, 2), &user)
+            .collect();
+        assert_eq!(dates, vec![
+            Utc.with_ymd_and_hms(2026, 10, 18, 6, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 10, 25, 7, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 11, 1, 7, 0, 0).unwrap(),
+        ]);
+    }
+
+    #[test]
+    fn test_local_occurrences_new_york_month_days_and_reverse() {
+        let periodicity = Periodicity::on_days_of_month(vec![8]).unwrap();
+        let user = user_with_day_start("America/New_York", 0);
+
+        // 2026-03-08 is the spring-forward day in New York
+        let dates: Vec<_> = periodicity
+            .occurrences_between_for(utc(2026, 2, 1), utc(2026, 4, 1), &user)
+            .rev()
+            .collect();
+        assert_eq!(dates, vec![
+            Utc.with_ymd_and_hms(2026, 3, 8, 5, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 8, 5, 0, 0).unwrap(),
+        ]);
+    }
+
+    // ── occurrences_between ─────────────────────────────────
+
+    #[test]
+    fn test_occurrences_daily_every_day() {
+        let periodicity = Periodicity::daily().unwrap();
+        let dates: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 2, 1), utc(2026, 2, 8), Weekday::Mon)
+            .collect();
+
+        assert_eq!(dates.len(), 7);
+        assert_eq!(dates[0], utc(2026, 2, 1));
+        assert_eq!(dates[6], utc(2026, 2, 7));
+    }
+
+    #[test]
+    fn test_occurrences_keep_time_of_day_of_start() {
+        let periodicity = Periodicity::daily().unwrap();
+        let start = Utc.with_ymd_and_hms(2026, 2, 1, 9, 30, 0).unwrap();
+        let dates: Vec<_> = periodicity
+            .occurrences_between(start, utc(2026, 2, 3), Weekday::Mon)
+            .collect();
+
+        assert_eq!(dates, vec![
+            start,
+            Utc.with_ymd_and_hms(2026, 2, 2, 9, 30, 0).unwrap(),
+        ]);
+    }
+
+    #[test]
+    fn test_occurrences_13th_24th_jan_feb() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![13, 24])
+            .in_months(vec![Month::January, Month::February])
+            .build()
+            .unwrap();
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 1, 1), utc(2027, 1, 1), Weekday::Mon)
+            .collect();
+
+        assert_eq!(dates, vec![
+            utc(2026, 1, 13),
+            utc(2026, 1, 24),
+            utc(2026, 2, 13),
+            utc(2026, 2, 24),
+        ]);
+    }
+
+    #[test]
+    fn test_occurrences_last_friday_of_month() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::last(Weekday::Fri)])
+            .build()
+            .unwrap();
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 1, 1), utc(2026, 4, 1), Weekday::Mon)
+            .collect();
+
+        assert_eq!(dates, vec![utc(2026, 1, 30), utc(2026, 2, 27), utc(2026, 3, 27)]);
+    }
+
+    #[test]
+    fn test_occurrences_clipped_to_timeframe() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .between(
+                Utc.with_ymd_and_hms(2026, 2, 10, 12, 0, 0).unwrap(),
+                utc(2026, 2, 13),
+            )
+            .build()
+            .unwrap();
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 2, 1), utc(2026, 3, 1), Weekday::Mon)
+            .collect();
+
+        // Feb 10 00:00 is before the timeframe start (12:00)
+        assert_eq!(dates, vec![utc(2026, 2, 11), utc(2026, 2, 12)]);
+    }
+
+    #[test]
+    fn test_occurrences_every_n_days_with_reference_date() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_days(3)
+            .with_reference_date(utc(2026, 1, 1))
+            .build()
+            .unwrap();
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 1, 2), utc(2026, 1, 12), Weekday::Mon)
+            .collect();
+
+        assert_eq!(dates, vec![utc(2026, 1, 4), utc(2026, 1, 7), utc(2026, 1, 10)]);
+    }
+
+    #[test]
+    fn test_occurrences_every_n_days_anchored_on_range_start() {
+        // No reference date or timeframe: intervals are counted from the range start
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_days(3)
+            .build()
+            .unwrap();
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 3, 5), utc(2026, 3, 12), Weekday::Mon)
+            .collect();
+
+        assert_eq!(dates, vec![utc(2026, 3, 5), utc(2026, 3, 8), utc(2026, 3, 11)]);
+    }
+
+    #[test]
+    fn test_occurrences_every_n_weeks() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Tue])
+            .every_n_weeks(2)
+            .with_reference_date(utc(2026, 1, 5))
+            .build()
+            .unwrap();
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 1, 1), utc(2026, 2, 1), Weekday::Mon)
+            .collect();
+
+        assert_eq!(dates, vec![utc(2026, 1, 6), utc(2026, 1, 20)]);
+    }
+
+    #[test]
+    fn test_week_of_year_matches_iso_weeks() {
+        let mut date = NaiveDate::from_ymd_opt(2019, 12, 1).unwrap();
+        while date.year() < 2033 {
+            let iso = date.iso_week();
+            assert_eq!(Periodicity::week_of_year(date, Weekday::Mon), (iso.year(), (iso.week() - 1) as u8), "{}", date);
+            date = date.succ_opt().unwrap();
+        }
+        
+        // 2020, 2026 and 2032 have 53 ISO weeks
+        let long_years: Vec<_> = (2019..=2033).filter(|year| Periodicity::weeks_in_year(*year, Weekday::Mon) == 53).collect();
+        assert_eq!(long_years, vec![2020, 2026, 2032]);
+    }
+    
+    #[test]
+    fn test_week_of_year_with_sunday_week_start() {
+        let day = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        // Sun 28 Dec 2025 - Sat 3 Jan 2026 has 3 days in 2026: the 53rd week of 2025
+        assert_eq!(Periodicity::week_of_year(day(2025, 12, 28), Weekday::Sun), (2025, 52));
+        assert_eq!(Periodicity::week_of_year(day(2026, 1, 3), Weekday::Sun), (2025, 52));
+        assert_eq!(Periodicity::week_of_year(day(2026, 1, 4), Weekday::Sun), (2026, 0));
+        // Sun 27 Dec 2026 - Sat 2 Jan 2027 has 5 days in 2026
+        assert_eq!(Periodicity::week_of_year(day(2027, 1, 2), Weekday::Sun), (2026, 51));
+        assert_eq!(Periodicity::week_of_year(day(2027, 1, 3), Weekday::Sun), (2027, 0));
+        assert_eq!(Periodicity::weeks_in_year(2025, Weekday::Sun), 53);
+        assert_eq!(Periodicity::weeks_in_year(2026, Weekday::Sun), 52);
+    }
+    
+    #[test]
+    fn test_occurrences_in_iso_weeks_across_53_week_years() {
+        let mondays = |builder: PeriodicityBuilder, from: DateTime<Utc>, to: DateTime<Utc>| -> Vec<DateTime<Utc>> {
+            builder.on_weekdays(vec![Weekday::Mon]).build().unwrap().occurrences_between(from, to, Weekday::Sun).collect()
+        };
+        let daily = || PeriodicityBuilder::new().daily(1);
+        
+        // W53 only exists in 2026 (Mon 28 December) and 2032 (Mon 27 December)
+        assert_eq!(
+            mondays(daily().on_iso_weeks(vec![53]), utc(2026, 1, 1), utc(2033, 1, 1)),
+            vec![utc(2026, 12, 28), utc(2032, 12, 27)],
+        );
+        
+        // W53 and the next W01 are both odd
+        assert_eq!(
+            mondays(daily().in_odd_iso_weeks(), utc(2026, 12, 14), utc(2027, 1, 19)),
+            vec![utc(2026, 12, 14), utc(2026, 12, 28), utc(2027, 1, 4), utc(2027, 1, 18)],
+        );
+        assert_eq!(
+            mondays(daily().in_even_iso_weeks(), utc(2026, 12, 14), utc(2027, 1, 19)),
+            vec![utc(2026, 12, 21), utc(2027, 1, 11)],
+        );
+        
+        // Weeks of the year follow the user's week start
+        let first_week = |week_start: Weekday| -> Vec<DateTime<Utc>> {
+            daily().on_weeks_of_year(vec![1]).build().unwrap()
+                .occurrences_between(utc(2025, 12, 25), utc(2026, 1, 15), week_start)
+                .collect()
+        };
+        assert_eq!(first_week(Weekday::Mon), (29..=31).map(|d| utc(2025, 12, d)).chain((1..=4).map(|d| utc(2026, 1, d))).collect::<Vec<_>>());
+        assert_eq!(first_week(Weekday::Sun), (4..=10).map(|d| utc(2026, 1, d)).collect::<Vec<_>>());
+    }
+    
+    #[test]
+    fn test_occurrences_special_pattern_dates() {
+        let periodicity = PeriodicityBuilder::new()
+            .custom_dates(vec![
+                Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 1, 15, 18, 30, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 6, 1, 8, 0, 0).unwrap(),
+            ])
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let dates: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 1, 1), utc(2026, 4, 1), Weekday::Mon)
+            .collect();
+
+        assert_eq!(dates, vec![
+            Utc.with_ymd_and_hms(2026, 1, 15, 18, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 0).unwrap(),
+        ]);
+    }
+
+    #[test]
+    fn test_occurrences_reversed() {
+        let periodicity = Periodicity::on_weekdays(vec![Weekday::Mon, Weekday::Thu]).unwrap();
+        let forward: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 2, 1), utc(2026, 3, 1), Weekday::Mon)
+            .collect();
+        let mut backward: Vec<_> = periodicity
+            .occurrences_between(utc(2026, 2, 1), utc(2026, 3, 1), Weekday::Mon)
+            .rev()
+            .collect();
+        backward.reverse();
+
+        assert_eq!(forward.len(), 8);
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn test_occurrences_empty_range() {
+        let periodicity = Periodicity::daily().unwrap();
+        let mut occurrences = periodicity.occurrences_between(utc(2026, 2, 8), utc(2026, 2, 1), Weekday::Mon);
+        assert_eq!(occurrences.next(), None);
+    }
+
+    // ── next_after / previous_before ────────────────────────
+
+    #[test]
+    fn test_next_after_and_previous_before() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::first(Weekday::Mon)])
+            .build()
+            .unwrap();
+
+        let date = utc(2026, 2, 2); // first Monday of February
+        assert_eq!(periodicity.next_after(&date, Weekday::Mon), Some(utc(2026, 3, 2)));
+        assert_eq!(periodicity.previous_before(&date, Weekday::Mon), Some(utc(2026, 1, 5)));
+    }
+
+    #[test]
+    fn test_next_after_sparse_yearly_pattern() {
+        // Feb 29 only happens in leap years
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![29])
+            .in_months(vec![Month::February])
+            .build()
+            .unwrap();
+
+        assert_eq!(periodicity.next_after(&utc(2026, 1, 1), Weekday::Mon), Some(utc(2028, 2, 29)));
+        assert_eq!(periodicity.previous_before(&utc(2026, 1, 1), Weekday::Mon), Some(utc(2024, 2, 29)));
+    }
+
+    #[test]
+    fn test_next_after_respects_timeframe_end() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon])
+            .between(utc(2026, 1, 1), utc(2026, 2, 1))
+            .build()
+            .unwrap();
+
+        assert_eq!(periodicity.next_after(&utc(2026, 1, 20), Weekday::Mon), Some(utc(2026, 1, 26)));
+        assert_eq!(periodicity.next_after(&utc(2026, 1, 26), Weekday::Mon), None);
+        assert_eq!(periodicity.previous_before(&utc(2026, 1, 5), Weekday::Mon), None);
+    }
+
+    #[test]
+    fn test_next_after_special_pattern() {
+        let date = Utc.with_ymd_and_hms(2026, 12, 25, 18, 0, 0).unwrap();
+        let periodicity = Periodicity::unique(date).unwrap();
+
+        assert_eq!(periodicity.next_after(&utc(2026, 12, 25), Weekday::Mon), Some(date));
+        assert_eq!(periodicity.next_after(&date, Weekday::Mon), None);
+        assert_eq!(periodicity.previous_before(&utc(2027, 1, 1), Weekday::Mon), Some(date));
+    }
+
+    #[test]
+    fn test_exceptions_skip_dates_and_holidays() {
+        let day = |month, day| NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+        let holidays = HolidayCalendar::new("fr-public".to_string(), vec![day(5, 1), day(5, 8)]).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Fri])
+            .except(vec![day(5, 15)])
+            .skip_holidays(holidays)
+            .build()
+            .unwrap();
+
+        assert!(!periodicity.matches_constraints(&utc(2026, 5, 1), Weekday::Mon));
+        assert!(periodicity.matches_constraints(&utc(2026, 5, 22), Weekday::Mon));
+
+        let fridays: Vec<_> = periodicity.occurrences_between(utc(2026, 5, 1), utc(2026, 6, 1), Weekday::Mon).collect();
+        assert_eq!(fridays, vec![utc(2026, 5, 22), utc(2026, 5, 29)]);
+        assert_eq!(periodicity.next_after(&utc(2026, 4, 30), Weekday::Mon), Some(utc(2026, 5, 22)));
+        assert_eq!(periodicity.previous_before(&utc(2026, 5, 22), Weekday::Mon), Some(utc(2026, 4, 24)));
+    }
+
+    #[test]
+    fn test_exceptions_use_the_users_calendar_day() {
+        let day = |month, day| NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+        let user = user_with_day_start("America/Los_Angeles", 0);
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .except(vec![day(5, 1)])
+            .build()
+            .unwrap();
+
+        // 2026-05-02 00:00 UTC is still May 1st in Los Angeles
+        assert!(!periodicity.matches_constraints_for(&utc(2026, 5, 2), &user));
+        assert!(periodicity.matches_constraints(&utc(2026, 5, 2), Weekday::Mon));
+
+        let days: Vec<_> = periodicity
+            .occurrences_between_for(utc(2026, 4, 30), utc(2026, 5, 3), &user)
+            .map(|occurrence| user.logical_date(&occurrence))
+            .collect();
+        assert_eq!(days, vec![day(4, 30), day(5, 2)]);
+    }
+
+    #[test]
+    fn test_exceptions_filter_special_patterns() {
+        let day = |month, day| NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .custom_dates(vec![utc(2026, 5, 1), utc(2026, 5, 8)])
+            .unwrap()
+            .except(vec![day(5, 1)])
+            .build()
+            .unwrap();
+
+        assert!(!periodicity.matches_constraints(&utc(2026, 5, 1), Weekday::Mon));
+        let dates: Vec<_> = periodicity.occurrences_between(utc(2026, 1, 1), utc(2027, 1, 1), Weekday::Mon).collect();
+        assert_eq!(dates, vec![utc(2026, 5, 8)]);
+        assert_eq!(periodicity.next_after(&utc(2026, 4, 1), Weekday::Mon), Some(utc(2026, 5, 8)));
+        assert_eq!(periodicity.previous_before(&utc(2026, 5, 8), Weekday::Mon), None);
+    }
+
+    // ── roll policy ─────────────────────────────────────────
+
+    #[test]
+    fn test_roll_policies_move_weekend_and_holiday_dates() {
+        let day = |month, day| NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+        let first_of_month = |policy| {
+            PeriodicityBuilder::new()
+                .daily(1)
+                .on_month_days(vec![1])
+                .skip_holidays(HolidayCalendar::new("fr-public".to_string(), vec![day(5, 1)]).unwrap())
+                .with_roll_policy(policy)
+                .build()
+                .unwrap()
+        };
+        // May 1st is a Friday holiday, Aug 1st a Saturday, Nov 1st a Sunday
+        let days = |policy| -> Vec<_> {
+            first_of_month(policy)
+                .occurrences_between(utc(2026, 4, 1), utc(2026, 12, 1), Weekday::Mon)
+                .map(|date| date.date_naive())
+                .collect()
+        };
+
+        assert_eq!(
+            days(RollPolicy::PreviousBusinessDay),
+            vec![day(4, 1), day(4, 30), day(6, 1), day(7, 1), day(7, 31), day(9, 1), day(10, 1), day(10, 30)]
+        );
+        assert_eq!(
+            days(RollPolicy::NextBusinessDay),
+            vec![day(4, 1), day(5, 4), day(6, 1), day(7, 1), day(8, 3), day(9, 1), day(10, 1), day(11, 2)]
+        );
+        assert_eq!(
+            days(RollPolicy::NearestBusinessDay),
+            vec![day(4, 1), day(4, 30), day(6, 1), day(7, 1), day(7, 31), day(9, 1), day(10, 1), day(11, 2)]
+        );
+        // Skipping only drops the holiday: weekends are ordinary days
+        assert_eq!(
+            days(RollPolicy::Skip),
+            vec![day(4, 1), day(6, 1), day(7, 1), day(8, 1), day(9, 1), day(10, 1), day(11, 1)]
+        );
+
+        // Matching follows the moved day
+        let rent = first_of_month(RollPolicy::PreviousBusinessDay);
+        assert!(rent.matches_constraints(&utc(2026, 7, 31), Weekday::Mon));
+        assert!(!rent.matches_constraints(&utc(2026, 8, 1), Weekday::Mon));
+        assert!(rent.matches_constraints(&utc(2026, 6, 1), Weekday::Mon));
+        assert_eq!(rent.next_after(&utc(2026, 7, 1), Weekday::Mon), Some(utc(2026, 7, 31)));
+        assert_eq!(rent.previous_before(&utc(2026, 8, 2), Weekday::Mon), Some(utc(2026, 7, 31)));
+    }
+
+    #[test]
+    fn test_roll_policy_moves_dates_into_the_range() {
+        let rent = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1])
+            .with_roll_policy(RollPolicy::PreviousBusinessDay)
+            .build()
+            .unwrap();
+
+        // Scheduled on Saturday Aug 1st, after the end of the range
+        let mut july = rent.occurrences_between(utc(2026, 7, 2), utc(2026, 8, 1), Weekday::Mon);
+        assert_eq!(july.next_with_nominal(), Some((utc(2026, 7, 31), NaiveDate::from_ymd_opt(2026, 8, 1).unwrap())));
+        assert_eq!(july.next(), None);
+    }
+
+    #[test]
+    fn test_rolled_dates_landing_on_the_same_day_are_yielded_once() {
+        let daily = PeriodicityBuilder::new()
+            .daily(1)
+            .with_roll_policy(RollPolicy::NextBusinessDay)
+            .build()
+            .unwrap();
+        // Friday Jan 9 to Tuesday Jan 13: the weekend moves onto Monday
+        let expected = vec![utc(2026, 1, 9), utc(2026, 1, 12), utc(2026, 1, 13)];
+
+        let forward: Vec<_> = daily.occurrences_between(utc(2026, 1, 9), utc(2026, 1, 14), Weekday::Mon).collect();
+        assert_eq!(forward, expected);
+        let mut backward: Vec<_> = daily.occurrences_between(utc(2026, 1, 9), utc(2026, 1, 14), Weekday::Mon).rev().collect();
+        backward.reverse();
+        assert_eq!(backward, expected);
+
+        // Meeting in the middle doesn't yield Monday twice
+        let mut both = daily.occurrences_between(utc(2026, 1, 10), utc(2026, 1, 13), Weekday::Mon);
+        assert_eq!(both.next_back(), Some(utc(2026, 1, 12)));
+        assert_eq!(both.next(), None);
+    }
+
+    #[test]
+    fn test_roll_policy_in_local_calendar_and_special_patterns() {
+        let user = user_with_day_start("America/Los_Angeles", 0);
+        let saturday = Utc.with_ymd_and_hms(2026, 8, 1, 18, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .unique(saturday)
+            .with_roll_policy(RollPolicy::NextBusinessDay)
+            .build()
+            .unwrap();
+
+        let monday = Utc.with_ymd_and_hms(2026, 8, 3, 18, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&monday, Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&saturday, Weekday::Mon));
+        assert_eq!(periodicity.next_after(&utc(2026, 7, 1), Weekday::Mon), Some(monday));
+
+        // 18:00 UTC is still the same day in Los Angeles
+        assert!(periodicity.matches_constraints_for(&monday, &user));
+        let dates: Vec<_> = periodicity.occurrences_between_for(utc(2026, 8, 1), utc(2026, 8, 8), &user).collect();
+        assert_eq!(dates, vec![monday]);
+    }
+
+    // ── completion-relative recurrence ──────────────────────
+
+    #[test]
+    fn test_after_completion_due_day() {
+        let day = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let rule = |interval, unit| AfterCompletion { interval, unit };
+
+        assert_eq!(rule(4, RepetitionUnit::Day).due_after(day(2026, 6, 29)), Some(day(2026, 7, 3)));
+        assert_eq!(rule(2, RepetitionUnit::Week).due_after(day(2026, 6, 29)), Some(day(2026, 7, 13)));
+        assert_eq!(rule(1, RepetitionUnit::Month).due_after(day(2026, 1, 31)), Some(day(2026, 2, 28)));
+        assert_eq!(rule(1, RepetitionUnit::Year).due_after(day(2028, 2, 29)), Some(day(2029, 2, 28)));
+        assert_eq!(rule(1, RepetitionUnit::None).due_after(day(2026, 1, 1)), None);
+    }
+
+    #[test]
+    fn test_next_due_counts_from_the_last_completion() {
+        let user = user_with_day_start("Europe/London", 5);
+        let watering = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri])
+            .after_completion(4, RepetitionUnit::Day)
+            .with_reference_date(utc(2026, 6, 1))
+            .build()
+            .unwrap();
+        let day_start = |day| Utc.with_ymd_and_hms(2026, 6, day, 4, 0, 0).unwrap();
+
+        // Never done: due from the reference date
+        assert_eq!(watering.next_due_for(None, &user), Some(day_start(1)));
+
+        // Done Tuesday 2nd: due Saturday 6th, moved to Monday 8th by the weekdays
+        let completed = Utc.with_ymd_and_hms(2026, 6, 2, 18, 0, 0).unwrap();
+        assert_eq!(watering.next_due_for(Some(&completed), &user), Some(day_start(8)));
+
+        // Done at 02:00 (BST) on Thursday 4th still counts as Wednesday 3rd
+        let late_night = Utc.with_ymd_and_hms(2026, 6, 4, 1, 0, 0).unwrap();
+        assert_eq!(watering.next_due_for(Some(&late_night), &user), Some(day_start(8)));
+
+        assert_eq!(Periodicity::daily().unwrap().next_due_for(Some(&completed), &user), None);
+    }
+}
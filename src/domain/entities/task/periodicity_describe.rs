@@ -0,0 +1,680 @@
+use std::fmt;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+use crate::domain::entities::task::periodicity::*;
+use super::periodicity_rrule::{open_end, open_start};
+
+// ========================================================================
+// HUMAN-READABLE DESCRIPTION
+// "3 times a day on Mondays in January", in a pluggable wording
+// ========================================================================
+//
+// `Periodicity::describe_with` splits a periodicity into parts and has a
+// `PeriodicityWording` phrase each of them, then assemble the sentence:
+// - schedule: the frequency, then the day, week, month and year filters
+//   (a rolling pattern on the repetition unit itself joins the frequency:
+//   "once every 2 weeks"), or the dates of a special pattern; the filters of
+//   alternative constraint sets are joined by the wording (with their own
+//   frequency when a rolling pattern makes it differ)
+// - clauses: completion-relative count, occurrence timing, exceptions and
+//   timeframe
+//
+// Dates are the UTC calendar days stored in the periodicity. The reference
+// date only anchors rolling patterns and isn't described.
+//
+// `English` is the default wording (`describe`, `Display`); other languages
+// implement `PeriodicityWording`.
+
+/// Phrases the parts of a periodicity description in one language
+pub trait PeriodicityWording {
+    /// How many times per repetition unit ("3 times a day", "once every 2 weeks")
+    /// `every` is the interval of a rolling pattern on the repetition unit
+    fn frequency(&self, count: u8, unit: RepetitionUnit, every: Option<u16>) -> String;
+
+    /// None when the constraint lets every day through (e.g. `EveryDay`)
+    fn day_constraint(&self, constraint: &DayConstraint) -> Option<String>;
+
+    /// None when the constraint lets every week through
+    fn week_constraint(&self, constraint: &WeekConstraint) -> Option<String>;
+
+    /// None when the constraint lets every month through
+    fn month_constraint(&self, constraint: &MonthConstraint) -> Option<String>;
+
+    /// None when the constraint lets every year through
+    fn year_constraint(&self, constraint: &YearConstraint) -> Option<String>;
+
+    /// Joins the phrases of alternative constraint sets ("... or ...")
+    fn alternatives(&self, sets: Vec<String>) -> String;
+
+    /// The dates of a one-off or custom schedule
+    fn special_pattern(&self, pattern: &SpecialPattern) -> String;
+
+    /// When the next occurrence is due after a completion
+    fn after_completion(&self, rule: &AfterCompletion) -> String;
+
+    /// None when no setting is set
+    fn timing(&self, settings: &OccurrenceTimingSettings) -> Option<String>;
+
+    /// None when nothing is excluded or moved
+    fn exceptions(&self, exceptions: &PeriodicityExceptions) -> Option<String>;
+
+    /// First and last included days (None for an open end, never both)
+    fn timeframe(&self, first: Option<NaiveDate>, last: Option<NaiveDate>) -> String;
+
+    /// Assembles the schedule phrases and the clauses
+    fn sentence(&self, schedule: Vec<String>, clauses: Vec<String>) -> String;
+}
+
+impl Periodicity {
+    /// Describes the periodicity in English
+    ///
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::builders::periodicity_builder::PeriodicityBuilder;
+    /// use chrono::{Month, Weekday};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let periodicity = PeriodicityBuilder::new()
+    ///     .daily(3)
+    ///     .on_weekdays(vec![Weekday::Mon])
+    ///     .in_months(vec![Month::January])
+    ///     .build()?;
+    ///
+    /// assert_eq!(periodicity.describe(), "3 times a day on Mondays in January");
+    /// assert_eq!(periodicity.to_string(), periodicity.describe());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn describe(&self) -> String {
+        self.describe_with(&English)
+    }
+
+    /// Describes the periodicity with the given wording
+    pub fn describe_with(&self, wording: &dyn PeriodicityWording) -> String {
+        let mut schedule = Vec::new();
+        match &self.special_pattern {
+            Some(pattern) => schedule.push(wording.special_pattern(pattern)),
+            None => {
+                let mut sets: Vec<_> = self.constraint_sets().map(|constraints| self.set_phrases(constraints, wording)).collect();
+                if sets.len() == 1 {
+                    let (frequency, filters) = sets.remove(0);
+                    schedule.push(frequency);
+                    schedule.extend(filters);
+                } else if sets.iter().all(|(frequency, _)| *frequency == sets[0].0) {
+                    // "once a day on the 1st of the month or on Fridays"
+                    schedule.push(sets[0].0.clone());
+                    schedule.push(wording.alternatives(sets.into_iter().map(|(_, filters)| filters.join(" ")).collect()));
+                } else {
+                    schedule.push(wording.alternatives(
+                        sets.into_iter().map(|(frequency, filters)| [vec![frequency], filters].concat().join(" ")).collect(),
+                    ));
+                }
+            }
+        }
+
+        let mut clauses = Vec::new();
+        if let Some(rule) = &self.after_completion {
+            clauses.push(wording.after_completion(rule));
+        }
+        clauses.extend(self.occurrence_settings.as_ref().and_then(|s| wording.timing(s)));
+        clauses.extend(wording.exceptions(&self.exceptions));
+        if let Some((start, end)) = self.timeframe {
+            let first = (start > open_start()).then(|| start.date_naive());
+            let last = (end < open_end()).then(|| (end - Duration::seconds(1)).date_naive());
+            if first.is_some() || last.is_some() {
+                clauses.push(wording.timeframe(first, last));
+            }
+        }
+
+        wording.sentence(schedule, clauses)
+    }
+}
+
+impl Periodicity {
+    /// Frequency and filters of one constraint set
+    fn set_phrases(&self, constraints: &PeriodicityConstraints, wording: &dyn PeriodicityWording) -> (String, Vec<String>) {
+        let every = rolling_interval(self.rep_unit, constraints);
+        let joined = if every.is_some() { self.rep_unit } else { RepetitionUnit::None };
+        let frequency = wording.frequency(self.rep_per_unit.unwrap_or(1), self.rep_unit, every);
+
+        let filters = [
+            (RepetitionUnit::Day, constraints.day_constraint.as_ref().and_then(|c| wording.day_constraint(c))),
+            (RepetitionUnit::Week, constraints.week_constraint.as_ref().and_then(|c| wording.week_constraint(c))),
+            (RepetitionUnit::Month, constraints.month_constraint.as_ref().and_then(|c| wording.month_constraint(c))),
+            (RepetitionUnit::Year, constraints.year_constraint.as_ref().and_then(|c| wording.year_constraint(c))),
+        ];
+        let filters = filters.into_iter().filter(|(unit, _)| *unit != joined).filter_map(|(_, phrase)| phrase).collect();
+        (frequency, filters)
+    }
+}
+
+impl fmt::Display for Periodicity {
+    /// English description (see `Periodicity::describe`)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Interval of a rolling pattern on the repetition unit itself
+fn rolling_interval(rep_unit: RepetitionUnit, constraints: &PeriodicityConstraints) -> Option<u16> {
+    match rep_unit {
+        RepetitionUnit::Day => match constraints.day_constraint {
+            Some(DayConstraint::EveryNDays(n)) => Some(n),
+            _ => None,
+        },
+        RepetitionUnit::Week => match constraints.week_constraint {
+            Some(WeekConstraint::EveryNWeeks(n)) => Some(n as u16),
+            _ => None,
+        },
+        RepetitionUnit::Month => match constraints.month_constraint {
+            Some(MonthConstraint::EveryNMonths(n)) => Some(n as u16),
+            _ => None,
+        },
+        RepetitionUnit::Year => match constraints.year_constraint {
+            Some(YearConstraint::EveryNYears(n)) => Some(n as u16),
+            _ => None,
+        },
+        RepetitionUnit::None => None,
+    }
+}
+
+// ========================================================================
+// ENGLISH
+// ========================================================================
+
+/// English wording
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+/// Custom schedules with more dates are summarized by their range
+const MAX_LISTED_DATES: usize = 5;
+
+impl PeriodicityWording for English {
+    fn frequency(&self, count: u8, unit: RepetitionUnit, every: Option<u16>) -> String {
+        let times = match count {
+            1 => "once".to_string(),
+            2 => "twice".to_string(),
+            n => format!("{} times", n),
+        };
+        let Some(name) = unit_name(unit) else {
+            return times;
+        };
+        match every {
+            Some(n) => format!("{} {}", times, every_n(n, name)),
+            None => format!("{} a {}", times, name),
+        }
+    }
+
+    fn day_constraint(&self, constraint: &DayConstraint) -> Option<String> {
+        match constraint {
+            DayConstraint::EveryDay => None,
+            DayConstraint::EveryNDays(n) => Some(every_n(*n, "day")),
+            DayConstraint::SpecificDaysWeek(days) => Some(weekdays(days)),
+            DayConstraint::SpecificDaysMonthFromFirst(days) => Some(format!(
+                "on the {} of the month",
+                list(days.iter().map(|d| ordinal(*d as u32 + 1)).collect()),
+            )),
+            DayConstraint::SpecificDaysMonthFromLast(days) => Some(format!(
+                "on the {} {} of the month",
+                list(days.iter().map(|d| from_last(*d)).collect()),
+                if days.len() == 1 { "day" } else { "days" },
+            )),
+            DayConstraint::SpecificNthWeekdaysMonth(patterns) => Some(format!(
+                "on {} of the month",
+                list(patterns.iter().map(|p| format!("the {} {}", position(p.position), weekday_name(p.weekday))).collect()),
+            )),
+        }
+    }
+
+    fn week_constraint(&self, constraint: &WeekConstraint) -> Option<String> {
+        let weeks_of_month = |positions: Vec<String>| format!(
+            "in the {} {} of the month",
+            list(positions.clone()),
+            if positions.len() == 1 { "week" } else { "weeks" },
+        );
+        let week_numbers = |weeks: &[u8]| format!(
+            "{} {}",
+            if weeks.len() == 1 { "week" } else { "weeks" },
+            list(weeks.iter().map(|w| (*w as u32 + 1).to_string()).collect()),
+        );
+        match constraint {
+            WeekConstraint::EveryWeek => None,
+            WeekConstraint::EveryNWeeks(n) => Some(every_n(*n as u16, "week")),
+            WeekConstraint::SpecificWeeksOfMonthFromFirst(weeks) => Some(weeks_of_month(
+                weeks.iter().map(|w| position(MonthWeekPosition::FromFirst(*w))).collect(),
+            )),
+            WeekConstraint::SpecificWeeksOfMonthFromLast(weeks) => Some(weeks_of_month(
+                weeks.iter().map(|w| from_last(*w)).collect(),
+            )),
+            WeekConstraint::SpecificWeeksOfYear(weeks) => Some(format!("in {} of the year", week_numbers(weeks))),
+            WeekConstraint::SpecificIsoWeeks(weeks) => Some(format!("in ISO {}", week_numbers(weeks))),
+            WeekConstraint::EvenIsoWeeks => Some("in even ISO weeks".to_string()),
+            WeekConstraint::OddIsoWeeks => Some("in odd ISO weeks".to_string()),
+        }
+    }
+
+    fn month_constraint(&self, constraint: &MonthConstraint) -> Option<String> {
+        match constraint {
+            MonthConstraint::EveryMonth => None,
+            MonthConstraint::EveryNMonths(n) => Some(every_n(*n as u16, "month")),
+            MonthConstraint::SpecificMonths(months) => Some(format!(
+                "in {}",
+                list(months.iter().map(|m| m.name().to_string()).collect()),
+            )),
+        }
+    }
+
+    fn year_constraint(&self, constraint: &YearConstraint) -> Option<String> {
+        match constraint {
+            YearConstraint::EveryYear => None,
+            YearConstraint::EveryNYears(n) => Some(every_n(*n as u16, "year")),
+            YearConstraint::SpecificYears(years) => Some(format!(
+                "in {}",
+                list(years.iter().map(|y| y.to_string()).collect()),
+            )),
+        }
+    }
+
+    fn alternatives(&self, sets: Vec<String>) -> String {
+        let sets: Vec<String> = sets.into_iter()
+            .map(|set| if set.is_empty() { "on any day".to_string() } else { set })
+            .collect();
+        sets.join(", or ")
+    }
+
+    fn special_pattern(&self, pattern: &SpecialPattern) -> String {
+        match pattern {
+            SpecialPattern::Unique(unique) => format!("once on {}", date_time(&unique.date)),
+            SpecialPattern::Custom(custom) if custom.dates.len() > MAX_LISTED_DATES => format!(
+                "on {} dates from {} to {}",
+                custom.dates.len(),
+                date_time(&custom.dates[0]),
+                date_time(&custom.dates[custom.dates.len() - 1]),
+            ),
+            SpecialPattern::Custom(custom) => format!(
+                "on {}",
+                list(custom.dates.iter().map(date_time).collect()),
+            ),
+        }
+    }
+
+    fn after_completion(&self, rule: &AfterCompletion) -> String {
+        let unit = unit_name(rule.unit).unwrap_or("day");
+        format!("due {} after the last completion", count_of(rule.interval, unit))
+    }
+
+    fn timing(&self, settings: &OccurrenceTimingSettings) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(minutes) = settings.duration {
+            parts.push(format!("for {}", duration(minutes)));
+        }
+        parts.extend(time_window(settings.not_before, settings.best_before));
+        for rep in settings.rep_timing_settings.iter().flatten() {
+            if let Some(window) = time_window(rep.not_before, rep.best_before) {
+                parts.push(format!("the {} time {}", ordinal(rep.rep_index as u32 + 1), window));
+            }
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    fn exceptions(&self, exceptions: &PeriodicityExceptions) -> Option<String> {
+        let holidays = (!exceptions.holidays.is_empty()).then(|| format!(
+            "{} holidays",
+            list(exceptions.holidays.iter().map(|c| c.name.clone()).collect()),
+        ));
+
+        let mut skipped: Vec<String> = exceptions.dates.iter().map(date).collect();
+        let mut parts = Vec::new();
+        let moved_to = match exceptions.roll {
+            RollPolicy::Skip => {
+                skipped.extend(holidays.clone());
+                None
+            }
+            RollPolicy::PreviousBusinessDay => Some("the previous business day"),
+            RollPolicy::NextBusinessDay => Some("the next business day"),
+            RollPolicy::NearestBusinessDay => Some("the nearest business day"),
+        };
+        if !skipped.is_empty() {
+            parts.push(format!("except on {}", list(skipped)));
+        }
+        if let Some(target) = moved_to {
+            let off = match holidays {
+                Some(holidays) => format!("weekends and {}", holidays),
+                None => "weekends".to_string(),
+            };
+            parts.push(format!("moved off {} to {}", off, target));
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    fn timeframe(&self, first: Option<NaiveDate>, last: Option<NaiveDate>) -> String {
+        match (first, last) {
+            (Some(first), Some(last)) => format!("from {} to {}", date(&first), date(&last)),
+            (Some(first), None) => format!("from {}", date(&first)),
+            (None, Some(last)) => format!("until {}", date(&last)),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn sentence(&self, schedule: Vec<String>, clauses: Vec<String>) -> String {
+        let mut sentence = schedule.join(" ");
+        for clause in clauses {
+            sentence.push_str(", ");
+            sentence.push_str(&clause);
+        }
+        sentence
+    }
+}
+
+// ── ENGLISH HELPERS ─────────────────────────────────────────
+
+fn unit_name(unit: RepetitionUnit) -> Option<&'static str> {
+    match unit {
+        RepetitionUnit::Day => Some("day"),
+        RepetitionUnit::Week => Some("week"),
+        RepetitionUnit::Month => Some("month"),
+        RepetitionUnit::Year => Some("year"),
+        RepetitionUnit::None => None,
+    }
+}
+
+/// "1 day", "3 days"
+fn count_of(n: u16, unit: &str) -> String {
+    if n == 1 { format!("1 {}", unit) } else { format!("{} {}s", n, unit) }
+}
+
+/// "every day", "every 3 days"
+fn every_n(n: u16, unit: &str) -> String {
+    if n == 1 { format!("every {}", unit) } else { format!("every {} {}s", n, unit) }
+}
+
+/// "a", "a and b", "a, b and c"
+fn list(mut items: Vec<String>) -> String {
+    match items.len() {
+        0 => String::new(),
+        1 => items.remove(0),
+        _ => {
+            let last = items.pop().unwrap_or_default();
+            format!("{} and {}", items.join(", "), last)
+        }
+    }
+}
+
+/// "1st", "2nd", "3rd", "11th", "22nd"
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// "first", "second", ..., "last", "second-to-last"
+fn position(position: MonthWeekPosition) -> String {
+    const WORDS: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+    match position {
+        MonthWeekPosition::FromFirst(n) => WORDS.get(n as usize).map(|w| w.to_string()).unwrap_or_else(|| ordinal(n as u32 + 1)),
+        MonthWeekPosition::FromLast(n) => from_last(n),
+    }
+}
+
+/// 0-indexed from the end: "last", "second-to-last", ...
+fn from_last(n: u8) -> String {
+    match n {
+        0 => "last".to_string(),
+        n => format!("{}-to-last", position(MonthWeekPosition::FromFirst(n))),
+    }
+}
+
+fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+/// "on Mondays and Fridays", "on weekdays", "on weekends"
+fn weekdays(days: &[Weekday]) -> String {
+    let mut sorted: Vec<u32> = days.iter().map(|d| d.num_days_from_monday()).collect();
+    sorted.sort_unstable();
+    match sorted.as_slice() {
+        [0, 1, 2, 3, 4] => "on weekdays".to_string(),
+        [5, 6] => "on weekends".to_string(),
+        _ => format!("on {}", list(days.iter().map(|d| format!("{}s", weekday_name(*d))).collect())),
+    }
+}
+
+/// "25 December 2026"
+fn date(date: &NaiveDate) -> String {
+    date.format("%-d %B %Y").to_string()
+}
+
+fn date_time(date_time: &DateTime<Utc>) -> String {
+    date(&date_time.date_naive())
+}
+
+/// "45 minutes", "1 hour", "1 hour 30 minutes"
+fn duration(minutes: u16) -> String {
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    match (hours, minutes) {
+        (0, m) => count_of(m, "minute"),
+        (h, 0) => count_of(h, "hour"),
+        (h, m) => format!("{} {}", count_of(h, "hour"), count_of(m, "minute")),
+    }
+}
+
+/// "not before 06:00 and best before 08:00"
+fn time_window(not_before: Option<NaiveTime>, best_before: Option<NaiveTime>) -> Option<String> {
+    let time = |t: NaiveTime| t.format("%H:%M").to_string();
+    match (not_before, best_before) {
+        (Some(from), Some(by)) => Some(format!("not before {} and best before {}", time(from), time(by))),
+        (Some(from), None) => Some(format!("not before {}", time(from))),
+        (None, Some(by)) => Some(format!("best before {}", time(by))),
+        (None, None) => None,
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Month, TimeZone};
+    use crate::domain::builders::PeriodicityBuilder;
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_describe_frequency_and_rolling_patterns() {
+        let describe = |builder: PeriodicityBuilder| builder.build().unwrap().describe();
+
+        assert_eq!(describe(PeriodicityBuilder::new().daily(1)), "once a day");
+        assert_eq!(describe(PeriodicityBuilder::new().weekly(2)), "twice a week");
+        assert_eq!(describe(PeriodicityBuilder::new().daily(1).every_n_days(3)), "once every 3 days");
+        assert_eq!(describe(PeriodicityBuilder::new().weekly(1).every_n_weeks(2)), "once every 2 weeks");
+        assert_eq!(describe(PeriodicityBuilder::new().monthly(1).every_n_months(1)), "once every month");
+        assert_eq!(
+            describe(PeriodicityBuilder::new().daily(1).every_n_weeks(2).every_n_years(3)),
+            "once a day every 2 weeks every 3 years",
+        );
+    }
+
+    #[test]
+    fn test_describe_constraints() {
+        let describe = |builder: PeriodicityBuilder| builder.build().unwrap().describe();
+
+        assert_eq!(
+            describe(PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Mon, Weekday::Fri])),
+            "once a day on Mondays and Fridays",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Fri, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu])),
+            "once a day on weekdays",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().daily(1).on_month_days(vec![1, 2, 3, 11, 22]).in_months(vec![Month::January, Month::February])),
+            "once a day on the 1st, 2nd, 3rd, 11th and 22nd of the month in January and February",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().daily(1).on_month_days_from_end(vec![1, 2])),
+            "once a day on the last and second-to-last days of the month",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().daily(1).on_nth_weekdays(vec![NthWeekdayOfMonth::first(Weekday::Mon), NthWeekdayOfMonth::last(Weekday::Fri)])),
+            "once a day on the first Monday and the last Friday of the month",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().weekly(1).on_weeks_of_month(vec![1, 3]).in_years(vec![2026, 2027])),
+            "once a week in the first and third weeks of the month in 2026 and 2027",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().weekly(1).on_weeks_of_month_from_end(vec![1])),
+            "once a week in the last week of the month",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().daily(1).on_weeks_of_year(vec![1, 27])),
+            "once a day in weeks 1 and 27 of the year",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().weekly(1).on_iso_weeks(vec![53])),
+            "once a week in ISO week 53",
+        );
+        assert_eq!(
+            describe(PeriodicityBuilder::new().weekly(1).in_odd_iso_weeks()),
+            "once a week in odd ISO weeks",
+        );
+    }
+
+    #[test]
+    fn test_describe_special_patterns() {
+        assert_eq!(Periodicity::unique(utc(2026, 3, 10)).unwrap().describe(), "once on 10 March 2026");
+
+        let few = PeriodicityBuilder::new().custom_dates(vec![utc(2026, 3, 1), utc(2026, 4, 5)]).unwrap().build().unwrap();
+        assert_eq!(few.describe(), "on 1 March 2026 and 5 April 2026");
+
+        let many = (1..=8).map(|d| utc(2026, 5, d)).collect();
+        let many = PeriodicityBuilder::new().custom_dates(many).unwrap().build().unwrap();
+        assert_eq!(many.describe(), "on 8 dates from 1 May 2026 to 8 May 2026");
+    }
+
+    #[test]
+    fn test_describe_clauses() {
+        let settings = OccurrenceTimingSettings {
+            duration: Some(90),
+            not_before: NaiveTime::from_hms_opt(6, 0, 0),
+            best_before: None,
+            rep_timing_settings: Some(vec![RepTimingSettings {
+                rep_index: 1,
+                not_before: None,
+                best_before: NaiveTime::from_hms_opt(20, 0, 0),
+            }]),
+        };
+        let timed = PeriodicityBuilder::new()
+            .daily(2)
+            .with_occurrence_settings(settings)
+            .between(utc(2026, 2, 1), utc(2026, 3, 1))
+            .build()
+            .unwrap();
+        assert_eq!(
+            timed.describe(),
+            "twice a day, for 1 hour 30 minutes, not before 06:00, the 2nd time best before 20:00, from 1 February 2026 to 28 February 2026",
+        );
+
+        let open = PeriodicityBuilder::new().daily(1).starting_from(utc(2026, 2, 1)).build().unwrap();
+        assert_eq!(open.describe(), "once a day, from 1 February 2026");
+
+        let watering = PeriodicityBuilder::new().daily(1).after_completion(4, RepetitionUnit::Day).build().unwrap();
+        assert_eq!(watering.describe(), "once a day, due 4 days after the last completion");
+    }
+
+    #[test]
+    fn test_describe_exceptions() {
+        let holidays = HolidayCalendar::new("fr-public".to_string(), vec![day(5, 1)]).unwrap();
+        let skipping = PeriodicityBuilder::new()
+            .daily(1)
+            .except(vec![day(12, 25)])
+            .skip_holidays(holidays.clone())
+            .build()
+            .unwrap();
+        assert_eq!(skipping.describe(), "once a day, except on 25 December 2026 and fr-public holidays");
+
+        let rent = PeriodicityBuilder::new()
+            .monthly(1)
+            .on_month_days(vec![1])
+            .skip_holidays(holidays)
+            .with_roll_policy(RollPolicy::PreviousBusinessDay)
+            .build()
+            .unwrap();
+        assert_eq!(
+            rent.describe(),
+            "once a month on the 1st of the month, moved off weekends and fr-public holidays to the previous business day",
+        );
+    }
+
+    #[test]
+    fn test_describe_alternatives() {
+        let payroll = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1, 15])
+            .or()
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::last(Weekday::Fri)])
+            .in_months(vec![Month::December])
+            .build()
+            .unwrap();
+        assert_eq!(
+            payroll.describe(),
+            "once a day on the 1st and 15th of the month, or on the last Friday of the month in December",
+        );
+
+        // Rolling patterns on the unit give each set its own frequency
+        let review = PeriodicityBuilder::new()
+            .weekly(1)
+            .every_n_weeks(2)
+            .or()
+            .on_weeks_of_month_from_end(vec![1])
+            .build()
+            .unwrap();
+        assert_eq!(review.describe(), "once every 2 weeks, or once a week in the last week of the month");
+    }
+
+    /// Shouts the frequency, English for the rest
+    struct Shouting;
+
+    impl PeriodicityWording for Shouting {
+        fn frequency(&self, count: u8, unit: RepetitionUnit, every: Option<u16>) -> String {
+            English.frequency(count, unit, every).to_uppercase()
+        }
+        fn day_constraint(&self, constraint: &DayConstraint) -> Option<String> { English.day_constraint(constraint) }
+        fn week_constraint(&self, constraint: &WeekConstraint) -> Option<String> { English.week_constraint(constraint) }
+        fn month_constraint(&self, constraint: &MonthConstraint) -> Option<String> { English.month_constraint(constraint) }
+        fn year_constraint(&self, constraint: &YearConstraint) -> Option<String> { English.year_constraint(constraint) }
+        fn alternatives(&self, sets: Vec<String>) -> String { English.alternatives(sets) }
+        fn special_pattern(&self, pattern: &SpecialPattern) -> String { English.special_pattern(pattern) }
+        fn after_completion(&self, rule: &AfterCompletion) -> String { English.after_completion(rule) }
+        fn timing(&self, settings: &OccurrenceTimingSettings) -> Option<String> { English.timing(settings) }
+        fn exceptions(&self, exceptions: &PeriodicityExceptions) -> Option<String> { English.exceptions(exceptions) }
+        fn timeframe(&self, first: Option<NaiveDate>, last: Option<NaiveDate>) -> String { English.timeframe(first, last) }
+        fn sentence(&self, schedule: Vec<String>, clauses: Vec<String>) -> String { English.sentence(schedule, clauses) }
+    }
+
+    #[test]
+    fn test_describe_with_another_wording() {
+        let periodicity = Periodicity::on_weekdays(vec![Weekday::Sat, Weekday::Sun]).unwrap();
+        assert_eq!(periodicity.describe_with(&Shouting), "ONCE A DAY on weekends");
+    }
+}
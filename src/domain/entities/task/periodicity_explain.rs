@@ -0,0 +1,531 @@
+use chrono::{DateTime, Datelike, Duration, Month, NaiveDate, Utc, Weekday};
+use crate::domain::entities::task::periodicity::*;
+use crate::domain::entities::user::User;
+
+// ========================================================================
+// MATCH EXPLANATION
+// Why a date does or doesn't have an occurrence
+// ========================================================================
+//
+// `Periodicity::explain` evaluates every rule on a date, in order, and
+// records the values each one computed:
+// - timeframe
+// - special pattern, or the day, week, month and year constraints (of each
+//   constraint set of a union, after a `Rule::ConstraintSet` check)
+// - exceptions: excluded dates, holidays and the roll policy on the date as
+//   scheduled, then occurrences of neighbouring days rolled onto it
+//
+// Unlike `matches_constraints`, it doesn't stop at the first failure.
+// `Explanation::matches` is the answer of `matches_constraints` and
+// `is_within_timeframe` together; the checks say why. Checks are on calendar
+// days: in `explain`, a special-pattern date on the day still needs the same
+// instant to match.
+
+/// What `explain` found for one date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The calendar date evaluated
+    pub date: NaiveDate,
+
+    /// Whether the date has an occurrence
+    pub matches: bool,
+
+    /// Each rule evaluated, in order
+    pub checks: Vec<Check>,
+}
+
+/// One rule evaluated on the date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub rule: Rule,
+    pub passed: bool,
+
+    /// Values computed for the date
+    pub values: Vec<ComputedValue>,
+}
+
+/// The rule a check is about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// Instants in `start..end`
+    Timeframe { start: DateTime<Utc>, end: DateTime<Utc> },
+
+    /// The dates of a one-off or custom schedule
+    SpecialPattern(SpecialPattern),
+
+    /// Constraint set `n` of a union: 0 is `constraints`, then the
+    /// alternatives. Passes when the whole set matches; the checks up to
+    /// the next set are its constraints. Only with alternatives
+    ConstraintSet(usize),
+
+    Day(DayConstraint),
+    Week(WeekConstraint),
+    Month(MonthConstraint),
+    Year(YearConstraint),
+
+    /// Excluded dates, holidays and the roll policy, on the date as scheduled
+    /// Passes when an occurrence scheduled on the date stays on it
+    Exceptions(RollPolicy),
+
+    /// Occurrences of neighbouring days moved onto the date
+    /// Passes when there is at least one
+    RolledIn(RollPolicy),
+}
+
+/// A value computed while evaluating a rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComputedValue {
+    Weekday(Weekday),
+
+    /// 1-based
+    DayOfMonth(u32),
+
+    /// 0 on the last day of the month
+    DaysBeforeMonthEnd(u32),
+
+    /// 0-based occurrence of this weekday in the month ("second Tuesday" = 1 from first)
+    NthWeekday { from_first: u8, from_last: u8 },
+
+    /// 0-based week of the month, None when the date is in a week of the
+    /// neighbouring month (see `Periodicity::week_of_month_from_first`)
+    WeekOfMonth { from_first: Option<u8>, from_last: Option<u8> },
+
+    /// 0-based week of the week-numbering year, which may differ from the
+    /// date's year around New Year (see `Periodicity::week_of_year`)
+    WeekOfYear { year: i32, week: u8 },
+
+    /// Signed distances from the reference date EveryN* intervals count from
+    DaysSinceReference { reference: NaiveDate, days: i64 },
+    WeeksSinceReference { reference: NaiveDate, weeks: i64 },
+    MonthsSinceReference { reference: NaiveDate, months: i64 },
+    YearsSinceReference { reference: NaiveDate, years: i64 },
+
+    Month(Month),
+    Year(i32),
+
+    /// A special-pattern date scheduled on the same day
+    ScheduledAt(DateTime<Utc>),
+
+    /// The date is one of the explicitly excluded dates
+    ExcludedDate,
+
+    /// The date is in this holiday calendar
+    Holiday(String),
+
+    /// The date is a weekend day, which a roll policy moves off
+    Weekend,
+
+    /// The occurrence scheduled on the date happens on this day instead
+    MovedTo(NaiveDate),
+
+    /// The occurrence scheduled on this day happens on the date
+    MovedFrom(NaiveDate),
+}
+
+impl Periodicity {
+    /// Explains `matches_constraints` and `is_within_timeframe` for an instant
+    ///
+    /// Constraints are read on the UTC calendar date, like `matches_constraints`.
+    ///
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::builders::periodicity_builder::PeriodicityBuilder;
+    /// use tsadaash::domain::entities::task::periodicity_explain::{ComputedValue, Rule};
+    /// use chrono::{TimeZone, Utc, Weekday};
+    ///
+    /// let periodicity = PeriodicityBuilder::new().daily(1).on_month_days(vec![13]).build().unwrap();
+    /// let explanation = periodicity.explain(&Utc.with_ymd_and_hms(2026, 3, 14, 0, 0, 0).unwrap(), Weekday::Mon);
+    ///
+    /// assert!(!explanation.matches);
+    /// let check = &explanation.checks[0];
+    /// assert!(matches!(check.rule, Rule::Day(_)));
+    /// assert!(!check.passed);
+    /// assert_eq!(check.values, vec![ComputedValue::DayOfMonth(14)]);
+    /// ```
+    pub fn explain(&self, date: &DateTime<Utc>, week_start: Weekday) -> Explanation {
+        let reference = self.get_effective_reference_date(date).date_naive();
+        let mut checks = self.timeframe_check(date);
+        checks.extend(self.explain_day(date.date_naive(), week_start, reference, &|d| d.date_naive()));
+        Explanation {
+            date: date.date_naive(),
+            matches: self.is_within_timeframe(date) && self.matches_constraints(date, week_start),
+            checks,
+        }
+    }
+
+    /// Explains `matches_constraints_for` and `is_within_timeframe` for an
+    /// instant, in the user's logical calendar
+    pub fn explain_for(&self, instant: &DateTime<Utc>, user: &User) -> Explanation {
+        let date = user.logical_date(instant);
+        let reference = user.logical_date(&self.get_effective_reference_date(instant));
+        let mut checks = self.timeframe_check(instant);
+        checks.extend(self.explain_day(date, user.week_start(), reference, &|d| user.logical_date(d)));
+        Explanation {
+            date,
+            matches: self.is_within_timeframe(instant) && self.matches_constraints_for(instant, user),
+            checks,
+        }
+    }
+
+    fn timeframe_check(&self, instant: &DateTime<Utc>) -> Vec<Check> {
+        self.timeframe
+            .map(|(start, end)| Check {
+                rule: Rule::Timeframe { start, end },
+                passed: self.is_within_timeframe(instant),
+                values: Vec::new(),
+            })
+            .into_iter()
+            .collect()
+    }
+
+    /// Checks of everything but the timeframe
+    /// `day_of` is the calendar day of a special-pattern date
+    fn explain_day(
+        &self,
+        date: NaiveDate,
+        week_start: Weekday,
+        reference: NaiveDate,
+        day_of: &dyn Fn(&DateTime<Utc>) -> NaiveDate,
+    ) -> Vec<Check> {
+        let mut checks = Vec::new();
+
+        let scheduled = |day: NaiveDate| match &self.special_pattern {
+            Some(pattern) => pattern.dates().iter().any(|d| day_of(d) == day),
+            None => self.matches_calendar_date(day, week_start, reference),
+        };
+
+        match &self.special_pattern {
+            Some(pattern) => {
+                let values: Vec<_> = pattern.dates().iter()
+                    .filter(|d| day_of(d) == date)
+                    .map(|d| ComputedValue::ScheduledAt(*d))
+                    .collect();
+                checks.push(Check { rule: Rule::SpecialPattern(pattern.clone()), passed: !values.is_empty(), values });
+            }
+            None if self.alternatives.is_empty() => {
+                checks.extend(constraint_checks(date, &self.constraints, week_start, reference));
+            }
+            None => {
+                for (index, constraints) in self.constraint_sets().enumerate() {
+                    let set_checks = constraint_checks(date, constraints, week_start, reference);
+                    checks.push(Check {
+                        rule: Rule::ConstraintSet(index),
+                        passed: set_checks.iter().all(|check| check.passed),
+                        values: Vec::new(),
+                    });
+                    checks.extend(set_checks);
+                }
+            }
+        }
+
+        let exceptions = &self.exceptions;
+        if exceptions.is_empty() && exceptions.roll == RollPolicy::Skip {
+            return checks;
+        }
+
+        let mut values = Vec::new();
+        if exceptions.dates.contains(&date) {
+            values.push(ComputedValue::ExcludedDate);
+        }
+        values.extend(exceptions.holidays.iter()
+            .filter(|calendar| calendar.contains(date))
+            .map(|calendar| ComputedValue::Holiday(calendar.name.clone())));
+        if exceptions.roll != RollPolicy::Skip && matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            values.push(ComputedValue::Weekend);
+        }
+        let rolled = exceptions.roll(date);
+        if let Some(day) = rolled.filter(|day| *day != date) {
+            values.push(ComputedValue::MovedTo(day));
+        }
+        checks.push(Check { rule: Rule::Exceptions(exceptions.roll), passed: rolled == Some(date), values });
+
+        // Same search as `PeriodicityExceptions::receives`
+        if exceptions.roll != RollPolicy::Skip && exceptions.is_business_day(date) {
+            let mut values = Vec::new();
+            for direction in [-1, 1] {
+                let neighbours = (1..=PeriodicityExceptions::MAX_ROLL_DAYS)
+                    .map_while(|days| date.checked_add_signed(Duration::days(direction * days)))
+                    .take_while(|nominal| !exceptions.is_business_day(*nominal));
+                for nominal in neighbours {
+                    if scheduled(nominal) && exceptions.roll(nominal) == Some(date) {
+                        values.push(ComputedValue::MovedFrom(nominal));
+                    }
+                }
+            }
+            values.sort_by_key(|value| match value {
+                ComputedValue::MovedFrom(day) => *day,
+                _ => date,
+            });
+            checks.push(Check { rule: Rule::RolledIn(exceptions.roll), passed: !values.is_empty(), values });
+        }
+
+        checks
+    }
+}
+
+/// Checks of one constraint set
+fn constraint_checks(date: NaiveDate, constraints: &PeriodicityConstraints, week_start: Weekday, reference: NaiveDate) -> Vec<Check> {
+    let mut checks = Vec::new();
+    if let Some(day) = &constraints.day_constraint {
+        checks.push(Check {
+            rule: Rule::Day(day.clone()),
+            passed: Periodicity::matches_day_constraint(date, day, reference),
+            values: day_values(date, day, reference),
+        });
+    }
+    if let Some(week) = &constraints.week_constraint {
+        checks.push(Check {
+            rule: Rule::Week(week.clone()),
+            passed: Periodicity::matches_week_constraint(date, week, week_start, reference),
+            values: week_values(date, week, week_start, reference),
+        });
+    }
+    if let Some(month) = &constraints.month_constraint {
+        let values = match month {
+            MonthConstraint::EveryMonth => Vec::new(),
+            MonthConstraint::EveryNMonths(_) => {
+                let months = (date.year() - reference.year()) as i64 * 12 + date.month() as i64 - reference.month() as i64;
+                vec![ComputedValue::MonthsSinceReference { reference, months }]
+            }
+            MonthConstraint::SpecificMonths(_) => {
+                Month::try_from(date.month() as u8).map(ComputedValue::Month).into_iter().collect()
+            }
+        };
+        checks.push(Check {
+            rule: Rule::Month(month.clone()),
+            passed: Periodicity::matches_month_constraint(date, month, reference),
+            values,
+        });
+    }
+    if let Some(year) = &constraints.year_constraint {
+        let values = match year {
+            YearConstraint::EveryYear => Vec::new(),
+            YearConstraint::EveryNYears(_) => {
+                vec![ComputedValue::YearsSinceReference { reference, years: (date.year() - reference.year()) as i64 }]
+            }
+            YearConstraint::SpecificYears(_) => vec![ComputedValue::Year(date.year())],
+        };
+        checks.push(Check {
+            rule: Rule::Year(year.clone()),
+            passed: Periodicity::matches_year_constraint(date, year, reference),
+            values,
+        });
+    }
+    checks
+}
+
+fn day_values(date: NaiveDate, constraint: &DayConstraint, reference: NaiveDate) -> Vec<ComputedValue> {
+    match constraint {
+        DayConstraint::EveryDay => Vec::new(),
+        DayConstraint::EveryNDays(_) => {
+            vec![ComputedValue::DaysSinceReference { reference, days: (date - reference).num_days() }]
+        }
+        DayConstraint::SpecificDaysWeek(_) => vec![ComputedValue::Weekday(date.weekday())],
+        DayConstraint::SpecificDaysMonthFromFirst(_) => vec![ComputedValue::DayOfMonth(date.day())],
+        DayConstraint::SpecificDaysMonthFromLast(_) => {
+            vec![ComputedValue::DaysBeforeMonthEnd(Periodicity::last_day_of_month(date) - date.day())]
+        }
+        DayConstraint::SpecificNthWeekdaysMonth(_) => {
+            let days_before_end = Periodicity::last_day_of_month(date) - date.day();
+            vec![
+                ComputedValue::Weekday(date.weekday()),
+                ComputedValue::NthWeekday { from_first: ((date.day() - 1) / 7) as u8, from_last: (days_before_end / 7) as u8 },
+            ]
+        }
+    }
+}
+
+fn week_values(date: NaiveDate, constraint: &WeekConstraint, week_start: Weekday, reference: NaiveDate) -> Vec<ComputedValue> {
+    // 255: the week belongs to the neighbouring month
+    let valid = |week: u8| (week != 255).then_some(week);
+    match constraint {
+        WeekConstraint::EveryWeek => Vec::new(),
+        WeekConstraint::EveryNWeeks(_) => {
+            let days = (Periodicity::get_week_start(date, week_start) - Periodicity::get_week_start(reference, week_start)).num_days();
+            vec![ComputedValue::WeeksSinceReference { reference, weeks: days / 7 }]
+        }
+        WeekConstraint::SpecificWeeksOfMonthFromFirst(_) | WeekConstraint::SpecificWeeksOfMonthFromLast(_) => {
+            vec![ComputedValue::WeekOfMonth {
+                from_first: valid(Periodicity::week_of_month_from_first(&date, week_start)),
+                from_last: valid(Periodicity::week_of_month_from_last(&date, week_start)),
+            }]
+        }
+        WeekConstraint::SpecificWeeksOfYear(_) => {
+            let (year, week) = Periodicity::week_of_year(date, week_start);
+            vec![ComputedValue::WeekOfYear { year, week }]
+        }
+        WeekConstraint::SpecificIsoWeeks(_) | WeekConstraint::EvenIsoWeeks | WeekConstraint::OddIsoWeeks => {
+            let (year, week) = Periodicity::week_of_year(date, Weekday::Mon);
+            vec![ComputedValue::WeekOfYear { year, week }]
+        }
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::domain::builders::PeriodicityBuilder;
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn day(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_explain_reports_every_constraint() {
+        // Every other week, on the second Tuesday, in March
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::second(Weekday::Tue)])
+            .every_n_weeks(2)
+            .in_months(vec![Month::March])
+            .with_reference_date(utc(2026, 3, 2))
+            .build()
+            .unwrap();
+
+        // Tuesday 10 March 2026: second Tuesday, one week after the reference
+        let explanation = periodicity.explain(&utc(2026, 3, 10), Weekday::Mon);
+        assert!(!explanation.matches);
+        assert_eq!(explanation.date, day(2026, 3, 10));
+        let passed: Vec<_> = explanation.checks.iter().map(|check| check.passed).collect();
+        assert_eq!(passed, vec![true, false, true]);
+        assert_eq!(
+            explanation.checks[0].values,
+            vec![ComputedValue::Weekday(Weekday::Tue), ComputedValue::NthWeekday { from_first: 1, from_last: 3 }],
+        );
+        assert_eq!(
+            explanation.checks[1].values,
+            vec![ComputedValue::WeeksSinceReference { reference: day(2026, 3, 2), weeks: 1 }],
+        );
+        assert_eq!(explanation.checks[2].values, vec![ComputedValue::Month(Month::March)]);
+
+        // A week later: the right week, but the third Tuesday
+        let explanation = periodicity.explain(&utc(2026, 3, 17), Weekday::Mon);
+        let passed: Vec<_> = explanation.checks.iter().map(|check| check.passed).collect();
+        assert_eq!(passed, vec![false, true, true]);
+        assert!(!explanation.matches);
+    }
+
+    #[test]
+    fn test_explain_marks_each_constraint_set() {
+        // The 1st of the month, or Fridays in March
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1])
+            .or()
+            .on_weekdays(vec![Weekday::Fri])
+            .in_months(vec![Month::March])
+            .build()
+            .unwrap();
+
+        // Friday 6 March 2026: only the alternative matches
+        let explanation = periodicity.explain(&utc(2026, 3, 6), Weekday::Mon);
+        assert!(explanation.matches);
+        let rules: Vec<_> = explanation.checks.iter().map(|check| (check.rule.clone(), check.passed)).collect();
+        assert_eq!(rules, vec![
+            (Rule::ConstraintSet(0), false),
+            (Rule::Day(DayConstraint::SpecificDaysMonthFromFirst(vec![0])), false),
+            (Rule::ConstraintSet(1), true),
+            (Rule::Day(DayConstraint::SpecificDaysWeek(vec![Weekday::Fri])), true),
+            (Rule::Month(MonthConstraint::SpecificMonths(vec![Month::March])), true),
+        ]);
+
+        let explanation = periodicity.explain(&utc(2026, 4, 3), Weekday::Mon);
+        assert!(!explanation.matches);
+        assert!(explanation.checks.iter().filter(|check| matches!(check.rule, Rule::ConstraintSet(_))).all(|check| !check.passed));
+    }
+
+    #[test]
+    fn test_explain_agrees_with_matches() {
+        let periodicities = vec![
+            PeriodicityBuilder::new().daily(1).every_n_days(3).between(utc(2026, 1, 5), utc(2026, 3, 1)).build().unwrap(),
+            PeriodicityBuilder::new().weekly(1).on_weeks_of_month_from_end(vec![1]).build().unwrap(),
+            PeriodicityBuilder::new().daily(1).in_odd_iso_weeks().build().unwrap(),
+            PeriodicityBuilder::new().daily(1).on_month_days_from_end(vec![1])
+                .skip_holidays(HolidayCalendar::new("office".to_string(), vec![day(2026, 3, 31)]).unwrap())
+                .with_roll_policy(RollPolicy::NextBusinessDay)
+                .build().unwrap(),
+        ];
+        for periodicity in &periodicities {
+            for offset in 0..120 {
+                let date = utc(2026, 1, 1) + Duration::days(offset);
+                let explanation = periodicity.explain(&date, Weekday::Mon);
+                let expected = periodicity.is_within_timeframe(&date) && periodicity.matches_constraints(&date, Weekday::Mon);
+                assert_eq!(explanation.matches, expected, "{} on {}", periodicity, date);
+
+                let scheduled_here = explanation.checks.iter()
+                    .filter(|check| !matches!(check.rule, Rule::RolledIn(_)))
+                    .all(|check| check.passed);
+                let rolled_in = explanation.checks.iter().any(|check| matches!(check.rule, Rule::RolledIn(_)) && check.passed);
+                assert_eq!(scheduled_here || rolled_in, expected, "{} on {}", periodicity, date);
+            }
+        }
+    }
+
+    #[test]
+    fn test_explain_reports_the_week_numbering_year() {
+        let periodicity = PeriodicityBuilder::new().daily(1).on_iso_weeks(vec![53]).build().unwrap();
+
+        // Friday 1 January 2027 is in 2026-W53
+        let explanation = periodicity.explain(&utc(2027, 1, 1), Weekday::Sun);
+        assert!(explanation.matches);
+        assert_eq!(explanation.checks[0].values, vec![ComputedValue::WeekOfYear { year: 2026, week: 52 }]);
+    }
+
+    #[test]
+    fn test_explain_exceptions_and_rolls() {
+        // Last day of the month; 31 March 2026 is a holiday, 31 May 2026 a Sunday
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days_from_end(vec![1])
+            .skip_holidays(HolidayCalendar::new("office".to_string(), vec![day(2026, 3, 31)]).unwrap())
+            .with_roll_policy(RollPolicy::PreviousBusinessDay)
+            .build()
+            .unwrap();
+
+        let explanation = periodicity.explain(&utc(2026, 3, 31), Weekday::Mon);
+        assert!(!explanation.matches);
+        let exceptions = &explanation.checks[1];
+        assert_eq!(exceptions.rule, Rule::Exceptions(RollPolicy::PreviousBusinessDay));
+        assert!(!exceptions.passed);
+        assert_eq!(
+            exceptions.values,
+            vec![ComputedValue::Holiday("office".to_string()), ComputedValue::MovedTo(day(2026, 3, 30))],
+        );
+
+        let explanation = periodicity.explain(&utc(2026, 5, 29), Weekday::Mon);
+        assert!(explanation.matches);
+        assert!(!explanation.checks[0].passed);
+        let rolled_in = explanation.checks.last().unwrap();
+        assert_eq!(rolled_in.rule, Rule::RolledIn(RollPolicy::PreviousBusinessDay));
+        assert_eq!(rolled_in.values, vec![ComputedValue::MovedFrom(day(2026, 5, 31))]);
+    }
+
+    #[test]
+    fn test_explain_special_pattern_and_timeframe() {
+        let periodicity = PeriodicityBuilder::new()
+            .unique(Utc.with_ymd_and_hms(2026, 12, 25, 9, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        let explanation = periodicity.explain(&utc(2026, 12, 25), Weekday::Mon);
+        assert_eq!(
+            explanation.checks.last().unwrap().values,
+            vec![ComputedValue::ScheduledAt(Utc.with_ymd_and_hms(2026, 12, 25, 9, 0, 0).unwrap())],
+        );
+
+        let periodicity = PeriodicityBuilder::new().daily(1).between(utc(2026, 2, 1), utc(2026, 3, 1)).build().unwrap();
+        let explanation = periodicity.explain(&utc(2026, 3, 1), Weekday::Mon);
+        assert!(!explanation.matches);
+        assert_eq!(explanation.checks.len(), 1);
+        assert_eq!(explanation.checks[0].rule, Rule::Timeframe { start: utc(2026, 2, 1), end: utc(2026, 3, 1) });
+        assert!(!explanation.checks[0].passed);
+    }
+}
@@ -0,0 +1,983 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::{DateTime, Month, NaiveDate, NaiveTime, SecondsFormat, Utc, Weekday};
+use serde_json::{json, Map, Value};
+use crate::domain::entities::task::periodicity::*;
+use crate::domain::validators::periodicity_validator::{validate_periodicity, ValidationError};
+
+// ========================================================================
+// PERIODICITY JSON REPRESENTATION
+// Stable, versioned JSON form of a Periodicity (for storage and exchange)
+// ========================================================================
+//
+// Format (version 6):
+// {
+//   "version": 6,
+//   "rep_unit": "day" | "week" | "month" | "year" | "none",
+//   "rep_per_unit": 3 | null,
+//   "occurrence_settings": null | {
+//     "duration": 30 | null,
+//     "not_before": "06:00:00" | null,
+//     "best_before": "08:00:00" | null,
+//     "rep_timing_settings": null | [{ "rep_index": 0, "not_before": ..., "best_before": ... }]
+//   },
+//   "constraints": {
+//     "day": null | { "type": "specific_days_week", "value": ["Mon", "Fri"] },
+//     "week": null | { "type": "every_n_weeks", "value": 2 },
+//     "month": null | { "type": "specific_months", "value": ["January"] },
+//     "year": null | { "type": "every_year" }
+//   },
+//   "alternatives": null | [{ "day": ..., "week": ..., "month": ..., "year": ... }],
+//   "timeframe": null | { "start": "2026-01-01T00:00:00Z", "end": "2026-12-31T00:00:00Z" },
+//   "special_pattern": null
+//     | { "type": "custom", "value": ["2026-01-01T00:00:00Z", ...] }
+//     | { "type": "unique", "value": "2026-01-01T00:00:00Z" },
+//   "after_completion": null | { "interval": 4, "unit": "day" | "week" | "month" | "year" },
+//   "exceptions": null | {
+//     "dates": ["2026-12-25"],
+//     "holidays": [{ "name": "fr-public", "dates": ["2026-01-01", "2026-05-01"] }],
+//     "roll": "skip" | "previous_business_day" | "next_business_day" | "nearest_business_day"
+//   },
+//   "reference_date": null | "2026-01-05T00:00:00Z"
+// }
+//
+// Constraint types are the variant names in snake_case; unit variants have
+// no "value". Missing optional fields read as null.
+//
+// The version goes up whenever a field or type is added that a reader can't
+// ignore without changing the schedule, so that older readers reject the
+// document instead of dropping it:
+// 1. base format
+// 2. "exceptions" (dates and holidays)
+// 3. "exceptions.roll"
+// 4. "after_completion"
+// 5. "alternatives"
+// 6. week types "specific_weeks_of_year", "specific_iso_weeks", "even_iso_weeks"
+//    and "odd_iso_weeks"
+// Every older version reads as is: its documents don't have the newer fields.
+
+/// Current version of the JSON representation
+pub const PERIODICITY_JSON_VERSION: u64 = 6;
+
+// ========================================================================
+// ERRORS
+// ========================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeriodicityJsonError {
+    /// Input is not valid JSON text
+    Syntax(String),
+
+    /// JSON doesn't have the expected shape
+    /// `path` locates the offending value (e.g. "constraints.day.value[1]")
+    Malformed { path: String, reason: String },
+
+    /// Written by a newer version of the format (or 0)
+    UnsupportedVersion(u64),
+
+    /// Well-formed, but describes an invalid Periodicity
+    Invalid(ValidationError),
+}
+
+impl fmt::Display for PeriodicityJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeriodicityJsonError::Syntax(reason) => {
+                write!(f, "Invalid periodicity JSON: {}", reason)
+            }
+            PeriodicityJsonError::Malformed { path, reason } => {
+                write!(f, "Malformed periodicity JSON at '{}': {}", path, reason)
+            }
+            PeriodicityJsonError::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "Unsupported periodicity JSON version {} (supported: 1 to {})",
+                    version, PERIODICITY_JSON_VERSION
+                )
+            }
+            PeriodicityJsonError::Invalid(error) => {
+                write!(f, "Invalid periodicity: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PeriodicityJsonError {}
+
+impl From<ValidationError> for PeriodicityJsonError {
+    fn from(error: ValidationError) -> Self {
+        PeriodicityJsonError::Invalid(error)
+    }
+}
+
+// ========================================================================
+// PUBLIC API
+// ========================================================================
+
+impl Periodicity {
+    /// Converts to the versioned JSON representation
+    pub fn to_json(&self) -> Value {
+        json!({
+            "version": PERIODICITY_JSON_VERSION,
+            "rep_unit": rep_unit_name(self.rep_unit),
+            "rep_per_unit": self.rep_per_unit,
+            "occurrence_settings": self.occurrence_settings.as_ref().map(occurrence_settings_to_json),
+            "constraints": constraints_to_json(&self.constraints),
+            "alternatives": alternatives_to_json(&self.alternatives),
+            "timeframe": self.timeframe.map(|(start, end)| json!({
+                "start": datetime_to_json(&start),
+                "end": datetime_to_json(&end),
+            })),
+            "special_pattern": self.special_pattern.as_ref().map(special_pattern_to_json),
+            "after_completion": self.after_completion.as_ref().map(after_completion_to_json),
+            "exceptions": exceptions_to_json(&self.exceptions),
+            "reference_date": self.reference_date.as_ref().map(datetime_to_json),
+        })
+    }
+
+    /// Converts to compact JSON text
+    pub fn to_json_string(&self) -> String {
+        self.to_json().to_string()
+    }
+
+    /// Reads the versioned JSON representation, of this version or an older one
+    ///
+    /// The result always passes `validate_periodicity`: a well-formed but
+    /// invalid configuration fails with `PeriodicityJsonError::Invalid`.
+    ///
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::entities::task::Periodicity;
+    /// use chrono::Weekday;
+    ///
+    /// let periodicity = Periodicity::on_weekdays(vec![Weekday::Mon, Weekday::Fri]).unwrap();
+    /// let json = periodicity.to_json_string();
+    /// assert_eq!(Periodicity::from_json_str(&json).unwrap(), periodicity);
+    /// ```
+    pub fn from_json(value: &Value) -> Result<Self, PeriodicityJsonError> {
+        let root = as_object(value, "$")?;
+
+        let version = match root.get("version") {
+            Some(v) => v.as_u64().ok_or_else(|| malformed("version", "expected a positive integer"))?,
+            None => return Err(malformed("version", "missing")),
+        };
+        if !(1..=PERIODICITY_JSON_VERSION).contains(&version) {
+            return Err(PeriodicityJsonError::UnsupportedVersion(version));
+        }
+
+        let rep_unit = match field(root, "rep_unit") {
+            Some(v) => rep_unit_from_json(v, "rep_unit")?,
+            None => return Err(malformed("rep_unit", "missing")),
+        };
+
+        let constraints = match field(root, "constraints") {
+            Some(v) => constraints_from_json(v, "constraints")?,
+            None => PeriodicityConstraints::default(),
+        };
+
+        let periodicity = Periodicity {
+            rep_unit,
+            rep_per_unit: optional(root, "rep_per_unit", "rep_per_unit", as_u8)?,
+            occurrence_settings: optional(root, "occurrence_settings", "occurrence_settings", occurrence_settings_from_json)?,
+            constraints,
+            alternatives: optional(root, "alternatives", "alternatives", |v, p| as_vec(v, p, constraints_from_json))?
+                .unwrap_or_default(),
+            timeframe: optional(root, "timeframe", "timeframe", timeframe_from_json)?,
+            special_pattern: optional(root, "special_pattern", "special_pattern", special_pattern_from_json)?,
+            after_completion: optional(root, "after_completion", "after_completion", after_completion_from_json)?,
+            exceptions: optional(root, "exceptions", "exceptions", exceptions_from_json)?.unwrap_or_default(),
+            reference_date: optional(root, "reference_date", "reference_date", datetime_from_json)?,
+        };
+
+        validate_periodicity(&periodicity)?;
+        Ok(periodicity)
+    }
+
+    /// Reads JSON text (see `from_json`)
+    pub fn from_json_str(text: &str) -> Result<Self, PeriodicityJsonError> {
+        let value: Value = serde_json::from_str(text)
+            .map_err(|e| PeriodicityJsonError::Syntax(e.to_string()))?;
+        Self::from_json(&value)
+    }
+}
+
+// ========================================================================
+// ENCODING
+// ========================================================================
+
+pub(super) fn rep_unit_name(unit: RepetitionUnit) -> &'static str {
+    match unit {
+        RepetitionUnit::Day => "day",
+        RepetitionUnit::Week => "week",
+        RepetitionUnit::Month => "month",
+        RepetitionUnit::Year => "year",
+        RepetitionUnit::None => "none",
+    }
+}
+
+/// `{"type": name}` or `{"type": name, "value": value}`
+fn tagged(name: &str, value: Option<Value>) -> Value {
+    match value {
+        Some(value) => json!({ "type": name, "value": value }),
+        None => json!({ "type": name }),
+    }
+}
+
+fn datetime_to_json(date: &DateTime<Utc>) -> Value {
+    Value::String(date.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+fn date_to_json(date: &NaiveDate) -> Value {
+    Value::String(date.format("%Y-%m-%d").to_string())
+}
+
+fn time_to_json(time: &NaiveTime) -> Value {
+    Value::String(time.format("%H:%M:%S").to_string())
+}
+
+fn weekday_to_json(weekday: &Weekday) -> Value {
+    Value::String(weekday.to_string())
+}
+
+fn month_to_json(month: &Month) -> Value {
+    Value::String(month.name().to_string())
+}
+
+pub(super) fn occurrence_settings_to_json(settings: &OccurrenceTimingSettings) -> Value {
+    json!({
+        "duration": settings.duration,
+        "not_before": settings.not_before.as_ref().map(time_to_json),
+        "best_before": settings.best_before.as_ref().map(time_to_json),
+        "rep_timing_settings": settings.rep_timing_settings.as_ref().map(|reps| {
+            reps.iter().map(rep_timing_to_json).collect::<Vec<_>>()
+        }),
+    })
+}
+
+pub(super) fn rep_timing_to_json(rep: &RepTimingSettings) -> Value {
+    json!({
+        "rep_index": rep.rep_index,
+        "not_before": rep.not_before.as_ref().map(time_to_json),
+        "best_before": rep.best_before.as_ref().map(time_to_json),
+    })
+}
+
+pub(super) fn constraints_to_json(constraints: &PeriodicityConstraints) -> Value {
+    json!({
+        "day": constraints.day_constraint.as_ref().map(day_constraint_to_json),
+        "week": constraints.week_constraint.as_ref().map(week_constraint_to_json),
+        "month": constraints.month_constraint.as_ref().map(month_constraint_to_json),
+        "year": constraints.year_constraint.as_ref().map(year_constraint_to_json),
+    })
+}
+
+/// Null without alternatives
+pub(super) fn alternatives_to_json(alternatives: &[PeriodicityConstraints]) -> Value {
+    if alternatives.is_empty() {
+        return Value::Null;
+    }
+    alternatives.iter().map(constraints_to_json).collect()
+}
+
+pub(super) fn day_constraint_to_json(constraint: &DayConstraint) -> Value {
+    match constraint {
+        DayConstraint::EveryDay => tagged("every_day", None),
+        DayConstraint::EveryNDays(n) => tagged("every_n_days", Some(json!(n))),
+        DayConstraint::SpecificDaysWeek(days) => tagged(
+            "specific_days_week",
+            Some(days.iter().map(weekday_to_json).collect()),
+        ),
+        DayConstraint::SpecificDaysMonthFromFirst(days) => {
+            tagged("specific_days_month_from_first", Some(json!(days)))
+        }
+        DayConstraint::SpecificDaysMonthFromLast(days) => {
+            tagged("specific_days_month_from_last", Some(json!(days)))
+        }
+        DayConstraint::SpecificNthWeekdaysMonth(patterns) => tagged(
+            "specific_nth_weekdays_month",
+            Some(patterns.iter().map(nth_weekday_to_json).collect()),
+        ),
+    }
+}
+
+pub(super) fn nth_weekday_to_json(pattern: &NthWeekdayOfMonth) -> Value {
+    json!({
+        "weekday": weekday_to_json(&pattern.weekday),
+        "position": month_week_position_to_json(&pattern.position),
+    })
+}
+
+pub(super) fn month_week_position_to_json(position: &MonthWeekPosition) -> Value {
+    match position {
+        MonthWeekPosition::FromFirst(n) => tagged("from_first", Some(json!(n))),
+        MonthWeekPosition::FromLast(n) => tagged("from_last", Some(json!(n))),
+    }
+}
+
+pub(super) fn week_constraint_to_json(constraint: &WeekConstraint) -> Value {
+    match constraint {
+        WeekConstraint::EveryWeek => tagged("every_week", None),
+        WeekConstraint::EveryNWeeks(n) => tagged("every_n_weeks", Some(json!(n))),
+        WeekConstraint::SpecificWeeksOfMonthFromFirst(weeks) => {
+            tagged("specific_weeks_of_month_from_first", Some(json!(weeks)))
+        }
+        WeekConstraint::SpecificWeeksOfMonthFromLast(weeks) => {
+            tagged("specific_weeks_of_month_from_last", Some(json!(weeks)))
+        }
+        WeekConstraint::SpecificWeeksOfYear(weeks) => tagged("specific_weeks_of_year", Some(json!(weeks))),
+        WeekConstraint::SpecificIsoWeeks(weeks) => tagged("specific_iso_weeks", Some(json!(weeks))),
+        WeekConstraint::EvenIsoWeeks => tagged("even_iso_weeks", None),
+        WeekConstraint::OddIsoWeeks => tagged("odd_iso_weeks", None),
+    }
+}
+
+pub(super) fn month_constraint_to_json(constraint: &MonthConstraint) -> Value {
+    match constraint {
+        MonthConstraint::EveryMonth => tagged("every_month", None),
+        MonthConstraint::EveryNMonths(n) => tagged("every_n_months", Some(json!(n))),
+        MonthConstraint::SpecificMonths(months) => tagged(
+            "specific_months",
+            Some(months.iter().map(month_to_json).collect()),
+        ),
+    }
+}
+
+pub(super) fn year_constraint_to_json(constraint: &YearConstraint) -> Value {
+    match constraint {
+        YearConstraint::EveryYear => tagged("every_year", None),
+        YearConstraint::EveryNYears(n) => tagged("every_n_years", Some(json!(n))),
+        YearConstraint::SpecificYears(years) => tagged("specific_years", Some(json!(years))),
+    }
+}
+
+pub(super) fn special_pattern_to_json(pattern: &SpecialPattern) -> Value {
+    match pattern {
+        SpecialPattern::Custom(custom) => tagged("custom", Some(custom_dates_to_json(custom))),
+        SpecialPattern::Unique(unique) => tagged("unique", Some(unique_date_to_json(unique))),
+    }
+}
+
+pub(super) fn custom_dates_to_json(custom: &CustomDates) -> Value {
+    custom.dates.iter().map(datetime_to_json).collect()
+}
+
+pub(super) fn unique_date_to_json(unique: &UniqueDate) -> Value {
+    datetime_to_json(&unique.date)
+}
+
+pub(super) fn after_completion_to_json(rule: &AfterCompletion) -> Value {
+    json!({
+        "interval": rule.interval,
+        "unit": rep_unit_name(rule.unit),
+    })
+}
+
+/// null when there are no exceptions and holidays are simply skipped
+pub(super) fn exceptions_to_json(exceptions: &PeriodicityExceptions) -> Value {
+    if exceptions.dates.is_empty() && exceptions.holidays.is_empty() && exceptions.roll == RollPolicy::Skip {
+        return Value::Null;
+    }
+    json!({
+        "dates": exceptions.dates.iter().map(date_to_json).collect::<Vec<_>>(),
+        "holidays": exceptions.holidays.iter().map(holiday_calendar_to_json).collect::<Vec<_>>(),
+        "roll": roll_policy_to_json(exceptions.roll),
+    })
+}
+
+pub(super) fn holiday_calendar_to_json(calendar: &HolidayCalendar) -> Value {
+    json!({
+        "name": calendar.name,
+        "dates": calendar.dates.iter().map(date_to_json).collect::<Vec<_>>(),
+    })
+}
+
+pub(super) fn roll_policy_to_json(policy: RollPolicy) -> Value {
+    let name = match policy {
+        RollPolicy::Skip => "skip",
+        RollPolicy::PreviousBusinessDay => "previous_business_day",
+        RollPolicy::NextBusinessDay => "next_business_day",
+        RollPolicy::NearestBusinessDay => "nearest_business_day",
+    };
+    json!(name)
+}
+
+// ========================================================================
+// DECODING
+// ========================================================================
+
+fn malformed(path: &str, reason: &str) -> PeriodicityJsonError {
+    PeriodicityJsonError::Malformed {
+        path: path.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Field value, treating null as missing
+fn field<'a>(map: &'a Map<String, Value>, key: &str) -> Option<&'a Value> {
+    map.get(key).filter(|v| !v.is_null())
+}
+
+/// Decodes an optional field (missing or null gives None)
+fn optional<T>(
+    map: &Map<String, Value>,
+    key: &str,
+    path: &str,
+    decode: impl Fn(&Value, &str) -> Result<T, PeriodicityJsonError>,
+) -> Result<Option<T>, PeriodicityJsonError> {
+    field(map, key).map(|v| decode(v, path)).transpose()
+}
+
+fn as_object<'a>(value: &'a Value, path: &str) -> Result<&'a Map<String, Value>, PeriodicityJsonError> {
+    value.as_object().ok_or_else(|| malformed(path, "expected an object"))
+}
+
+fn as_str<'a>(value: &'a Value, path: &str) -> Result<&'a str, PeriodicityJsonError> {
+    value.as_str().ok_or_else(|| malformed(path, "expected a string"))
+}
+
+fn as_u8(value: &Value, path: &str) -> Result<u8, PeriodicityJsonError> {
+    value.as_u64()
+        .and_then(|n| u8::try_from(n).ok())
+        .ok_or_else(|| malformed(path, "expected an integer between 0 and 255"))
+}
+
+fn as_u16(value: &Value, path: &str) -> Result<u16, PeriodicityJsonError> {
+    value.as_u64()
+        .and_then(|n| u16::try_from(n).ok())
+        .ok_or_else(|| malformed(path, "expected an integer between 0 and 65535"))
+}
+
+fn as_i32(value: &Value, path: &str) -> Result<i32, PeriodicityJsonError> {
+    value.as_i64()
+        .and_then(|n| i32::try_from(n).ok())
+        .ok_or_else(|| malformed(path, "expected a 32-bit integer"))
+}
+
+/// Decodes every item of an array
+fn as_vec<T>(
+    value: &Value,
+    path: &str,
+    decode: impl Fn(&Value, &str) -> Result<T, PeriodicityJsonError>,
+) -> Result<Vec<T>, PeriodicityJsonError> {
+    value.as_array()
+        .ok_or_else(|| malformed(path, "expected an array"))?
+        .iter()
+        .enumerate()
+        .map(|(i, item)| decode(item, &format!("{}[{}]", path, i)))
+        .collect()
+}
+
+fn datetime_from_json(value: &Value, path: &str) -> Result<DateTime<Utc>, PeriodicityJsonError> {
+    DateTime::parse_from_rfc3339(as_str(value, path)?)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|_| malformed(path, "expected an RFC 3339 date-time"))
+}
+
+fn date_from_json(value: &Value, path: &str) -> Result<NaiveDate, PeriodicityJsonError> {
+    NaiveDate::parse_from_str(as_str(value, path)?, "%Y-%m-%d")
+        .map_err(|_| malformed(path, "expected a date as YYYY-MM-DD"))
+}
+
+fn time_from_json(value: &Value, path: &str) -> Result<NaiveTime, PeriodicityJsonError> {
+    NaiveTime::parse_from_str(as_str(value, path)?, "%H:%M:%S")
+        .map_err(|_| malformed(path, "expected a time as HH:MM:SS"))
+}
+
+fn weekday_from_json(value: &Value, path: &str) -> Result<Weekday, PeriodicityJsonError> {
+    Weekday::from_str(as_str(value, path)?)
+        .map_err(|_| malformed(path, "expected a weekday (e.g. \"Mon\")"))
+}
+
+fn month_from_json(value: &Value, path: &str) -> Result<Month, PeriodicityJsonError> {
+    Month::from_str(as_str(value, path)?)
+        .map_err(|_| malformed(path, "expected a month (e.g. \"January\")"))
+}
+
+pub(super) fn rep_unit_from_json(value: &Value, path: &str) -> Result<RepetitionUnit, PeriodicityJsonError> {
+    match as_str(value, path)? {
+        "day" => Ok(RepetitionUnit::Day),
+        "week" => Ok(RepetitionUnit::Week),
+        "month" => Ok(RepetitionUnit::Month),
+        "year" => Ok(RepetitionUnit::Year),
+        "none" => Ok(RepetitionUnit::None),
+        _ => Err(malformed(path, "expected one of day, week, month, year, none")),
+    }
+}
+
+/// Splits `{"type": ..., "value": ...}` into its tag and value path
+fn tag<'a>(value: &'a Value, path: &str) -> Result<(&'a str, Option<&'a Value>), PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    let name = match field(map, "type") {
+        Some(t) => as_str(t, &format!("{}.type", path))?,
+        None => return Err(malformed(&format!("{}.type", path), "missing")),
+    };
+    Ok((name, field(map, "value")))
+}
+
+/// The "value" of a tagged object, required
+fn tag_value<'a>(value: Option<&'a Value>, path: &str) -> Result<&'a Value, PeriodicityJsonError> {
+    value.ok_or_else(|| malformed(&format!("{}.value", path), "missing"))
+}
+
+fn unknown_type(path: &str, name: &str) -> PeriodicityJsonError {
+    malformed(&format!("{}.type", path), &format!("unknown type '{}'", name))
+}
+
+pub(super) fn occurrence_settings_from_json(value: &Value, path: &str) -> Result<OccurrenceTimingSettings, PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    Ok(OccurrenceTimingSettings {
+        duration: optional(map, "duration", &format!("{}.duration", path), as_u16)?,
+        not_before: optional(map, "not_before", &format!("{}.not_before", path), time_from_json)?,
+        best_before: optional(map, "best_before", &format!("{}.best_before", path), time_from_json)?,
+        rep_timing_settings: optional(
+            map,
+            "rep_timing_settings",
+            &format!("{}.rep_timing_settings", path),
+            |v, p| as_vec(v, p, rep_timing_from_json),
+        )?,
+    })
+}
+
+pub(super) fn rep_timing_from_json(value: &Value, path: &str) -> Result<RepTimingSettings, PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    let index_path = format!("{}.rep_index", path);
+    Ok(RepTimingSettings {
+        rep_index: match field(map, "rep_index") {
+            Some(v) => as_u8(v, &index_path)?,
+            None => return Err(malformed(&index_path, "missing")),
+        },
+        not_before: optional(map, "not_before", &format!("{}.not_before", path), time_from_json)?,
+        best_before: optional(map, "best_before", &format!("{}.best_before", path), time_from_json)?,
+    })
+}
+
+pub(super) fn constraints_from_json(value: &Value, path: &str) -> Result<PeriodicityConstraints, PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    Ok(PeriodicityConstraints {
+        day_constraint: optional(map, "day", &format!("{}.day", path), day_constraint_from_json)?,
+        week_constraint: optional(map, "week", &format!("{}.week", path), week_constraint_from_json)?,
+        month_constraint: optional(map, "month", &format!("{}.month", path), month_constraint_from_json)?,
+        year_constraint: optional(map, "year", &format!("{}.year", path), year_constraint_from_json)?,
+    })
+}
+
+pub(super) fn day_constraint_from_json(value: &Value, path: &str) -> Result<DayConstraint, PeriodicityJsonError> {
+    let (name, inner) = tag(value, path)?;
+    let value_path = format!("{}.value", path);
+    match name {
+        "every_day" => Ok(DayConstraint::EveryDay),
+        "every_n_days" => Ok(DayConstraint::EveryNDays(as_u16(tag_value(inner, path)?, &value_path)?)),
+        "specific_days_week" => Ok(DayConstraint::SpecificDaysWeek(
+            as_vec(tag_value(inner, path)?, &value_path, weekday_from_json)?,
+        )),
+        "specific_days_month_from_first" => Ok(DayConstraint::SpecificDaysMonthFromFirst(
+            as_vec(tag_value(inner, path)?, &value_path, as_u8)?,
+        )),
+        "specific_days_month_from_last" => Ok(DayConstraint::SpecificDaysMonthFromLast(
+            as_vec(tag_value(inner, path)?, &value_path, as_u8)?,
+        )),
+        "specific_nth_weekdays_month" => Ok(DayConstraint::SpecificNthWeekdaysMonth(
+            as_vec(tag_value(inner, path)?, &value_path, nth_weekday_from_json)?,
+        )),
+        other => Err(unknown_type(path, other)),
+    }
+}
+
+pub(super) fn nth_weekday_from_json(value: &Value, path: &str) -> Result<NthWeekdayOfMonth, PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    let weekday_path = format!("{}.weekday", path);
+    let position_path = format!("{}.position", path);
+
+    let weekday = match field(map, "weekday") {
+        Some(v) => weekday_from_json(v, &weekday_path)?,
+        None => return Err(malformed(&weekday_path, "missing")),
+    };
+    let position = match field(map, "position") {
+        Some(v) => month_week_position_from_json(v, &position_path)?,
+        None => return Err(malformed(&position_path, "missing")),
+    };
+
+    Ok(NthWeekdayOfMonth { weekday, position })
+}
+
+pub(super) fn month_week_position_from_json(value: &Value, path: &str) -> Result<MonthWeekPosition, PeriodicityJsonError> {
+    let (name, inner) = tag(value, path)?;
+    let n = as_u8(tag_value(inner, path)?, &format!("{}.value", path))?;
+    match name {
+        "from_first" => Ok(MonthWeekPosition::FromFirst(n)),
+        "from_last" => Ok(MonthWeekPosition::FromLast(n)),
+        other => Err(unknown_type(path, other)),
+    }
+}
+
+pub(super) fn week_constraint_from_json(value: &Value, path: &str) -> Result<WeekConstraint, PeriodicityJsonError> {
+    let (name, inner) = tag(value, path)?;
+    let value_path = format!("{}.value", path);
+    match name {
+        "every_week" => Ok(WeekConstraint::EveryWeek),
+        "every_n_weeks" => Ok(WeekConstraint::EveryNWeeks(as_u8(tag_value(inner, path)?, &value_path)?)),
+        "specific_weeks_of_month_from_first" => Ok(WeekConstraint::SpecificWeeksOfMonthFromFirst(
+            as_vec(tag_value(inner, path)?, &value_path, as_u8)?,
+        )),
+        "specific_weeks_of_month_from_last" => Ok(WeekConstraint::SpecificWeeksOfMonthFromLast(
+            as_vec(tag_value(inner, path)?, &value_path, as_u8)?,
+        )),
+        "specific_weeks_of_year" => Ok(WeekConstraint::SpecificWeeksOfYear(
+            as_vec(tag_value(inner, path)?, &value_path, as_u8)?,
+        )),
+        "specific_iso_weeks" => Ok(WeekConstraint::SpecificIsoWeeks(
+            as_vec(tag_value(inner, path)?, &value_path, as_u8)?,
+        )),
+        "even_iso_weeks" => Ok(WeekConstraint::EvenIsoWeeks),
+        "odd_iso_weeks" => Ok(WeekConstraint::OddIsoWeeks),
+        other => Err(unknown_type(path, other)),
+    }
+}
+
+pub(super) fn month_constraint_from_json(value: &Value, path: &str) -> Result<MonthConstraint, PeriodicityJsonError> {
+    let (name, inner) = tag(value, path)?;
+    let value_path = format!("{}.value", path);
+    match name {
+        "every_month" => Ok(MonthConstraint::EveryMonth),
+        "every_n_months" => Ok(MonthConstraint::EveryNMonths(as_u8(tag_value(inner, path)?, &value_path)?)),
+        "specific_months" => Ok(MonthConstraint::SpecificMonths(
+            as_vec(tag_value(inner, path)?, &value_path, month_from_json)?,
+        )),
+        other => Err(unknown_type(path, other)),
+    }
+}
+
+pub(super) fn year_constraint_from_json(value: &Value, path: &str) -> Result<YearConstraint, PeriodicityJsonError> {
+    let (name, inner) = tag(value, path)?;
+    let value_path = format!("{}.value", path);
+    match name {
+        "every_year" => Ok(YearConstraint::EveryYear),
+        "every_n_years" => Ok(YearConstraint::EveryNYears(as_u8(tag_value(inner, path)?, &value_path)?)),
+        "specific_years" => Ok(YearConstraint::SpecificYears(
+            as_vec(tag_value(inner, path)?, &value_path, as_i32)?,
+        )),
+        other => Err(unknown_type(path, other)),
+    }
+}
+
+fn timeframe_from_json(value: &Value, path: &str) -> Result<(DateTime<Utc>, DateTime<Utc>), PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    let bound = |key: &str| {
+        let bound_path = format!("{}.{}", path, key);
+        match field(map, key) {
+            Some(v) => datetime_from_json(v, &bound_path),
+            None => Err(malformed(&bound_path, "missing")),
+        }
+    };
+    Ok((bound("start")?, bound("end")?))
+}
+
+pub(super) fn special_pattern_from_json(value: &Value, path: &str) -> Result<SpecialPattern, PeriodicityJsonError> {
+    let (name, inner) = tag(value, path)?;
+    let value_path = format!("{}.value", path);
+    match name {
+        "custom" => Ok(SpecialPattern::Custom(custom_dates_from_json(tag_value(inner, path)?, &value_path)?)),
+        "unique" => Ok(SpecialPattern::Unique(unique_date_from_json(tag_value(inner, path)?, &value_path)?)),
+        other => Err(unknown_type(path, other)),
+    }
+}
+
+pub(super) fn custom_dates_from_json(value: &Value, path: &str) -> Result<CustomDates, PeriodicityJsonError> {
+    Ok(CustomDates::new(as_vec(value, path, datetime_from_json)?)?)
+}
+
+pub(super) fn unique_date_from_json(value: &Value, path: &str) -> Result<UniqueDate, PeriodicityJsonError> {
+    Ok(UniqueDate { date: datetime_from_json(value, path)? })
+}
+
+pub(super) fn after_completion_from_json(value: &Value, path: &str) -> Result<AfterCompletion, PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    let required = |key: &str| {
+        let key_path = format!("{}.{}", path, key);
+        field(map, key).ok_or_else(|| malformed(&key_path, "missing")).map(|v| (v, key_path))
+    };
+    let (interval, interval_path) = required("interval")?;
+    let (unit, unit_path) = required("unit")?;
+    Ok(AfterCompletion {
+        interval: as_u16(interval, &interval_path)?,
+        unit: rep_unit_from_json(unit, &unit_path)?,
+    })
+}
+
+pub(super) fn exceptions_from_json(value: &Value, path: &str) -> Result<PeriodicityExceptions, PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    Ok(PeriodicityExceptions {
+        dates: sorted_dates(map, path)?,
+        holidays: optional(map, "holidays", &format!("{}.holidays", path), |v, p| as_vec(v, p, holiday_calendar_from_json))?
+            .unwrap_or_default(),
+        roll: optional(map, "roll", &format!("{}.roll", path), roll_policy_from_json)?.unwrap_or_default(),
+    })
+}
+
+pub(super) fn holiday_calendar_from_json(value: &Value, path: &str) -> Result<HolidayCalendar, PeriodicityJsonError> {
+    let map = as_object(value, path)?;
+    let name_path = format!("{}.name", path);
+    let name = match field(map, "name") {
+        Some(v) => as_str(v, &name_path)?.to_string(),
+        None => return Err(malformed(&name_path, "missing")),
+    };
+    Ok(HolidayCalendar::new(name, sorted_dates(map, path)?)?)
+}
+
+/// The optional "dates" list of an exceptions or holidays object, sorted and deduplicated
+fn sorted_dates(map: &Map<String, Value>, path: &str) -> Result<Vec<NaiveDate>, PeriodicityJsonError> {
+    let mut dates = optional(map, "dates", &format!("{}.dates", path), |v, p| as_vec(v, p, date_from_json))?
+        .unwrap_or_default();
+    dates.sort();
+    dates.dedup();
+    Ok(dates)
+}
+
+pub(super) fn roll_policy_from_json(value: &Value, path: &str) -> Result<RollPolicy, PeriodicityJsonError> {
+    match as_str(value, path)? {
+        "skip" => Ok(RollPolicy::Skip),
+        "previous_business_day" => Ok(RollPolicy::PreviousBusinessDay),
+        "next_business_day" => Ok(RollPolicy::NextBusinessDay),
+        "nearest_business_day" => Ok(RollPolicy::NearestBusinessDay),
+        _ => Err(malformed(path, "expected one of skip, previous_business_day, next_business_day, nearest_business_day")),
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::domain::builders::PeriodicityBuilder;
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn round_trip(periodicity: &Periodicity) -> Periodicity {
+        Periodicity::from_json_str(&periodicity.to_json_string()).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_all_constraint_kinds() {
+        let periodicities = vec![
+            PeriodicityBuilder::new()
+                .daily(2)
+                .on_nth_weekdays(vec![NthWeekdayOfMonth::first(Weekday::Mon), NthWeekdayOfMonth::last(Weekday::Fri)])
+                .in_months(vec![Month::January, Month::July])
+                .every_n_years(2)
+                .with_reference_date(utc(2026, 1, 1))
+                .build()
+                .unwrap(),
+            PeriodicityBuilder::new()
+                .weekly(1)
+                .every_n_weeks(2)
+                .between(utc(2026, 1, 1), utc(2027, 1, 1))
+                .build()
+                .unwrap(),
+            PeriodicityBuilder::new()
+                .daily(1)
+                .on_month_days_from_end(vec![1])
+                .on_weeks_of_month_from_end(vec![1])
+                .in_years(vec![2026, 2027])
+                .build()
+                .unwrap(),
+            PeriodicityBuilder::new().monthly(1).every_n_months(3).build().unwrap(),
+            PeriodicityBuilder::new().weekly(1).on_iso_weeks(vec![1, 53]).build().unwrap(),
+            PeriodicityBuilder::new().daily(1).on_weeks_of_year(vec![10]).build().unwrap(),
+            PeriodicityBuilder::new().weekly(1).in_odd_iso_weeks().build().unwrap(),
+            Periodicity::unique(utc(2026, 12, 25)).unwrap(),
+            PeriodicityBuilder::new()
+                .custom_dates(vec![utc(2026, 3, 1), utc(2026, 6, 1)])
+                .unwrap()
+                .build()
+                .unwrap(),
+        ];
+
+        for periodicity in &periodicities {
+            assert_eq!(&round_trip(periodicity), periodicity);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_occurrence_settings() {
+        let at = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .daily(2)
+            .with_occurrence_settings(OccurrenceTimingSettings {
+                duration: Some(15),
+                not_before: Some(at(6)),
+                best_before: None,
+                rep_timing_settings: Some(vec![
+                    RepTimingSettings { rep_index: 0, not_before: Some(at(7)), best_before: Some(at(9)) },
+                    RepTimingSettings { rep_index: 1, not_before: None, best_before: Some(at(21)) },
+                ]),
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(round_trip(&periodicity), periodicity);
+    }
+
+    #[test]
+    fn test_round_trip_exceptions() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .except(vec![day(12, 25), day(8, 15)])
+            .skip_holidays(HolidayCalendar::new("fr-public".into(), vec![day(5, 1), day(1, 1)]).unwrap())
+            .build()
+            .unwrap();
+
+        let json = periodicity.to_json();
+        assert_eq!(json["exceptions"]["dates"], json!(["2026-08-15", "2026-12-25"]));
+        assert_eq!(json["exceptions"]["holidays"][0], json!({ "name": "fr-public", "dates": ["2026-01-01", "2026-05-01"] }));
+        assert_eq!(round_trip(&periodicity), periodicity);
+
+        // No exceptions: null, as before the field existed
+        assert!(Periodicity::daily().unwrap().to_json()["exceptions"].is_null());
+    }
+
+    #[test]
+    fn test_round_trip_roll_policy() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1])
+            .with_roll_policy(RollPolicy::NearestBusinessDay)
+            .build()
+            .unwrap();
+
+        let json = periodicity.to_json();
+        assert_eq!(json["exceptions"], json!({ "dates": [], "holidays": [], "roll": "nearest_business_day" }));
+        assert_eq!(round_trip(&periodicity), periodicity);
+
+        // Written before roll policies existed: holidays are skipped
+        let mut value = periodicity.to_json();
+        value["exceptions"] = json!({ "dates": ["2026-12-25"] });
+        assert_eq!(Periodicity::from_json(&value).unwrap().exceptions.roll, RollPolicy::Skip);
+
+        value["exceptions"]["roll"] = json!("sideways");
+        assert!(matches!(
+            Periodicity::from_json(&value),
+            Err(PeriodicityJsonError::Malformed { path, .. }) if path == "exceptions.roll"
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_after_completion() {
+        let periodicity = PeriodicityBuilder::new()
+            .weekly(1)
+            .after_completion(10, RepetitionUnit::Day)
+            .build()
+            .unwrap();
+
+        let json = periodicity.to_json();
+        assert_eq!(json["after_completion"], json!({ "interval": 10, "unit": "day" }));
+        assert_eq!(round_trip(&periodicity), periodicity);
+        assert!(Periodicity::daily().unwrap().to_json()["after_completion"].is_null());
+
+        let mut value = json;
+        value["after_completion"] = json!({ "interval": 10 });
+        assert!(matches!(
+            Periodicity::from_json(&value),
+            Err(PeriodicityJsonError::Malformed { path, .. }) if path == "after_completion.unit"
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_alternatives() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1, 15])
+            .or()
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::last(Weekday::Fri)])
+            .in_months(vec![Month::June])
+            .build()
+            .unwrap();
+
+        let json = periodicity.to_json();
+        assert_eq!(json["alternatives"][0]["month"], json!({ "type": "specific_months", "value": ["June"] }));
+        assert_eq!(round_trip(&periodicity), periodicity);
+        assert!(Periodicity::daily().unwrap().to_json()["alternatives"].is_null());
+
+        let mut value = json;
+        value["alternatives"][0]["day"]["value"][0]["weekday"] = json!("Funday");
+        assert!(matches!(
+            Periodicity::from_json(&value),
+            Err(PeriodicityJsonError::Malformed { path, .. }) if path == "alternatives[0].day.value[0].weekday"
+        ));
+    }
+
+    #[test]
+    fn test_json_shape() {
+        let periodicity = Periodicity::on_weekdays(vec![Weekday::Mon, Weekday::Fri]).unwrap();
+        let json = periodicity.to_json();
+
+        assert_eq!(json["version"], 6);
+        assert_eq!(json["rep_unit"], "day");
+        assert_eq!(json["constraints"]["day"], json!({ "type": "specific_days_week", "value": ["Mon", "Fri"] }));
+        assert!(json["timeframe"].is_null());
+    }
+
+    #[test]
+    fn test_missing_optional_fields_read_as_none() {
+        let periodicity = Periodicity::from_json(&json!({
+            "version": 1,
+            "rep_unit": "week",
+            "rep_per_unit": 1,
+            "constraints": { "week": { "type": "every_week" } },
+        })).unwrap();
+
+        assert_eq!(periodicity.rep_unit, RepetitionUnit::Week);
+        assert_eq!(periodicity.constraints.week_constraint, Some(WeekConstraint::EveryWeek));
+        assert!(periodicity.timeframe.is_none());
+    }
+
+    #[test]
+    fn test_rejects_unsupported_version() {
+        let mut json = Periodicity::daily().unwrap().to_json();
+        json["version"] = json!(99);
+        assert_eq!(Periodicity::from_json(&json), Err(PeriodicityJsonError::UnsupportedVersion(99)));
+        json["version"] = json!(PERIODICITY_JSON_VERSION + 1);
+        assert!(Periodicity::from_json(&json).is_err());
+        json["version"] = json!(0);
+        assert_eq!(Periodicity::from_json(&json), Err(PeriodicityJsonError::UnsupportedVersion(0)));
+
+        // Older versions still read
+        json["version"] = json!(1);
+        assert_eq!(Periodicity::from_json(&json), Ok(Periodicity::daily().unwrap()));
+    }
+
+    #[test]
+    fn test_malformed_reports_path() {
+        let mut json = Periodicity::on_weekdays(vec![Weekday::Mon]).unwrap().to_json();
+        json["constraints"]["day"]["value"] = json!(["Mon", "Funday"]);
+
+        match Periodicity::from_json(&json) {
+            Err(PeriodicityJsonError::Malformed { path, .. }) => {
+                assert_eq!(path, "constraints.day.value[1]");
+            }
+            other => panic!("expected Malformed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_periodicity_is_rejected() {
+        // Well-formed, but EveryNDays(0) is invalid
+        let json = json!({
+            "version": 1,
+            "rep_unit": "day",
+            "rep_per_unit": 1,
+            "constraints": { "day": { "type": "every_n_days", "value": 0 } },
+        });
+        assert!(matches!(Periodicity::from_json(&json), Err(PeriodicityJsonError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        assert!(matches!(Periodicity::from_json_str("{ not json"), Err(PeriodicityJsonError::Syntax(_))));
+    }
+}
@@ -0,0 +1,1079 @@
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+use chrono::{Days, Month, NaiveDate, TimeZone, Utc, Weekday};
+use crate::domain::builders::PeriodicityBuilder;
+use crate::domain::entities::task::periodicity::*;
+use crate::domain::validators::periodicity_validator::ValidationError;
+
+// ========================================================================
+// NATURAL-LANGUAGE PERIODICITY
+// Short English phrases ("every other Tuesday") to a Periodicity
+// ========================================================================
+//
+// A phrase is a sequence of parts, in any order:
+// - how often: "3 times a day", "once a week", "twice per month", "daily", "weekly", ...
+// - every: "every day", "every 3 days", "every other week", "every 2nd month",
+//   "every Tuesday", "every other Tuesday and Thursday", "every weekday"
+// - weekdays: "on Mondays and Fridays", "Tue, Thu", "on weekdays", "on weekends"
+// - days of the month: "the 13th and 24th", "on the last day of the month",
+//   "the second-to-last day"
+// - nth weekdays: "the first Monday", "last Friday of the month", "the 2nd and 4th Tuesday"
+// - weeks of the month: "in the first and third weeks of the month", "the last week"
+// - weeks of the year: "in weeks 1 and 27 of the year" (counted from `week_start`),
+//   "in ISO week 53", "in even ISO weeks", "in odd ISO weeks"
+// - months and years: "in January and February", "in Jan, Mar", "in 2026 and 2027"
+// - timeframe: "from 2026-03-01 until 30 June 2026", "starting March 1 2026",
+//   "until 2026-06-30" (UTC calendar days, the last one included)
+// - "or" starts an alternative set of day, week, month and year filters:
+//   "the 1st and 15th, or the last Friday" (how often and the timeframe are
+//   shared by every set)
+// Words are case-insensitive; commas and "and" separate list items. The
+// wording of `Periodicity::describe` reads back, except for special patterns,
+// timing settings, exceptions and completion-relative counts.
+//
+// The repetition unit is the one of "how often", else a day when days are
+// picked (weekdays, days of the month, "every N days"), else the unit of an
+// "every" part, else the finest of the week, month and year filters.
+// "every other Tuesday" is Tuesdays of every other week, counted from the
+// reference date like any `every_n_weeks`.
+
+// ========================================================================
+// ERRORS
+// ========================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhraseError {
+    /// Text the parser didn't understand
+    /// `span` is its byte range in the phrase (empty at the end of the
+    /// phrase when something is missing)
+    Unexpected {
+        span: Range<usize>,
+        found: String,
+        expected: String,
+    },
+
+    /// A part of the schedule given twice ("on Mondays on the 13th")
+    Repeated { span: Range<usize>, part: String },
+
+    /// Understood, but the periodicity is invalid
+    Invalid(ValidationError),
+}
+
+impl PhraseError {
+    /// Byte range of the phrase the error is about (None for `Invalid`)
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            PhraseError::Unexpected { span, .. } | PhraseError::Repeated { span, .. } => Some(span.clone()),
+            PhraseError::Invalid(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for PhraseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhraseError::Unexpected { found, expected, .. } if found.is_empty() => {
+                write!(f, "Expected {} at the end of the schedule", expected)
+            }
+            PhraseError::Unexpected { span, found, expected } => {
+                write!(f, "Didn't understand '{}' at {}..{}: expected {}", found, span.start, span.end, expected)
+            }
+            PhraseError::Repeated { span, part } => {
+                write!(f, "The {} is given twice (again at {}..{})", part, span.start, span.end)
+            }
+            PhraseError::Invalid(error) => {
+                write!(f, "Invalid periodicity: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PhraseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PhraseError::Invalid(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ValidationError> for PhraseError {
+    fn from(error: ValidationError) -> Self {
+        PhraseError::Invalid(error)
+    }
+}
+
+// ========================================================================
+// PUBLIC API
+// ========================================================================
+
+impl Periodicity {
+    /// Reads a short English phrase (see the forms above)
+    ///
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::builders::periodicity_builder::PeriodicityBuilder;
+    /// use tsadaash::domain::entities::task::Periodicity;
+    /// use chrono::Weekday;
+    ///
+    /// let parsed = Periodicity::from_phrase("every other Tuesday").unwrap();
+    /// let built = PeriodicityBuilder::new()
+    ///     .daily(1)
+    ///     .on_weekdays(vec![Weekday::Tue])
+    ///     .every_n_weeks(2)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(parsed, built);
+    ///
+    /// let error = Periodicity::from_phrase("every other Tusday").unwrap_err();
+    /// assert_eq!(error.span(), Some(12..18));
+    /// ```
+    pub fn from_phrase(text: &str) -> Result<Self, PhraseError> {
+        let mut parser = Parser::new(text);
+        while !parser.at_end() {
+            parser.part()?;
+        }
+        parser.build()
+    }
+}
+
+impl FromStr for Periodicity {
+    type Err = PhraseError;
+
+    /// Same as `Periodicity::from_phrase`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Periodicity::from_phrase(text)
+    }
+}
+
+// ========================================================================
+// PARSER
+// ========================================================================
+
+/// A word of the phrase, lowercased, with its byte range
+#[derive(Debug, Clone)]
+struct Token {
+    word: String,
+    span: Range<usize>,
+}
+
+/// "first" .. "fifth", "13th" (1-based), or "last", "second-to-last" (0-based from the end)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    First(u32),
+    Last(u8),
+}
+
+/// A position, with the weekdays that follow it ("the first Monday")
+struct Item {
+    position: Position,
+    weekdays: Vec<Weekday>,
+    span: Range<usize>,
+}
+
+/// What the phrase said so far
+#[derive(Default)]
+struct Parts {
+    count: Option<u8>,
+    unit: Option<RepetitionUnit>,
+    every_unit: Option<RepetitionUnit>,
+    day: Option<DayConstraint>,
+    week: Option<WeekConstraint>,
+    month: Option<MonthConstraint>,
+    year: Option<YearConstraint>,
+    first_day: Option<NaiveDate>,
+    last_day: Option<NaiveDate>,
+    /// Filter sets closed by "or"
+    previous_sets: Vec<PeriodicityConstraints>,
+}
+
+impl Parts {
+    /// The filters given since the last "or"
+    fn take_filters(&mut self) -> PeriodicityConstraints {
+        PeriodicityConstraints {
+            day_constraint: self.day.take(),
+            week_constraint: self.week.take(),
+            month_constraint: self.month.take(),
+            year_constraint: self.year.take(),
+        }
+    }
+}
+
+struct Parser<'t> {
+    text: &'t str,
+    tokens: Vec<Token>,
+    pos: usize,
+    parts: Parts,
+}
+
+/// Expected at the start of a part
+const PART: &str = "a schedule part (e.g. \"every\", \"on\", \"the\", \"in\", \"from\", \"3 times a day\")";
+const DATE: &str = "a date (2026-03-01, 1 March 2026 or March 1 2026)";
+const UNIT: &str = "day, week, month or year";
+
+impl<'t> Parser<'t> {
+    fn new(text: &'t str) -> Self {
+        let mut tokens = Vec::new();
+        let mut start = None;
+        for (i, c) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+            let separator = c.is_whitespace() || c == ',';
+            match (start, separator) {
+                (None, false) => start = Some(i),
+                (Some(s), true) => {
+                    tokens.push(Token { word: text[s..i].to_lowercase(), span: s..i });
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        Self { text, tokens, pos: 0, parts: Parts::default() }
+    }
+
+    // ── TOKENS ──────────────────────────────────────────────
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&str> {
+        self.tokens.get(self.pos + offset).map(|t| t.word.as_str())
+    }
+
+    fn advance(&mut self) {
+        self.pos += 1;
+    }
+
+    /// Consumes the next word if it is one of `words`
+    fn eat(&mut self, words: &[&str]) -> bool {
+        match self.peek() {
+            Some(word) if words.contains(&word) => {
+                self.advance();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Byte range from token `from` to the last consumed token
+    fn span_from(&self, from: usize) -> Range<usize> {
+        let start = self.tokens.get(from).map(|t| t.span.start).unwrap_or(self.text.len());
+        let end = self.pos.checked_sub(1)
+            .and_then(|last| self.tokens.get(last))
+            .map(|t| t.span.end)
+            .unwrap_or(start);
+        start..end.max(start)
+    }
+
+    /// Unexpected error at the next token
+    fn unexpected(&self, expected: &str) -> PhraseError {
+        match self.tokens.get(self.pos) {
+            Some(token) => PhraseError::Unexpected {
+                span: token.span.clone(),
+                found: self.text[token.span.clone()].to_string(),
+                expected: expected.to_string(),
+            },
+            None => PhraseError::Unexpected {
+                span: self.text.len()..self.text.len(),
+                found: String::new(),
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    /// Unexpected error over already consumed tokens
+    fn unexpected_at(&self, span: Range<usize>, expected: &str) -> PhraseError {
+        PhraseError::Unexpected {
+            found: self.text[span.clone()].to_string(),
+            span,
+            expected: expected.to_string(),
+        }
+    }
+
+    // ── PARTS ───────────────────────────────────────────────
+
+    fn part(&mut self) -> Result<(), PhraseError> {
+        let start = self.pos;
+        let word = self.peek().unwrap_or_default().to_string();
+        match word.as_str() {
+            "once" | "twice" => self.frequency(start),
+            _ if word.parse::<u32>().is_ok() && matches!(self.peek_at(1), Some("times" | "time" | "x")) => {
+                self.frequency(start)
+            }
+            "daily" | "weekly" | "monthly" | "yearly" | "annually" => {
+                let unit = adverb_unit(&word);
+                self.advance();
+                let span = self.span_from(start);
+                set(&mut self.parts.unit, unit, span, "repetition unit")
+            }
+            "every" | "each" => self.every(start),
+            "on" => {
+                self.advance();
+                self.on(start)
+            }
+            "in" => {
+                self.advance();
+                self.in_part(start)
+            }
+            "from" | "starting" | "beginning" => {
+                self.advance();
+                self.eat(&["on", "from"]);
+                let first = self.date()?;
+                let span = self.span_from(start);
+                set(&mut self.parts.first_day, first, span, "first day")?;
+                if self.eat(&["until", "till", "to", "through", "thru"]) {
+                    self.until(start)?;
+                }
+                Ok(())
+            }
+            "until" | "till" | "through" | "thru" => {
+                self.advance();
+                self.until(start)
+            }
+            "or" => {
+                let filters = self.parts.take_filters();
+                if filters == PeriodicityConstraints::default() {
+                    return Err(self.unexpected("a day, week, month or year filter before \"or\""));
+                }
+                self.parts.previous_sets.push(filters);
+                self.advance();
+                Ok(())
+            }
+            _ => self.on(start),
+        }
+    }
+
+    /// "3 times a day", "once a week", "twice per month", "3 times daily", "once"
+    fn frequency(&mut self, start: usize) -> Result<(), PhraseError> {
+        let count = match self.peek() {
+            Some("once") => 1,
+            Some("twice") => 2,
+            _ => {
+                let count = self.peek().and_then(|w| w.parse::<u8>().ok())
+                    .ok_or_else(|| self.unexpected("a number of times from 1 to 255"))?;
+                self.advance();
+                count
+            }
+        };
+        self.advance();
+        let span = self.span_from(start);
+        set(&mut self.parts.count, count, span, "number of times")?;
+
+        let unit_start = self.pos;
+        let unit = match self.peek() {
+            Some("a" | "an" | "per" | "each") => {
+                self.advance();
+                let unit = self.peek().and_then(unit_word).ok_or_else(|| self.unexpected(UNIT))?;
+                self.advance();
+                unit
+            }
+            Some(word @ ("daily" | "weekly" | "monthly" | "yearly" | "annually")) => {
+                let unit = adverb_unit(word);
+                self.advance();
+                unit
+            }
+            _ => return Ok(()),
+        };
+        let span = self.span_from(unit_start);
+        set(&mut self.parts.unit, unit, span, "repetition unit")
+    }
+
+    /// "every day", "every 3 days", "every other Tuesday", "every weekday", "every January"
+    fn every(&mut self, start: usize) -> Result<(), PhraseError> {
+        self.advance();
+        let n_start = self.pos;
+        let n = match self.peek() {
+            Some("other") => {
+                self.advance();
+                2
+            }
+            Some(word) => match number_or_ordinal(word) {
+                Some(n) => {
+                    self.advance();
+                    n
+                }
+                None => 1,
+            },
+            None => 1,
+        };
+        if n == 0 {
+            return Err(self.unexpected_at(self.span_from(n_start), "a number of at least 1"));
+        }
+
+        if let Some(unit) = self.peek().and_then(unit_word) {
+            self.advance();
+            let span = self.span_from(start);
+            set(&mut self.parts.every_unit, unit, span.clone(), "\"every\" unit")?;
+            if n > 1 {
+                let too_big = |parser: &Self| parser.unexpected_at(parser.span_from(n_start), "a smaller interval");
+                match unit {
+                    RepetitionUnit::Day => {
+                        let n = u16::try_from(n).map_err(|_| too_big(self))?;
+                        set(&mut self.parts.day, DayConstraint::EveryNDays(n), span, "day filter")?;
+                    }
+                    RepetitionUnit::Week => {
+                        let n = u8::try_from(n).map_err(|_| too_big(self))?;
+                        set(&mut self.parts.week, WeekConstraint::EveryNWeeks(n), span, "week filter")?;
+                    }
+                    RepetitionUnit::Month => {
+                        let n = u8::try_from(n).map_err(|_| too_big(self))?;
+                        set(&mut self.parts.month, MonthConstraint::EveryNMonths(n), span, "month filter")?;
+                    }
+                    _ => {
+                        let n = u8::try_from(n).map_err(|_| too_big(self))?;
+                        set(&mut self.parts.year, YearConstraint::EveryNYears(n), span, "year filter")?;
+                    }
+                }
+            }
+            return Ok(());
+        }
+
+        if self.peek().and_then(weekday_group).is_some() {
+            let days = self.weekdays()?;
+            let span = self.span_from(start);
+            set(&mut self.parts.day, DayConstraint::SpecificDaysWeek(days), span.clone(), "day filter")?;
+            if n > 1 {
+                let n = u8::try_from(n).map_err(|_| self.unexpected_at(self.span_from(n_start), "a smaller interval"))?;
+                set(&mut self.parts.week, WeekConstraint::EveryNWeeks(n), span, "week filter")?;
+            }
+            return Ok(());
+        }
+
+        if n == 1 && self.peek().and_then(month_word).is_some() {
+            let months = self.months()?;
+            let span = self.span_from(start);
+            return set(&mut self.parts.month, MonthConstraint::SpecificMonths(months), span, "month filter");
+        }
+
+        Err(self.unexpected("day(s), week(s), month(s), year(s), a weekday or a month"))
+    }
+
+    /// After "on" (or at the start of a part): weekdays or positions
+    fn on(&mut self, start: usize) -> Result<(), PhraseError> {
+        if self.peek().and_then(weekday_group).is_some() {
+            let days = self.weekdays()?;
+            let span = self.span_from(start);
+            return set(&mut self.parts.day, DayConstraint::SpecificDaysWeek(days), span, "day filter");
+        }
+        if self.peek() == Some("the") || self.peek().and_then(position_start).is_some() {
+            return self.positions(start);
+        }
+        Err(self.unexpected(PART))
+    }
+
+    /// After "in": months, years or weeks of the month
+    fn in_part(&mut self, start: usize) -> Result<(), PhraseError> {
+        if self.peek().and_then(month_word).is_some() {
+            let months = self.months()?;
+            let span = self.span_from(start);
+            return set(&mut self.parts.month, MonthConstraint::SpecificMonths(months), span, "month filter");
+        }
+        if self.peek().and_then(year_word).is_some() {
+            let mut years = Vec::new();
+            while let Some(year) = self.peek().and_then(year_word) {
+                years.push(year);
+                self.advance();
+                if !(self.peek() == Some("and") && self.peek_at(1).and_then(year_word).is_some()) {
+                    continue;
+                }
+                self.advance();
+            }
+            let span = self.span_from(start);
+            return set(&mut self.parts.year, YearConstraint::SpecificYears(years), span, "year filter");
+        }
+        if let Some(even) = self.peek().and_then(|word| match word { "even" => Some(true), "odd" => Some(false), _ => None }) {
+            self.advance();
+            if !(self.eat(&["iso"]) && self.eat(&["weeks"])) {
+                return Err(self.unexpected("'ISO weeks'"));
+            }
+            let span = self.span_from(start);
+            let weeks = if even { WeekConstraint::EvenIsoWeeks } else { WeekConstraint::OddIsoWeeks };
+            return set(&mut self.parts.week, weeks, span, "week filter");
+        }
+        if self.eat(&["iso"]) {
+            let weeks = self.week_numbers()?;
+            let span = self.span_from(start);
+            return set(&mut self.parts.week, WeekConstraint::SpecificIsoWeeks(weeks), span, "week filter");
+        }
+        if matches!(self.peek(), Some("week" | "weeks")) {
+            let weeks = self.week_numbers()?;
+            if !(self.eat(&["of"]) && self.eat(&["the"]) && self.eat(&["year"])) {
+                return Err(self.unexpected("'of the year'"));
+            }
+            let span = self.span_from(start);
+            return set(&mut self.parts.week, WeekConstraint::SpecificWeeksOfYear(weeks), span, "week filter");
+        }
+        if self.peek() == Some("the") || self.peek().and_then(position_start).is_some() {
+            return self.positions(start);
+        }
+        Err(self.unexpected("a month, a year, weeks of the month or weeks of the year"))
+    }
+
+    /// "week 53", "weeks 1, 10 and 20" (0-based in the result)
+    fn week_numbers(&mut self) -> Result<Vec<u8>, PhraseError> {
+        if !self.eat(&["week", "weeks"]) {
+            return Err(self.unexpected("week(s)"));
+        }
+        let week_number = |word: &str| word.parse::<u8>().ok().filter(|week| (1..=53).contains(week));
+        let mut weeks = Vec::new();
+        loop {
+            let week = self.peek().and_then(week_number).ok_or_else(|| self.unexpected("a week number from 1 to 53"))?;
+            weeks.push(week - 1);
+            self.advance();
+            if self.peek() == Some("and") && self.peek_at(1).and_then(week_number).is_some() {
+                self.advance();
+            } else if self.peek().and_then(week_number).is_none() {
+                return Ok(weeks);
+            }
+        }
+    }
+
+    /// "the 13th and 24th", "the last day", "the first Monday and last Friday",
+    /// "the first and third weeks", each optionally "of the month"
+    fn positions(&mut self, start: usize) -> Result<(), PhraseError> {
+        self.eat(&["the"]);
+        let mut items: Vec<Item> = Vec::new();
+        loop {
+            let item_start = self.pos;
+            let position = self.position()?;
+            let weekdays = if self.peek().and_then(weekday_group).is_some() { self.weekdays()? } else { Vec::new() };
+            items.push(Item { position, weekdays, span: self.span_from(item_start) });
+
+            let and = self.peek() == Some("and") || self.peek() == Some("the");
+            let next = if and { self.peek_at(1) } else { self.peek() };
+            let next_is_position = next.and_then(position_start).is_some()
+                || (and && next == Some("the") && self.peek_at(2).and_then(position_start).is_some());
+            if !next_is_position {
+                break;
+            }
+            if and {
+                self.advance();
+            }
+            self.eat(&["the"]);
+        }
+
+        // "the 2nd and 4th Tuesday": positions without weekdays take the next ones
+        if items.iter().any(|item| !item.weekdays.is_empty()) {
+            let mut following: Vec<Weekday> = Vec::new();
+            for item in items.iter_mut().rev() {
+                if item.weekdays.is_empty() {
+                    item.weekdays = following.clone();
+                } else {
+                    following = item.weekdays.clone();
+                }
+            }
+            if let Some(item) = items.iter().find(|item| item.weekdays.is_empty()) {
+                return Err(self.unexpected_at(item.span.clone(), "a weekday after the position"));
+            }
+            let mut patterns = Vec::new();
+            for item in &items {
+                let position = match item.position {
+                    Position::First(n @ 1..=5) => MonthWeekPosition::FromFirst(n as u8 - 1),
+                    Position::First(_) => return Err(self.unexpected_at(item.span.clone(), "first to fifth, or last")),
+                    Position::Last(n) => MonthWeekPosition::FromLast(n),
+                };
+                patterns.extend(item.weekdays.iter().map(|&weekday| NthWeekdayOfMonth { weekday, position }));
+            }
+            self.of_the_month();
+            let span = self.span_from(start);
+            return set(&mut self.parts.day, DayConstraint::SpecificNthWeekdaysMonth(patterns), span, "day filter");
+        }
+
+        let weeks = self.eat(&["week", "weeks"]);
+        if !weeks {
+            self.eat(&["day", "days"]);
+        }
+        self.of_the_month();
+        let span = self.span_from(start);
+
+        let (max, expected) = if weeks { (5, "weeks 1st to 5th, or from the last") } else { (31, "days 1st to 31st, or from the last") };
+        let mut from_first = Vec::new();
+        let mut from_last = Vec::new();
+        for item in &items {
+            match item.position {
+                Position::First(n) if (1..=max).contains(&n) && from_last.is_empty() => from_first.push(n as u8 - 1),
+                Position::Last(n) if from_first.is_empty() => from_last.push(n),
+                _ => return Err(self.unexpected_at(item.span.clone(), expected)),
+            }
+        }
+        match (weeks, from_first.is_empty()) {
+            (true, false) => set(&mut self.parts.week, WeekConstraint::SpecificWeeksOfMonthFromFirst(from_first), span, "week filter"),
+            (true, true) => set(&mut self.parts.week, WeekConstraint::SpecificWeeksOfMonthFromLast(from_last), span, "week filter"),
+            (false, false) => set(&mut self.parts.day, DayConstraint::SpecificDaysMonthFromFirst(from_first), span, "day filter"),
+            (false, true) => set(&mut self.parts.day, DayConstraint::SpecificDaysMonthFromLast(from_last), span, "day filter"),
+        }
+    }
+
+    /// "13th", "first", "last", "second-to-last", "second to last"
+    fn position(&mut self) -> Result<Position, PhraseError> {
+        let word = self.peek().unwrap_or_default().to_string();
+        if word == "last" {
+            self.advance();
+            return Ok(Position::Last(0));
+        }
+        if let Some(base) = word.strip_suffix("-to-last") {
+            let n = position_start(base).filter(|n| (2..=u8::MAX as u32).contains(n))
+                .ok_or_else(|| self.unexpected("second-to-last, third-to-last, ..."))?;
+            self.advance();
+            return Ok(Position::Last(n as u8 - 1));
+        }
+        let n = position_start(&word).ok_or_else(|| self.unexpected("a position (13th, first, last, ...)"))?;
+        self.advance();
+        if self.peek() == Some("to") && self.peek_at(1) == Some("last") {
+            if n < 2 {
+                return Err(self.unexpected("a position (13th, first, last, ...)"));
+            }
+            self.advance();
+            self.advance();
+            return Ok(Position::Last(n as u8 - 1));
+        }
+        Ok(Position::First(n))
+    }
+
+    /// Optional "of the month", "of each month", "of every month"
+    fn of_the_month(&mut self) {
+        if self.peek() == Some("of")
+            && matches!(self.peek_at(1), Some("the" | "each" | "every" | "a"))
+            && self.peek_at(2) == Some("month")
+        {
+            self.pos += 3;
+        }
+    }
+
+    /// "Mondays and Fridays", "Tue, Thu", "weekdays", "weekends and Mondays"
+    fn weekdays(&mut self) -> Result<Vec<Weekday>, PhraseError> {
+        let mut days = Vec::new();
+        loop {
+            let group = self.peek().and_then(weekday_group).ok_or_else(|| self.unexpected("a weekday"))?;
+            days.extend(group);
+            self.advance();
+            if self.peek() == Some("and") && self.peek_at(1).and_then(weekday_group).is_some() {
+                self.advance();
+            } else if self.peek().and_then(weekday_group).is_none() {
+                return Ok(days);
+            }
+        }
+    }
+
+    /// "January and February", "Jan, Mar"
+    fn months(&mut self) -> Result<Vec<Month>, PhraseError> {
+        let mut months = Vec::new();
+        loop {
+            let month = self.peek().and_then(month_word).ok_or_else(|| self.unexpected("a month"))?;
+            months.push(month);
+            self.advance();
+            if self.peek() == Some("and") && self.peek_at(1).and_then(month_word).is_some() {
+                self.advance();
+            } else if self.peek().and_then(month_word).is_none() {
+                return Ok(months);
+            }
+        }
+    }
+
+    /// The date after "until" (included)
+    fn until(&mut self, start: usize) -> Result<(), PhraseError> {
+        let last = self.date()?;
+        let span = self.span_from(start);
+        set(&mut self.parts.last_day, last, span, "last day")
+    }
+
+    /// "2026-03-01", "1 March 2026", "1st March 2026", "March 1 2026"
+    fn date(&mut self) -> Result<NaiveDate, PhraseError> {
+        let start = self.pos;
+        let word = self.peek().ok_or_else(|| self.unexpected(DATE))?.to_string();
+        if let Ok(date) = NaiveDate::parse_from_str(&word, "%Y-%m-%d") {
+            self.advance();
+            return Ok(date);
+        }
+
+        let (day, month) = match (number_or_ordinal(&word), month_word(&word)) {
+            (Some(day), None) => {
+                self.advance();
+                let month = self.peek().and_then(month_word).ok_or_else(|| self.unexpected("a month"))?;
+                (day, month)
+            }
+            (None, Some(month)) => {
+                self.advance();
+                let day = self.peek().and_then(number_or_ordinal).ok_or_else(|| self.unexpected("a day of the month"))?;
+                (day, month)
+            }
+            _ => return Err(self.unexpected(DATE)),
+        };
+        self.advance();
+        let year = self.peek().and_then(year_word).ok_or_else(|| self.unexpected("a year"))?;
+        self.advance();
+        NaiveDate::from_ymd_opt(year, month.number_from_month(), day)
+            .ok_or_else(|| self.unexpected_at(self.span_from(start), "an existing date"))
+    }
+
+    // ── BUILD ───────────────────────────────────────────────
+
+    fn build(self) -> Result<Periodicity, PhraseError> {
+        let mut parts = self.parts;
+        let last = parts.take_filters();
+        if !parts.previous_sets.is_empty() && last == PeriodicityConstraints::default() {
+            return Err(PhraseError::Unexpected {
+                span: self.text.len()..self.text.len(),
+                found: String::new(),
+                expected: "a day, week, month or year filter after \"or\"".to_string(),
+            });
+        }
+        let mut sets = std::mem::take(&mut parts.previous_sets);
+        sets.push(last);
+
+        let any = |has: fn(&PeriodicityConstraints) -> bool| sets.iter().any(has);
+        let filter_unit = if any(|set| set.day_constraint.is_some()) {
+            Some(RepetitionUnit::Day)
+        } else {
+            parts.every_unit.or(if any(|set| set.week_constraint.is_some()) {
+                Some(RepetitionUnit::Week)
+            } else if any(|set| set.month_constraint.is_some()) {
+                Some(RepetitionUnit::Month)
+            } else if any(|set| set.year_constraint.is_some()) {
+                Some(RepetitionUnit::Year)
+            } else {
+                None
+            })
+        };
+        let Some(unit) = parts.unit.or(filter_unit) else {
+            return Err(PhraseError::Unexpected {
+                span: self.text.len()..self.text.len(),
+                found: String::new(),
+                expected: "how often (e.g. \"every day\", \"once a week\", \"on Mondays\")".to_string(),
+            });
+        };
+
+        let count = parts.count.unwrap_or(1);
+        let mut builder = match unit {
+            RepetitionUnit::Day => PeriodicityBuilder::new().daily(count),
+            RepetitionUnit::Week => PeriodicityBuilder::new().weekly(count),
+            RepetitionUnit::Month => PeriodicityBuilder::new().monthly(count),
+            _ => PeriodicityBuilder::new().yearly(count),
+        };
+
+        for (index, set) in sets.into_iter().enumerate() {
+            if index > 0 {
+                builder = builder.or();
+            }
+            builder = with_filters(builder, set);
+        }
+
+        let midnight = |day: NaiveDate| Utc.from_utc_datetime(&day.and_hms_opt(0, 0, 0).unwrap_or_default());
+        // The last day is included: the timeframe ends when the next day starts
+        let end_of = |day: NaiveDate| midnight(day.checked_add_days(Days::new(1)).unwrap_or(day));
+        builder = match (parts.first_day, parts.last_day) {
+            (Some(first), Some(last)) => builder.between(midnight(first), end_of(last)),
+            (Some(first), None) => builder.starting_from(midnight(first)),
+            (None, Some(last)) => builder.until(end_of(last)),
+            (None, None) => builder,
+        };
+
+        Ok(builder.build()?)
+    }
+}
+
+/// Applies one filter set to the builder
+fn with_filters(mut builder: PeriodicityBuilder, filters: PeriodicityConstraints) -> PeriodicityBuilder {
+    let one_based = |values: Vec<u8>| values.into_iter().map(|v| v + 1).collect::<Vec<_>>();
+    builder = match filters.day_constraint {
+        None => builder,
+        Some(DayConstraint::EveryDay) => builder.every_day(),
+        Some(DayConstraint::EveryNDays(n)) => builder.every_n_days(n),
+        Some(DayConstraint::SpecificDaysWeek(days)) => builder.on_weekdays(days),
+        Some(DayConstraint::SpecificDaysMonthFromFirst(days)) => builder.on_month_days(one_based(days)),
+        Some(DayConstraint::SpecificDaysMonthFromLast(days)) => builder.on_month_days_from_end(one_based(days)),
+        Some(DayConstraint::SpecificNthWeekdaysMonth(patterns)) => builder.on_nth_weekdays(patterns),
+    };
+    builder = match filters.week_constraint {
+        None => builder,
+        Some(WeekConstraint::EveryWeek) => builder.every_week(),
+        Some(WeekConstraint::EveryNWeeks(n)) => builder.every_n_weeks(n),
+        Some(WeekConstraint::SpecificWeeksOfMonthFromFirst(weeks)) => builder.on_weeks_of_month(one_based(weeks)),
+        Some(WeekConstraint::SpecificWeeksOfMonthFromLast(weeks)) => builder.on_weeks_of_month_from_end(one_based(weeks)),
+        Some(WeekConstraint::SpecificWeeksOfYear(weeks)) => builder.on_weeks_of_year(one_based(weeks)),
+        Some(WeekConstraint::SpecificIsoWeeks(weeks)) => builder.on_iso_weeks(one_based(weeks)),
+        Some(WeekConstraint::EvenIsoWeeks) => builder.in_even_iso_weeks(),
+        Some(WeekConstraint::OddIsoWeeks) => builder.in_odd_iso_weeks(),
+    };
+    builder = match filters.month_constraint {
+        None => builder,
+        Some(MonthConstraint::EveryMonth) => builder.every_month(),
+        Some(MonthConstraint::EveryNMonths(n)) => builder.every_n_months(n),
+        Some(MonthConstraint::SpecificMonths(months)) => builder.in_months(months),
+    };
+    match filters.year_constraint {
+        None => builder,
+        Some(YearConstraint::EveryYear) => builder.every_year(),
+        Some(YearConstraint::EveryNYears(n)) => builder.every_n_years(n),
+        Some(YearConstraint::SpecificYears(years)) => builder.in_years(years),
+    }
+}
+
+/// Stores a part, unless the phrase already gave it
+fn set<T>(slot: &mut Option<T>, value: T, span: Range<usize>, part: &str) -> Result<(), PhraseError> {
+    if slot.is_some() {
+        return Err(PhraseError::Repeated { span, part: part.to_string() });
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+// ── WORDS ───────────────────────────────────────────────────
+
+fn unit_word(word: &str) -> Option<RepetitionUnit> {
+    match word {
+        "day" | "days" => Some(RepetitionUnit::Day),
+        "week" | "weeks" => Some(RepetitionUnit::Week),
+        "month" | "months" => Some(RepetitionUnit::Month),
+        "year" | "years" => Some(RepetitionUnit::Year),
+        _ => None,
+    }
+}
+
+fn adverb_unit(word: &str) -> RepetitionUnit {
+    match word {
+        "daily" => RepetitionUnit::Day,
+        "weekly" => RepetitionUnit::Week,
+        "monthly" => RepetitionUnit::Month,
+        _ => RepetitionUnit::Year,
+    }
+}
+
+/// "3", "3rd", "21st"
+fn number_or_ordinal(word: &str) -> Option<u32> {
+    let digits = ["st", "nd", "rd", "th"].iter().find_map(|suffix| word.strip_suffix(suffix)).unwrap_or(word);
+    digits.parse().ok()
+}
+
+/// First word of a position: "13th" (13), "first" (1) .. "fifth" (5), "last" (0)
+fn position_start(word: &str) -> Option<u32> {
+    const WORDS: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+    if word == "last" || word.ends_with("-to-last") {
+        return Some(0);
+    }
+    if let Some(index) = WORDS.iter().position(|w| *w == word) {
+        return Some(index as u32 + 1);
+    }
+    // Ordinals only: a bare number is a count or a year
+    if word.parse::<u32>().is_ok() {
+        return None;
+    }
+    number_or_ordinal(word)
+}
+
+/// A weekday ("monday", "mondays", "mon"), or "weekday(s)" / "weekend(s)"
+fn weekday_group(word: &str) -> Option<Vec<Weekday>> {
+    use Weekday::*;
+    match word {
+        "weekday" | "weekdays" => return Some(vec![Mon, Tue, Wed, Thu, Fri]),
+        "weekend" | "weekends" => return Some(vec![Sat, Sun]),
+        _ => {}
+    }
+    let singular = word.strip_suffix('s').filter(|w| w.ends_with("day")).unwrap_or(word);
+    let day = match singular {
+        "monday" | "mon" => Mon,
+        "tuesday" | "tue" | "tues" => Tue,
+        "wednesday" | "wed" => Wed,
+        "thursday" | "thu" | "thur" | "thurs" => Thu,
+        "friday" | "fri" => Fri,
+        "saturday" | "sat" => Sat,
+        "sunday" | "sun" => Sun,
+        _ => return None,
+    };
+    Some(vec![day])
+}
+
+/// "january", "jan", "sept"
+fn month_word(word: &str) -> Option<Month> {
+    if word == "sept" {
+        return Some(Month::September);
+    }
+    if word.len() == 3 {
+        return (1..=12u8)
+            .filter_map(|n| Month::try_from(n).ok())
+            .find(|month| month.name()[..3].eq_ignore_ascii_case(word));
+    }
+    Month::from_str(word).ok().filter(|_| word.len() > 3)
+}
+
+/// A four-digit year
+fn year_word(word: &str) -> Option<i32> {
+    (word.len() == 4).then(|| word.parse().ok()).flatten()
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn parse(text: &str) -> Periodicity {
+        Periodicity::from_phrase(text).unwrap_or_else(|e| panic!("'{}': {}", text, e))
+    }
+
+    fn build(builder: PeriodicityBuilder) -> Periodicity {
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_every_n_units() {
+        assert_eq!(parse("every day"), build(PeriodicityBuilder::new().daily(1)));
+        assert_eq!(parse("Every 3 days"), build(PeriodicityBuilder::new().daily(1).every_n_days(3)));
+        assert_eq!(parse("every other week"), build(PeriodicityBuilder::new().weekly(1).every_n_weeks(2)));
+        assert_eq!(parse("every 2nd month"), build(PeriodicityBuilder::new().monthly(1).every_n_months(2)));
+        assert_eq!(parse("every 5 years"), build(PeriodicityBuilder::new().yearly(1).every_n_years(5)));
+        assert_eq!(parse("weekly"), build(PeriodicityBuilder::new().weekly(1)));
+    }
+
+    #[test]
+    fn test_frequency() {
+        assert_eq!(parse("3 times a day"), build(PeriodicityBuilder::new().daily(3)));
+        assert_eq!(parse("twice per week"), build(PeriodicityBuilder::new().weekly(2)));
+        assert_eq!(parse("once every 3 days"), build(PeriodicityBuilder::new().daily(1).every_n_days(3)));
+        assert_eq!(
+            parse("3 times a day on Mondays in January"),
+            build(PeriodicityBuilder::new().daily(3).on_weekdays(vec![Weekday::Mon]).in_months(vec![Month::January])),
+        );
+    }
+
+    #[test]
+    fn test_weekdays() {
+        let on = |days: Vec<Weekday>| build(PeriodicityBuilder::new().daily(1).on_weekdays(days));
+        assert_eq!(parse("on Mondays and Fridays"), on(vec![Weekday::Mon, Weekday::Fri]));
+        assert_eq!(parse("tue, thu"), on(vec![Weekday::Tue, Weekday::Thu]));
+        assert_eq!(parse("every weekday"), on(vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]));
+        assert_eq!(parse("on weekends"), on(vec![Weekday::Sat, Weekday::Sun]));
+        assert_eq!(
+            parse("every other Tuesday and Thursday"),
+            build(PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Tue, Weekday::Thu]).every_n_weeks(2)),
+        );
+    }
+
+    #[test]
+    fn test_positions() {
+        let daily = || PeriodicityBuilder::new().daily(1);
+        assert_eq!(parse("the 13th and 24th"), build(daily().on_month_days(vec![13, 24])));
+        assert_eq!(
+            parse("on the 13th and 24th in January and February"),
+            build(daily().on_month_days(vec![13, 24]).in_months(vec![Month::January, Month::February])),
+        );
+        assert_eq!(parse("on the last day of the month"), build(daily().on_month_days_from_end(vec![1])));
+        assert_eq!(parse("the second-to-last day"), build(daily().on_month_days_from_end(vec![2])));
+        assert_eq!(
+            parse("last Friday of the month"),
+            build(daily().on_nth_weekdays(vec![NthWeekdayOfMonth::last(Weekday::Fri)])),
+        );
+        assert_eq!(
+            parse("the first Monday and the second to last Friday"),
+            build(daily().on_nth_weekdays(vec![NthWeekdayOfMonth::first(Weekday::Mon), NthWeekdayOfMonth::second_last(Weekday::Fri)])),
+        );
+        assert_eq!(
+            parse("the 2nd and 4th Tuesday"),
+            build(daily().on_nth_weekdays(vec![NthWeekdayOfMonth::second(Weekday::Tue), NthWeekdayOfMonth::fourth(Weekday::Tue)])),
+        );
+        assert_eq!(
+            parse("once a week in the first and third weeks of the month"),
+            build(PeriodicityBuilder::new().weekly(1).on_weeks_of_month(vec![1, 3])),
+        );
+        assert_eq!(parse("in 2026 and 2027"), build(PeriodicityBuilder::new().yearly(1).in_years(vec![2026, 2027])));
+    }
+
+    #[test]
+    fn test_weeks_of_the_year() {
+        assert_eq!(
+            parse("on Mondays in weeks 1, 10 and 20 of the year"),
+            build(PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Mon]).on_weeks_of_year(vec![1, 10, 20])),
+        );
+        assert_eq!(parse("in ISO week 53"), build(PeriodicityBuilder::new().weekly(1).on_iso_weeks(vec![53])));
+        assert_eq!(parse("every Friday in even ISO weeks"), build(PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Fri]).in_even_iso_weeks()));
+        assert_eq!(parse("once a week in odd ISO weeks"), build(PeriodicityBuilder::new().weekly(1).in_odd_iso_weeks()));
+
+        let error = |text: &str| Periodicity::from_phrase(text).unwrap_err().span().map(|span| text[span].to_string());
+        assert_eq!(error("in ISO week 54"), Some("54".to_string()));
+        assert_eq!(error("in weeks 1 and 2"), Some(String::new()));
+        assert_eq!(error("in odd weeks"), Some("weeks".to_string()));
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let daily = || PeriodicityBuilder::new().daily(1);
+        assert_eq!(
+            parse("the 1st and 15th, or the last Friday in December"),
+            build(daily().on_month_days(vec![1, 15]).or().on_nth_weekdays(vec![NthWeekdayOfMonth::last(Weekday::Fri)])
+                .in_months(vec![Month::December])),
+        );
+        assert_eq!(
+            parse("once a week in the first week of the month or every Friday until 2026-06-30"),
+            build(PeriodicityBuilder::new().weekly(1).on_weeks_of_month(vec![1]).or().on_weekdays(vec![Weekday::Fri]).until(utc(2026, 7, 1))),
+        );
+
+        let payroll = daily().on_month_days(vec![1, 15]).or().on_weeks_of_month_from_end(vec![1]).build().unwrap();
+        assert_eq!(parse(&payroll.describe()), payroll);
+
+        assert_eq!(Periodicity::from_phrase("or on Mondays").unwrap_err().span(), Some(0..2));
+        assert_eq!(Periodicity::from_phrase("on Mondays or").unwrap_err().span(), Some(13..13));
+    }
+
+    #[test]
+    fn test_timeframe() {
+        let daily = || PeriodicityBuilder::new().daily(1);
+        assert_eq!(
+            parse("every day from 2026-03-01 until 30 June 2026"),
+            build(daily().between(utc(2026, 3, 1), utc(2026, 7, 1))),
+        );
+        assert_eq!(parse("daily starting March 1st 2026"), build(daily().starting_from(utc(2026, 3, 1))));
+        assert_eq!(parse("every day until 2026-06-30"), build(daily().until(utc(2026, 7, 1))));
+    }
+
+    #[test]
+    fn test_descriptions_read_back() {
+        let periodicities = vec![
+            build(PeriodicityBuilder::new().daily(3).on_weekdays(vec![Weekday::Mon]).in_months(vec![Month::January])),
+            build(PeriodicityBuilder::new().weekly(1).every_n_weeks(2)),
+            build(PeriodicityBuilder::new().daily(1).on_nth_weekdays(vec![NthWeekdayOfMonth::first(Weekday::Mon), NthWeekdayOfMonth::last(Weekday::Fri)])),
+            build(PeriodicityBuilder::new().daily(1).on_month_days_from_end(vec![1, 2])),
+            build(PeriodicityBuilder::new().weekly(1).on_weeks_of_month_from_end(vec![1]).in_years(vec![2026])),
+            build(PeriodicityBuilder::new().daily(2).between(utc(2026, 2, 1), utc(2026, 3, 1))),
+            build(PeriodicityBuilder::new().daily(1).on_weeks_of_year(vec![1, 27])),
+            build(PeriodicityBuilder::new().weekly(1).on_iso_weeks(vec![53]).in_years(vec![2026])),
+            build(PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Tue]).in_even_iso_weeks()),
+        ];
+        for periodicity in periodicities {
+            assert_eq!(parse(&periodicity.describe()), periodicity, "{}", periodicity);
+        }
+    }
+
+    #[test]
+    fn test_errors_point_at_the_text() {
+        let error = |text: &str| Periodicity::from_phrase(text).unwrap_err();
+        let spanned = |text: &str| {
+            let span = error(text).span().unwrap();
+            text[span].to_string()
+        };
+
+        assert_eq!(spanned("every other Tusday"), "Tusday");
+        assert_eq!(spanned("on Mondays please"), "please");
+        assert_eq!(spanned("the 32nd"), "32nd");
+        assert_eq!(spanned("every day from 2026-02-30"), "2026-02-30");
+        assert_eq!(spanned("on Mondays on the 13th"), "on the 13th");
+        assert!(matches!(error("on Mondays on the 13th"), PhraseError::Repeated { .. }));
+
+        // Missing words: an empty span at the end
+        assert!(matches!(error("every"), PhraseError::Unexpected { span, .. } if span == (5..5)));
+        assert!(matches!(error(""), PhraseError::Unexpected { span, .. } if span == (0..0)));
+
+        assert!(matches!(error("on Mondays and Mondays"), PhraseError::Invalid(ValidationError::DuplicateValues { .. })));
+        assert_eq!("every other Tuesday".parse::<Periodicity>().unwrap(), parse("every other Tuesday"));
+    }
+}
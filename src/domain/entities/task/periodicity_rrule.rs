@@ -0,0 +1,1501 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::{Datelike, DateTime, Duration, Month, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use crate::domain::builders::PeriodicityBuilder;
+use crate::domain::entities::task::periodicity::*;
+use crate::domain::entities::user::User;
+use crate::domain::validators::periodicity_validator::ValidationError;
+
+// ========================================================================
+// RFC 5545 RECURRENCE RULES
+// Periodicity to and from an iCalendar RRULE (plus its DTSTART)
+// ========================================================================
+//
+// One RRULE instance per occurrence:
+// - FREQ is the repetition unit (DAILY, WEEKLY, MONTHLY, YEARLY)
+// - Day and month filters become BYDAY / BYMONTHDAY / BYMONTH
+// - EveryN* becomes INTERVAL, counted from DTSTART (the reference date)
+// - Weekly, monthly and yearly units get BYSETPOS=1 when a finer BY part is
+//   present: one instance per period, on the first day the occurrence can be done.
+//   With a DTSTART, "any day" is spelled out (all of BYDAY or BYMONTHDAY), as a
+//   bare FREQ=WEEKLY would repeat on DTSTART's weekday
+// - Daily repetition on nth weekdays uses FREQ=MONTHLY (or YEARLY), since RRULE
+//   only allows BYDAY ordinals there; it still yields every matching day
+// - timeframe gives DTSTART and UNTIL (the last second before its exclusive end);
+//   the open ends written by `starting_from` / `until` are left out. DTSTART is
+//   the first occurrence from the timeframe start (or from the reference date
+//   INTERVAL counts from), since RFC 5545 makes DTSTART an instance
+//
+// rep_per_unit and occurrence settings describe each occurrence, not when it
+// happens: they are not part of the rule.
+
+/// A recurrence rule and its start, as written in an iCalendar component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    /// First instance (None when the periodicity has no start of its own)
+    pub dtstart: Option<DateTime<Utc>>,
+
+    /// DTSTART's date as written, for a DATE, floating or TZID value
+    /// (None for a UTC value): the day the rule starts on
+    pub start_date: Option<NaiveDate>,
+
+    /// RRULE value, without the "RRULE:" name (e.g. "FREQ=WEEKLY;BYDAY=MO,FR")
+    pub rrule: String,
+}
+
+impl fmt::Display for RecurrenceRule {
+    /// iCalendar content lines: "DTSTART:20260105T000000Z\nRRULE:FREQ=..."
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(dtstart) = &self.dtstart {
+            writeln!(f, "DTSTART:{}", format_utc(dtstart))?;
+        }
+        write!(f, "RRULE:{}", self.rrule)
+    }
+}
+
+// ========================================================================
+// ERRORS
+// ========================================================================
+
+/// A part of a Periodicity that RRULE can't express
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedRulePart {
+    /// Where it is, as in the JSON representation (e.g. "constraints.week")
+    pub part: String,
+    pub reason: String,
+}
+
+/// Periodicity can't be written as an RRULE; lists every unsupported part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRuleExportError {
+    pub unsupported: Vec<UnsupportedRulePart>,
+}
+
+impl fmt::Display for RRuleExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Periodicity can't be expressed as an RRULE")?;
+        for (i, part) in self.unsupported.iter().enumerate() {
+            let separator = if i == 0 { ": " } else { "; " };
+            write!(f, "{}{} ({})", separator, part.part, part.reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RRuleExportError {}
+
+fn unsupported(part: &str, reason: impl Into<String>) -> UnsupportedRulePart {
+    UnsupportedRulePart { part: part.to_string(), reason: reason.into() }
+}
+
+/// RRULE (or DTSTART) that can't be read into a Periodicity
+#[derive(Debug, Clone, PartialEq)]
+pub enum RRuleImportError {
+    /// Not valid RFC 5545 (malformed or missing part, value out of range)
+    Syntax { part: String, reason: String },
+
+    /// Valid RFC 5545 that a Periodicity can't represent (COUNT on a weekly
+    /// unit, BYHOUR, BYSETPOS=-1, ...)
+    Unsupported { part: String, reason: String },
+
+    /// Parts that can't be used together (COUNT with UNTIL, BYDAY ordinals
+    /// with FREQ=WEEKLY, UNTIL before DTSTART, ...)
+    Contradictory { parts: Vec<String>, reason: String },
+
+    /// The resulting periodicity was rejected by the validator
+    Invalid(ValidationError),
+}
+
+impl fmt::Display for RRuleImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RRuleImportError::Syntax { part, reason } => {
+                write!(f, "Invalid RRULE part {}: {}", part, reason)
+            }
+            RRuleImportError::Unsupported { part, reason } => {
+                write!(f, "Unsupported RRULE part {}: {}", part, reason)
+            }
+            RRuleImportError::Contradictory { parts, reason } => {
+                write!(f, "Contradictory RRULE parts {}: {}", parts.join(" and "), reason)
+            }
+            RRuleImportError::Invalid(error) => {
+                write!(f, "Invalid periodicity: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RRuleImportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RRuleImportError::Invalid(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ValidationError> for RRuleImportError {
+    fn from(error: ValidationError) -> Self {
+        RRuleImportError::Invalid(error)
+    }
+}
+
+fn syntax(part: &str, reason: impl Into<String>) -> RRuleImportError {
+    RRuleImportError::Syntax { part: part.to_string(), reason: reason.into() }
+}
+
+fn unsupported_import(part: &str, reason: impl Into<String>) -> RRuleImportError {
+    RRuleImportError::Unsupported { part: part.to_string(), reason: reason.into() }
+}
+
+fn contradictory(parts: [&str; 2], reason: impl Into<String>) -> RRuleImportError {
+    RRuleImportError::Contradictory { parts: parts.iter().map(|part| part.to_string()).collect(), reason: reason.into() }
+}
+
+// ========================================================================
+// PUBLIC API
+// ========================================================================
+
+impl Periodicity {
+    /// Writes this periodicity as an RFC 5545 RRULE with its DTSTART
+    ///
+    /// `week_start` is the user's first day of the week: it becomes WKST for
+    /// weekly rules, where it decides which days form a week.
+    ///
+    /// Fails with every part RRULE can't express: weeks of the month
+    /// (counted from the first `week_start` of the month), specific years,
+    /// more than one EveryN* interval, alternatives (a union is one RRULE
+    /// per constraint set), special patterns (one-off dates
+    /// are DTSTART / RDATE material, not a rule), exceptions (EXDATE
+    /// material: clear them and list the excluded instances beside the rule)
+    /// and roll policies (moved instances are RDATE material).
+    ///
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::builders::PeriodicityBuilder;
+    /// use tsadaash::domain::entities::task::periodicity::NthWeekdayOfMonth;
+    /// use chrono::Weekday;
+    ///
+    /// let periodicity = PeriodicityBuilder::new()
+    ///     .daily(1)
+    ///     .on_nth_weekdays(vec![NthWeekdayOfMonth::last(Weekday::Fri)])
+    ///     .build()
+    ///     .unwrap();
+    /// let rule = periodicity.to_rrule(Weekday::Mon).unwrap();
+    /// assert_eq!(rule.rrule, "FREQ=MONTHLY;BYDAY=-1FR");
+    /// assert_eq!(rule.dtstart, None);
+    /// ```
+    pub fn to_rrule(&self, week_start: Weekday) -> Result<RecurrenceRule, RRuleExportError> {
+        self.export_rrule(week_start, None)
+    }
+
+    /// Same as `to_rrule`, in the user's local calendar
+    ///
+    /// DTSTART is the start of the user's first logical day with an occurrence.
+    pub fn to_rrule_for(&self, user: &User) -> Result<RecurrenceRule, RRuleExportError> {
+        self.export_rrule(user.week_start(), Some(user))
+    }
+
+    /// `to_rrule`, with DTSTART on UTC days or (with `calendar`) the user's logical days
+    fn export_rrule(&self, week_start: Weekday, calendar: Option<&User>) -> Result<RecurrenceRule, RRuleExportError> {
+        if self.special_pattern.is_some() {
+            return Err(RRuleExportError {
+                unsupported: vec![unsupported("special_pattern", "one-off dates are not a recurrence rule")],
+            });
+        }
+        let Some(unit) = Frequency::of_unit(self.rep_unit) else {
+            return Err(RRuleExportError {
+                unsupported: vec![unsupported("rep_unit", "no repetition unit")],
+            });
+        };
+
+        let mut errors = Vec::new();
+        if !self.exceptions.is_empty() {
+            errors.push(unsupported("exceptions", "excluded days are EXDATEs, not part of a rule"));
+        }
+        if self.exceptions.roll != RollPolicy::Skip {
+            errors.push(unsupported("roll_policy", "moving occurrences to business days has no RRULE equivalent"));
+        }
+        if !self.alternatives.is_empty() {
+            errors.push(unsupported("alternatives", "a union of constraint sets takes one RRULE per set"));
+        }
+        if self.after_completion.is_some() {
+            errors.push(unsupported("after_completion", "counting from the last completion has no RRULE equivalent"));
+        }
+        let mut rule = RuleParts::new(unit);
+        let mut intervals = Vec::new();
+        collect_constraints(&self.constraints, week_start, &mut rule, &mut intervals, &mut errors);
+
+        // Lists of every value add no constraint (as on import)
+        if rule.by_day.len() == 7 && rule.by_day.iter().all(|(ordinal, _)| ordinal.is_none()) {
+            rule.by_day.clear();
+        }
+        if rule.by_month_day == (1..=31).collect::<Vec<i8>>() {
+            rule.by_month_day.clear();
+        }
+        if rule.by_month == (1..=12).collect::<Vec<u8>>() {
+            rule.by_month.clear();
+        }
+
+        // ── INTERVAL ────────────────────────────────────────
+        let interval = intervals.first().copied();
+        for (part, _, _) in intervals.iter().skip(1) {
+            errors.push(unsupported(part, "INTERVAL can only count one unit"));
+        }
+
+        // ── DTSTART / UNTIL ─────────────────────────────────
+        let start = self.timeframe.map(|(start, _)| start).filter(|start| *start > open_start());
+        rule.until = self.timeframe
+            .map(|(_, end)| end)
+            .filter(|end| *end < open_end())
+            .map(|end| end - Duration::seconds(1));
+
+        let dtstart = match (interval, self.reference_date, start) {
+            (Some(_), Some(reference), Some(start)) if reference != start => {
+                errors.push(unsupported(
+                    "reference_date",
+                    "INTERVAL counts from DTSTART, which is the timeframe start",
+                ));
+                Some(start)
+            }
+            (Some(_), Some(reference), None) if rule.until.is_some_and(|until| until < reference) => {
+                errors.push(unsupported("reference_date", "DTSTART would be after the timeframe end"));
+                None
+            }
+            (Some(_), Some(reference), None) => Some(reference),
+            _ => start,
+        };
+        // The first occurrence is in an "on" period of the interval, so INTERVAL
+        // counts from DTSTART with the same phase
+        let dtstart = dtstart.map(|anchor| {
+            match calendar {
+                Some(user) => {
+                    let day_start = user.day_start_instant(user.logical_date(&anchor));
+                    self.occurrences_between_for(day_start, open_end(), user).next()
+                }
+                None => self.occurrences_between(anchor, open_end(), week_start).next(),
+            }
+            .unwrap_or(anchor)
+        });
+
+        // ── FREQ, and BY parts it needs ─────────────────────
+        let has_ordinal = rule.by_day.iter().any(|(ordinal, _)| ordinal.is_some());
+        if unit == Frequency::Daily {
+            // Every matching day is an instance: a coarser FREQ is fine as long
+            // as the BY parts expand to those days
+            let needed = if has_ordinal { Frequency::Monthly } else { Frequency::Daily };
+            rule.freq = match interval {
+                Some((part, frequency, _)) if frequency < needed => {
+                    errors.push(unsupported(part, "nth weekdays need FREQ=MONTHLY, which can't count this interval"));
+                    needed
+                }
+                Some((_, frequency, _)) => frequency,
+                None => needed,
+            };
+            if rule.by_day.is_empty() && rule.by_month_day.is_empty() {
+                match rule.freq {
+                    Frequency::Daily => {}
+                    Frequency::Weekly => rule.by_day = ALL_WEEKDAYS.iter().map(|day| (None, *day)).collect(),
+                    Frequency::Monthly | Frequency::Yearly => rule.by_month_day = (1..=31).collect(),
+                }
+            }
+        } else {
+            if let Some((part, frequency, _)) = interval {
+                if frequency != unit {
+                    errors.push(unsupported(
+                        part,
+                        format!("INTERVAL would count {} for a {} repetition", frequency.unit_name(), unit.name()),
+                    ));
+                }
+            }
+            // Weeks that contain a day of the listed months
+            if unit == Frequency::Weekly && rule.by_day.is_empty() && !rule.by_month.is_empty() {
+                rule.by_day = ALL_WEEKDAYS.iter().map(|day| (None, *day)).collect();
+            }
+            // Without a finer part, instances fall on DTSTART's weekday or day:
+            // spell out "any day" when there is a DTSTART
+            if !rule.has_parts_finer_than(unit) && dtstart.is_some() {
+                match unit {
+                    Frequency::Weekly => rule.by_day = ALL_WEEKDAYS.iter().map(|day| (None, *day)).collect(),
+                    _ => rule.by_month_day = (1..=31).collect(),
+                }
+            }
+            if rule.has_parts_finer_than(unit) {
+                rule.by_set_pos = Some(1);
+            }
+        }
+        rule.interval = interval.map_or(1, |(_, _, n)| n);
+
+        match rule.freq {
+            Frequency::Weekly if !rule.by_month_day.is_empty() => {
+                errors.push(unsupported("constraints.day", "BYMONTHDAY is not allowed with FREQ=WEEKLY"));
+            }
+            Frequency::Daily | Frequency::Weekly if has_ordinal => {
+                errors.push(unsupported("constraints.day", "nth weekdays need FREQ=MONTHLY or YEARLY"));
+            }
+            Frequency::Yearly if has_ordinal && rule.by_month.is_empty() => {
+                errors.push(unsupported(
+                    "constraints.day",
+                    "without BYMONTH, a yearly rule counts nth weekdays within the year, not the month",
+                ));
+            }
+            _ => {}
+        }
+        if rule.freq == Frequency::Weekly {
+            rule.week_start = week_start;
+        }
+
+        if !errors.is_empty() {
+            return Err(RRuleExportError { unsupported: errors });
+        }
+        Ok(RecurrenceRule { dtstart, start_date: None, rrule: rule.to_string() })
+    }
+}
+
+// ========================================================================
+// RRULE IMPORT
+// ========================================================================
+//
+// The reverse mapping, through PeriodicityBuilder:
+// - BYSETPOS=1 on a weekly, monthly or yearly rule: once per week / month / year
+// - Otherwise every instance is an occurrence (repetition unit Day); a bare
+//   FREQ=WEEKLY/MONTHLY/YEARLY repeats on DTSTART's weekday / day / date
+// - BY parts that list every value (all of BYDAY, BYMONTHDAY=1..31,
+//   BYMONTH=1..12) add no constraint
+// - The timeframe starts with DTSTART's day: its date as written for DATE,
+//   floating and TZID values, else the UTC date (or the user's logical day with
+//   `from_rrule_for`); that day's start is the reference date INTERVAL counts from
+// - UNTIL ends the timeframe; COUNT is counted from DTSTART's day and turned into UNTIL
+
+impl RecurrenceRule {
+    /// Reads "DTSTART...\nRRULE:..." content lines, or a bare RRULE value
+    ///
+    /// DTSTART may be UTC ("20260105T090000Z"), floating ("20260105T090000",
+    /// read as UTC), a date ("DTSTART;VALUE=DATE:20260105") or local to a TZID
+    /// ("DTSTART;TZID=Europe/Paris:20260105T090000").
+    pub fn parse(text: &str) -> Result<Self, RRuleImportError> {
+        let mut dtstart = None;
+        let mut start_date = None;
+        let mut rrule = None;
+
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let Some((head, value)) = line.split_once(':') else {
+                // Bare rule value
+                if rrule.replace(line.to_string()).is_some() {
+                    return Err(syntax("RRULE", "appears twice"));
+                }
+                continue;
+            };
+            let mut params = head.split(';');
+            let name = params.next().unwrap_or_default().to_ascii_uppercase();
+            let params: Vec<&str> = params.collect();
+            match name.as_str() {
+                "DTSTART" => {
+                    if dtstart.replace(parse_date_time("DTSTART", &params, value)?).is_some() {
+                        return Err(syntax("DTSTART", "appears twice"));
+                    }
+                    start_date = written_date(value);
+                }
+                "RRULE" => {
+                    if rrule.replace(value.to_string()).is_some() {
+                        return Err(syntax("RRULE", "appears twice"));
+                    }
+                }
+                _ => return Err(unsupported_import(&name, "only DTSTART and RRULE are read")),
+            }
+        }
+
+        let rrule = rrule.ok_or_else(|| syntax("RRULE", "missing"))?;
+        Ok(RecurrenceRule { dtstart, start_date, rrule })
+    }
+}
+
+impl Periodicity {
+    /// Reads an RFC 5545 RRULE (and its DTSTART) into a validated periodicity
+    ///
+    /// `week_start` is the user's first day of the week; a weekly rule with
+    /// INTERVAL or BYSETPOS must use the same WKST, since it decides which
+    /// days form a week. The periodicity gets one repetition per unit.
+    ///
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::entities::task::periodicity::Periodicity;
+    /// use tsadaash::domain::entities::task::RecurrenceRule;
+    /// use chrono::{TimeZone, Utc, Weekday};
+    ///
+    /// let rule = RecurrenceRule::parse("DTSTART:20260105T090000Z\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH").unwrap();
+    /// let periodicity = Periodicity::from_rrule(&rule, Weekday::Mon).unwrap();
+    ///
+    /// let start = Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2026, 1, 26, 0, 0, 0).unwrap();
+    /// let days: Vec<u32> = periodicity.occurrences_between(start, end, Weekday::Mon)
+    ///     .map(|date| chrono::Datelike::day(&date))
+    ///     .collect();
+    /// assert_eq!(days, vec![6, 8, 20, 22]);
+    /// ```
+    pub fn from_rrule(rule: &RecurrenceRule, week_start: Weekday) -> Result<Self, RRuleImportError> {
+        import_rule(rule, week_start, None)
+    }
+
+    /// Same as `from_rrule`, in the user's local calendar
+    ///
+    /// The timeframe starts with the user's logical day of a UTC DTSTART (the
+    /// date as written otherwise), and COUNT counts the user's days.
+    pub fn from_rrule_for(rule: &RecurrenceRule, user: &User) -> Result<Self, RRuleImportError> {
+        import_rule(rule, user.week_start(), Some(user))
+    }
+}
+
+/// `from_rrule`, on UTC days or (with `calendar`) the user's logical days
+fn import_rule(rule: &RecurrenceRule, week_start: Weekday, calendar: Option<&User>) -> Result<Periodicity, RRuleImportError> {
+    let (mut parts, count) = parse_rule_parts(&rule.rrule)?;
+    let dtstart = rule.dtstart;
+
+    // ── Contradictions ──────────────────────────────────
+    if count.is_some() && parts.until.is_some() {
+        return Err(contradictory(["COUNT", "UNTIL"], "a rule ends after COUNT instances or at UNTIL, not both"));
+    }
+    if let (Some(start), Some(until)) = (dtstart, parts.until) {
+        if until < start {
+            return Err(contradictory(["DTSTART", "UNTIL"], "UNTIL is before DTSTART"));
+        }
+    }
+    let has_ordinal = parts.by_day.iter().any(|(ordinal, _)| ordinal.is_some());
+    if has_ordinal && parts.freq < Frequency::Monthly {
+        return Err(contradictory(["FREQ", "BYDAY"], "BYDAY ordinals need FREQ=MONTHLY or YEARLY"));
+    }
+    if parts.freq == Frequency::Weekly && !parts.by_month_day.is_empty() {
+        return Err(contradictory(["FREQ", "BYMONTHDAY"], "BYMONTHDAY is not allowed with FREQ=WEEKLY"));
+    }
+
+    // ── Unsupported ─────────────────────────────────────
+    if let Some(position) = parts.by_set_pos {
+        if position != 1 {
+            return Err(unsupported_import(
+                "BYSETPOS",
+                "only BYSETPOS=1 (the first day of each period) is supported",
+            ));
+        }
+    }
+    if has_ordinal && parts.freq == Frequency::Yearly && parts.by_month.is_empty() {
+        return Err(unsupported_import("BYDAY", "nth weekdays of the year (without BYMONTH)"));
+    }
+    if has_ordinal && parts.by_day.iter().any(|(ordinal, _)| ordinal.is_none()) {
+        return Err(unsupported_import("BYDAY", "nth weekdays can't be mixed with every such weekday"));
+    }
+    if !parts.by_day.is_empty() && !parts.by_month_day.is_empty() {
+        return Err(unsupported_import(
+            "BYMONTHDAY",
+            "days matching both BYDAY and BYMONTHDAY (e.g. Friday the 13th)",
+        ));
+    }
+    if parts.by_month_day.iter().any(|day| *day > 0) && parts.by_month_day.iter().any(|day| *day < 0) {
+        return Err(unsupported_import("BYMONTHDAY", "days counted from the start and the end of the month"));
+    }
+    let weeks_matter = parts.interval > 1 || parts.by_set_pos.is_some();
+    if parts.freq == Frequency::Weekly && weeks_matter && parts.week_start != week_start {
+        return Err(unsupported_import(
+            "WKST",
+            format!(
+                "weeks start on {}, but the periodicity's weeks start on {}",
+                weekday_code(parts.week_start),
+                weekday_code(week_start)
+            ),
+        ));
+    }
+
+    // ── DTSTART's day ───────────────────────────────────
+    let first_day = rule.start_date.or(dtstart.map(|start| match calendar {
+        Some(user) => user.logical_date(&start),
+        None => start.date_naive(),
+    }));
+    let start = first_day.map(|day| match calendar {
+        Some(user) => user.day_start_instant(day),
+        None => Utc.from_utc_datetime(&day.and_time(NaiveTime::MIN)),
+    });
+
+    // ── Repetition unit ─────────────────────────────────
+    let unit = if parts.freq == Frequency::Daily {
+        Frequency::Daily
+    } else if parts.by_set_pos == Some(1) {
+        parts.freq
+    } else if parts.has_parts_finer_than(parts.freq) {
+        Frequency::Daily
+    } else if let Some(start) = first_day {
+        // Repeats on DTSTART's weekday / day of the month / date
+        match parts.freq {
+            Frequency::Weekly => parts.by_day = vec![(None, start.weekday())],
+            Frequency::Monthly => parts.by_month_day = vec![start.day() as i8],
+            _ => {
+                parts.by_month = vec![start.month() as u8];
+                parts.by_month_day = vec![start.day() as i8];
+            }
+        }
+        Frequency::Daily
+    } else {
+        parts.freq
+    };
+
+    // Lists of every value add no constraint
+    if parts.by_day.len() == 7 && parts.by_day.iter().all(|(ordinal, _)| ordinal.is_none()) {
+        parts.by_day.clear();
+    }
+    if parts.by_month_day == (1..=31).collect::<Vec<i8>>() {
+        parts.by_month_day.clear();
+    }
+    if parts.by_month == (1..=12).collect::<Vec<u8>>() {
+        parts.by_month.clear();
+    }
+
+    // ── Build ───────────────────────────────────────────
+    let end = parts.until.map(|until| until + Duration::seconds(1));
+    let builder = rule_builder(&parts, unit, start)?;
+    let Some(count) = count else {
+        return Ok(with_timeframe(builder, start, end).build()?);
+    };
+
+    // COUNT: ends after the count-th occurrence
+    let Some(first) = start else {
+        return Err(unsupported_import("COUNT", "counting instances needs a DTSTART"));
+    };
+    if unit != Frequency::Daily {
+        return Err(unsupported_import(
+            "COUNT",
+            "occurrences are counted per day, not per week, month or year",
+        ));
+    }
+    let open = with_timeframe(builder.clone(), start, None).build()?;
+    let mut occurrences = match calendar {
+        Some(user) => open.occurrences_between_for(first, open_end(), user),
+        None => open.occurrences_between(first, open_end(), week_start),
+    };
+    let last = occurrences.nth(count as usize - 1);
+    Ok(with_timeframe(builder, start, last.map(|last| last + Duration::seconds(1))).build()?)
+}
+
+/// The builder for the constraints and INTERVAL of `parts`, without timeframe
+///
+/// `start` is the start of DTSTART's day, which INTERVAL counts from
+fn rule_builder(
+    parts: &RuleParts,
+    unit: Frequency,
+    start: Option<DateTime<Utc>>,
+) -> Result<PeriodicityBuilder, RRuleImportError> {
+    let builder = PeriodicityBuilder::new();
+    let mut builder = match unit {
+        Frequency::Daily => builder.daily(1),
+        Frequency::Weekly => builder.weekly(1),
+        Frequency::Monthly => builder.monthly(1),
+        Frequency::Yearly => builder.yearly(1),
+    };
+
+    if parts.by_day.iter().any(|(ordinal, _)| ordinal.is_some()) {
+        let patterns = parts.by_day
+            .iter()
+            .filter_map(|(ordinal, weekday)| {
+                let position = match (*ordinal)? {
+                    n if n > 0 => MonthWeekPosition::FromFirst(n as u8 - 1),
+                    n => MonthWeekPosition::FromLast(n.unsigned_abs() - 1),
+                };
+                Some(NthWeekdayOfMonth { weekday: *weekday, position })
+            })
+            .collect();
+        builder = builder.on_nth_weekdays(patterns);
+    } else if !parts.by_day.is_empty() {
+        builder = builder.on_weekdays(parts.by_day.iter().map(|(_, weekday)| *weekday).collect());
+    }
+
+    if parts.by_month_day.iter().any(|day| *day < 0) {
+        builder = builder.on_month_days_from_end(parts.by_month_day.iter().map(|day| day.unsigned_abs()).collect());
+    } else if !parts.by_month_day.is_empty() {
+        builder = builder.on_month_days(parts.by_month_day.iter().map(|day| *day as u8).collect());
+    }
+
+    if !parts.by_month.is_empty() {
+        let months = parts.by_month.iter().map(|month| Month::try_from(*month).unwrap()).collect();
+        builder = builder.in_months(months);
+    }
+
+    if parts.interval > 1 {
+        let n = parts.interval;
+        let too_large = || unsupported_import("INTERVAL", format!("at most {} {}", u8::MAX, parts.freq.unit_name()));
+        builder = match parts.freq {
+            Frequency::Daily => builder.every_n_days(n),
+            Frequency::Weekly => builder.every_n_weeks(u8::try_from(n).map_err(|_| too_large())?),
+            Frequency::Monthly => builder.every_n_months(u8::try_from(n).map_err(|_| too_large())?),
+            Frequency::Yearly => builder.every_n_years(u8::try_from(n).map_err(|_| too_large())?),
+        };
+        if let Some(start) = start {
+            builder = builder.with_reference_date(start);
+        }
+    }
+
+    Ok(builder)
+}
+
+/// Timeframe from the start of DTSTART's day and the exclusive end (UNTIL + 1s)
+fn with_timeframe(
+    builder: PeriodicityBuilder,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+) -> PeriodicityBuilder {
+    match (start, end) {
+        (Some(start), Some(end)) => builder.between(start, end),
+        (Some(start), None) => builder.starting_from(start),
+        (None, Some(end)) => builder.until(end),
+        (None, None) => builder,
+    }
+}
+
+/// Splits "FREQ=...;BYDAY=..." into its parts, and COUNT
+fn parse_rule_parts(rrule: &str) -> Result<(RuleParts, Option<u32>), RRuleImportError> {
+    let mut parts = RuleParts::new(Frequency::Daily);
+    let mut freq = None;
+    let mut count = None;
+    let mut seen: Vec<String> = Vec::new();
+
+    for part in rrule.split(';').map(str::trim).filter(|part| !part.is_empty()) {
+        let Some((name, value)) = part.split_once('=') else {
+            return Err(syntax(part, "expected NAME=VALUE"));
+        };
+        let name = name.trim().to_ascii_uppercase();
+        if seen.contains(&name) {
+            return Err(syntax(&name, "appears twice"));
+        }
+        seen.push(name.clone());
+
+        match name.as_str() {
+            "FREQ" => {
+                freq = Some(match value.to_ascii_uppercase().as_str() {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    "SECONDLY" | "MINUTELY" | "HOURLY" => {
+                        return Err(unsupported_import("FREQ", "tasks repeat over days at the finest"));
+                    }
+                    _ => return Err(syntax("FREQ", format!("unknown frequency '{}'", value))),
+                });
+            }
+            "INTERVAL" => parts.interval = parse_number(&name, value, 1, u16::MAX as i64)? as u16,
+            "COUNT" => count = Some(parse_number(&name, value, 1, u32::MAX as i64)? as u32),
+            "UNTIL" => parts.until = Some(parse_until(value)?),
+            "BYDAY" => {
+                parts.by_day = parse_list(&name, value, |item| {
+                    let code_at = item.len().checked_sub(2).filter(|at| item.is_char_boundary(*at))?;
+                    let weekday = parse_weekday(&item[code_at..])?;
+                    let ordinal = match &item[..code_at] {
+                        "" => None,
+                        number => Some(number.parse::<i8>().ok().filter(|n| *n != 0 && n.abs() <= 53)?),
+                    };
+                    Some((ordinal, weekday))
+                })?;
+            }
+            "BYMONTHDAY" => {
+                parts.by_month_day = parse_list(&name, value, |item| {
+                    item.parse::<i8>().ok().filter(|day| *day != 0 && day.abs() <= 31)
+                })?;
+            }
+            "BYMONTH" => {
+                parts.by_month = parse_list(&name, value, |item| {
+                    item.parse::<u8>().ok().filter(|month| (1..=12).contains(month))
+                })?;
+            }
+            "BYSETPOS" => {
+                let positions: Vec<i16> = parse_list(&name, value, |item| {
+                    item.parse::<i16>().ok().filter(|position| *position != 0 && position.abs() <= 366)
+                })?;
+                if positions != [1] {
+                    return Err(unsupported_import(
+                        "BYSETPOS",
+                        "only BYSETPOS=1 (the first day of each period) is supported",
+                    ));
+                }
+                parts.by_set_pos = Some(1);
+            }
+            "WKST" => {
+                parts.week_start = parse_weekday(value)
+                    .ok_or_else(|| syntax("WKST", format!("unknown weekday '{}'", value)))?;
+            }
+            "BYSECOND" | "BYMINUTE" | "BYHOUR" => {
+                return Err(unsupported_import(&name, "times of day are not part of a periodicity"));
+            }
+            "BYYEARDAY" => return Err(unsupported_import(&name, "days of the year")),
+            "BYWEEKNO" => return Err(unsupported_import(&name, "week numbers of the year")),
+            _ => return Err(unsupported_import(&name, "unknown rule part")),
+        }
+    }
+
+    parts.freq = freq.ok_or_else(|| syntax("FREQ", "missing"))?;
+    Ok((parts, count))
+}
+
+/// Comma-separated values; `parse` returns None for an invalid item
+fn parse_list<T>(name: &str, value: &str, parse: impl Fn(&str) -> Option<T>) -> Result<Vec<T>, RRuleImportError> {
+    value
+        .split(',')
+        .map(|item| {
+            let item = item.trim().to_ascii_uppercase();
+            parse(&item).ok_or_else(|| syntax(name, format!("invalid value '{}'", item)))
+        })
+        .collect()
+}
+
+fn parse_number(name: &str, value: &str, min: i64, max: i64) -> Result<i64, RRuleImportError> {
+    value
+        .trim()
+        .parse::<i64>()
+        .ok()
+        .filter(|n| (min..=max).contains(n))
+        .ok_or_else(|| syntax(name, format!("expected a number from {} to {}, got '{}'", min, max, value)))
+}
+
+/// Last instant UNTIL allows: a date includes its whole day
+fn parse_until(value: &str) -> Result<DateTime<Utc>, RRuleImportError> {
+    let until = parse_date_time("UNTIL", &[], value)?;
+    if value.trim().len() == 8 {
+        Ok(until + Duration::days(1) - Duration::seconds(1))
+    } else {
+        Ok(until)
+    }
+}
+
+/// An iCalendar DATE or DATE-TIME value, with its TZID / VALUE parameters
+///
+/// `part` names the property in errors; `params` are its raw parameters
+/// ("TZID=Europe/Paris"). Floating times are read as UTC.
+pub fn parse_date_time(part: &str, params: &[&str], value: &str) -> Result<DateTime<Utc>, RRuleImportError> {
+    let value = value.trim();
+    let invalid = || syntax(part, format!("invalid date or date-time '{}'", value));
+
+    let local = if value.len() == 8 {
+        NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| invalid())?.and_time(NaiveTime::MIN)
+    } else if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+        return Ok(Utc.from_utc_datetime(&naive));
+    } else {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| invalid())?
+    };
+
+    let tzid = params.iter().find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.eq_ignore_ascii_case("TZID").then(|| value.trim_matches('"'))
+    });
+    match tzid {
+        // Floating time: read as UTC
+        None => Ok(Utc.from_utc_datetime(&local)),
+        Some(name) => {
+            let tz = Tz::from_str(name).map_err(|_| unsupported_import(part, format!("unknown time zone '{}'", name)))?;
+            tz.from_local_datetime(&local)
+                .earliest()
+                .map(|date| date.with_timezone(&Utc))
+                .ok_or_else(|| syntax(part, format!("{} doesn't exist in {}", value, name)))
+        }
+    }
+}
+
+/// The date of a DATE, floating or TZID date-time value as written
+///
+/// None for a UTC value ("...Z"), whose day depends on the calendar it is read in.
+pub fn written_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    if value.ends_with('Z') {
+        return None;
+    }
+    NaiveDate::parse_from_str(value.get(..8)?, "%Y%m%d").ok()
+}
+
+// ========================================================================
+// RULE PARTS
+// ========================================================================
+
+/// RRULE FREQ values this model maps to, coarsest last
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn of_unit(unit: RepetitionUnit) -> Option<Self> {
+        match unit {
+            RepetitionUnit::Day => Some(Frequency::Daily),
+            RepetitionUnit::Week => Some(Frequency::Weekly),
+            RepetitionUnit::Month => Some(Frequency::Monthly),
+            RepetitionUnit::Year => Some(Frequency::Yearly),
+            RepetitionUnit::None => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+
+    fn unit_name(self) -> &'static str {
+        match self {
+            Frequency::Daily => "days",
+            Frequency::Weekly => "weeks",
+            Frequency::Monthly => "months",
+            Frequency::Yearly => "years",
+        }
+    }
+}
+
+const ALL_WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun,
+];
+
+/// The parts of an RRULE this model uses
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RuleParts {
+    freq: Frequency,
+    interval: u16,
+    /// (ordinal, weekday): 1 = first, -1 = last
+    by_day: Vec<(Option<i8>, Weekday)>,
+    /// 1 to 31, or -1 (last day) to -31
+    by_month_day: Vec<i8>,
+    /// 1 to 12
+    by_month: Vec<u8>,
+    by_set_pos: Option<i8>,
+    week_start: Weekday,
+    until: Option<DateTime<Utc>>,
+}
+
+impl RuleParts {
+    fn new(freq: Frequency) -> Self {
+        Self {
+            freq,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: None,
+            week_start: Weekday::Mon,
+            until: None,
+        }
+    }
+}
+
+impl RuleParts {
+    /// BY parts that pick days within a `freq` period
+    fn has_parts_finer_than(&self, freq: Frequency) -> bool {
+        match freq {
+            Frequency::Daily => false,
+            Frequency::Weekly => !self.by_day.is_empty(),
+            Frequency::Monthly => !self.by_day.is_empty() || !self.by_month_day.is_empty(),
+            Frequency::Yearly => !self.by_day.is_empty() || !self.by_month_day.is_empty() || !self.by_month.is_empty(),
+        }
+    }
+}
+
+impl fmt::Display for RuleParts {
+    /// FREQ first, then INTERVAL, BYMONTH, BYMONTHDAY, BYDAY, BYSETPOS, WKST, UNTIL
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn list<T>(values: &[T], format: impl Fn(&T) -> String) -> String {
+            values.iter().map(format).collect::<Vec<_>>().join(",")
+        }
+
+        write!(f, "FREQ={}", self.freq.name())?;
+        if self.interval > 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_month.is_empty() {
+            write!(f, ";BYMONTH={}", list(&self.by_month, u8::to_string))?;
+        }
+        if !self.by_month_day.is_empty() {
+            write!(f, ";BYMONTHDAY={}", list(&self.by_month_day, i8::to_string))?;
+        }
+        if !self.by_day.is_empty() {
+            let by_day = list(&self.by_day, |(ordinal, weekday)| match ordinal {
+                Some(n) => format!("{}{}", n, weekday_code(*weekday)),
+                None => weekday_code(*weekday).to_string(),
+            });
+            write!(f, ";BYDAY={}", by_day)?;
+        }
+        if let Some(position) = self.by_set_pos {
+            write!(f, ";BYSETPOS={}", position)?;
+        }
+        if self.week_start != Weekday::Mon {
+            write!(f, ";WKST={}", weekday_code(self.week_start))?;
+        }
+        if let Some(until) = &self.until {
+            write!(f, ";UNTIL={}", format_utc(until))?;
+        }
+        Ok(())
+    }
+}
+
+/// Intervals found in the constraints: (part, unit counted, N)
+type Interval = (&'static str, Frequency, u16);
+
+/// Fills the BY parts from the constraints, and lists EveryN* intervals (N > 1)
+fn collect_constraints(
+    constraints: &PeriodicityConstraints,
+    week_start: Weekday,
+    rule: &mut RuleParts,
+    intervals: &mut Vec<Interval>,
+    errors: &mut Vec<UnsupportedRulePart>,
+) {
+    match &constraints.day_constraint {
+        None | Some(DayConstraint::EveryDay) => {}
+        Some(DayConstraint::EveryNDays(n)) => intervals.push(("constraints.day", Frequency::Daily, *n)),
+        Some(DayConstraint::SpecificDaysWeek(weekdays)) => {
+            rule.by_day = weekdays.iter().map(|day| (None, *day)).collect();
+        }
+        Some(DayConstraint::SpecificDaysMonthFromFirst(days)) => {
+            rule.by_month_day = days.iter().map(|day| *day as i8 + 1).collect();
+        }
+        Some(DayConstraint::SpecificDaysMonthFromLast(days)) => {
+            rule.by_month_day = days.iter().map(|day| -(*day as i8 + 1)).collect();
+        }
+        Some(DayConstraint::SpecificNthWeekdaysMonth(patterns)) => {
+            rule.by_day = patterns
+                .iter()
+                .map(|pattern| {
+                    let ordinal = match pattern.position {
+                        MonthWeekPosition::FromFirst(n) => n as i8 + 1,
+                        MonthWeekPosition::FromLast(n) => -(n as i8 + 1),
+                    };
+                    (Some(ordinal), pattern.weekday)
+                })
+                .collect();
+        }
+    }
+
+    match &constraints.week_constraint {
+        None | Some(WeekConstraint::EveryWeek) => {}
+        Some(WeekConstraint::EveryNWeeks(n)) => intervals.push(("constraints.week", Frequency::Weekly, *n as u16)),
+        Some(WeekConstraint::SpecificWeeksOfMonthFromFirst(_))
+        | Some(WeekConstraint::SpecificWeeksOfMonthFromLast(_)) => errors.push(unsupported(
+            "constraints.week",
+            format!(
+                "weeks of the month are counted from the first {} of the month; RRULE has no week-of-month rule",
+                weekday_code(week_start)
+            ),
+        )),
+        Some(WeekConstraint::SpecificWeeksOfYear(_)) | Some(WeekConstraint::SpecificIsoWeeks(_)) => errors.push(unsupported(
+            "constraints.week",
+            "BYWEEKNO only applies to yearly rules, which can't express the rest of the periodicity",
+        )),
+        Some(WeekConstraint::EvenIsoWeeks) | Some(WeekConstraint::OddIsoWeeks) => errors.push(unsupported(
+            "constraints.week",
+            "RRULE has no even or odd week rule",
+        )),
+    }
+
+    match &constraints.month_constraint {
+        None | Some(MonthConstraint::EveryMonth) => {}
+        Some(MonthConstraint::EveryNMonths(n)) => intervals.push(("constraints.month", Frequency::Monthly, *n as u16)),
+        Some(MonthConstraint::SpecificMonths(months)) => {
+            rule.by_month = months.iter().map(|month| month.number_from_month() as u8).collect();
+        }
+    }
+
+    match &constraints.year_constraint {
+        None | Some(YearConstraint::EveryYear) => {}
+        Some(YearConstraint::EveryNYears(n)) => intervals.push(("constraints.year", Frequency::Yearly, *n as u16)),
+        Some(YearConstraint::SpecificYears(_)) => {
+            errors.push(unsupported("constraints.year", "RRULE can't select specific years"));
+        }
+    }
+
+    // EveryN(1) is every unit
+    intervals.retain(|(_, _, n)| *n > 1);
+}
+
+// ── FORMATTING HELPERS ──────────────────────────────────────
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    ALL_WEEKDAYS.iter().copied().find(|weekday| weekday_code(*weekday).eq_ignore_ascii_case(code))
+}
+
+/// iCalendar UTC date-time ("20260105T000000Z")
+fn format_utc(date: &DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Start written by `PeriodicityBuilder::until` (no real start)
+pub(super) fn open_start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap()
+}
+
+/// End written by `PeriodicityBuilder::starting_from` (no real end)
+pub(super) fn open_end() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2200, 12, 31, 23, 59, 59).unwrap()
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::user::{PasswordHash, Timezone};
+    use proptest::prelude::*;
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn rrule(periodicity: &Periodicity) -> String {
+        periodicity.to_rrule(Weekday::Mon).unwrap().rrule
+    }
+
+    fn unsupported_parts(periodicity: &Periodicity, week_start: Weekday) -> Vec<String> {
+        let error = periodicity.to_rrule(week_start).unwrap_err();
+        error.unsupported.into_iter().map(|part| part.part).collect()
+    }
+
+    #[test]
+    fn test_daily_filters() {
+        assert_eq!(rrule(&Periodicity::daily().unwrap()), "FREQ=DAILY");
+        assert_eq!(
+            rrule(&Periodicity::on_weekdays(vec![Weekday::Mon, Weekday::Fri]).unwrap()),
+            "FREQ=DAILY;BYDAY=MO,FR"
+        );
+
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![13, 24])
+            .in_months(vec![Month::January, Month::February])
+            .build()
+            .unwrap();
+        assert_eq!(rrule(&periodicity), "FREQ=DAILY;BYMONTH=1,2;BYMONTHDAY=13,24");
+
+        let last_days = PeriodicityBuilder::new().daily(1).on_month_days_from_end(vec![1, 2]).build().unwrap();
+        assert_eq!(rrule(&last_days), "FREQ=DAILY;BYMONTHDAY=-1,-2");
+    }
+
+    #[test]
+    fn test_nth_weekdays_use_monthly_or_yearly() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::first(Weekday::Mon), NthWeekdayOfMonth::last(Weekday::Fri)])
+            .build()
+            .unwrap();
+        assert_eq!(rrule(&periodicity), "FREQ=MONTHLY;BYDAY=1MO,-1FR");
+
+        let every_other_year = PeriodicityBuilder::new()
+            .daily(1)
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::second(Weekday::Sun)])
+            .in_months(vec![Month::May])
+            .every_n_years(2)
+            .with_reference_date(utc(2026, 1, 1))
+            .build()
+            .unwrap();
+        let rule = every_other_year.to_rrule(Weekday::Mon).unwrap();
+        assert_eq!(rule.rrule, "FREQ=YEARLY;INTERVAL=2;BYMONTH=5;BYDAY=2SU");
+        assert_eq!(rule.dtstart, Some(utc(2026, 5, 10)));
+    }
+
+    #[test]
+    fn test_intervals_count_from_dtstart() {
+        // Every other Tuesday
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Tue])
+            .every_n_weeks(2)
+            .with_reference_date(utc(2026, 1, 5))
+            .build()
+            .unwrap();
+        let rule = periodicity.to_rrule(Weekday::Sun).unwrap();
+        assert_eq!(rule.rrule, "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;WKST=SU");
+        // DTSTART is the first Tuesday, not the Monday counted from
+        assert_eq!(rule.to_string(), "DTSTART:20260106T000000Z\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;WKST=SU");
+
+        let every_3_days = PeriodicityBuilder::new().daily(1).every_n_days(3).build().unwrap();
+        assert_eq!(rrule(&every_3_days), "FREQ=DAILY;INTERVAL=3");
+
+        // Every day of every other week
+        let weeks = PeriodicityBuilder::new().daily(1).every_n_weeks(2).build().unwrap();
+        assert_eq!(rrule(&weeks), "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TU,WE,TH,FR,SA,SU");
+    }
+
+    #[test]
+    fn test_coarser_units_keep_one_instance_per_period() {
+        assert_eq!(rrule(&Periodicity::weekly().unwrap()), "FREQ=WEEKLY");
+
+        let periodicity = PeriodicityBuilder::new()
+            .weekly(2)
+            .on_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .every_n_weeks(2)
+            .build()
+            .unwrap();
+        assert_eq!(rrule(&periodicity), "FREQ=WEEKLY;INTERVAL=2;BYDAY=SA,SU;BYSETPOS=1");
+
+        let quarterly = PeriodicityBuilder::new()
+            .monthly(1)
+            .on_month_days(vec![1, 15])
+            .every_n_months(3)
+            .build()
+            .unwrap();
+        assert_eq!(rrule(&quarterly), "FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=1,15;BYSETPOS=1");
+    }
+
+    #[test]
+    fn test_timeframe_gives_dtstart_and_until() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon])
+            .between(utc(2026, 1, 1), utc(2026, 2, 1))
+            .build()
+            .unwrap();
+        assert_eq!(
+            periodicity.to_rrule(Weekday::Mon).unwrap().to_string(),
+            "DTSTART:20260105T000000Z\nRRULE:FREQ=DAILY;BYDAY=MO;UNTIL=20260131T235959Z"
+        );
+
+        // From an "off" week of the interval: DTSTART moves to the next "on" week
+        let fortnightly = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon])
+            .every_n_weeks(2)
+            .between(utc(2026, 1, 6), utc(2026, 3, 1))
+            .build()
+            .unwrap();
+        let rule = fortnightly.to_rrule(Weekday::Mon).unwrap();
+        assert_eq!(rule.dtstart, Some(utc(2026, 1, 19)));
+        let imported = Periodicity::from_rrule(&rule, Weekday::Mon).unwrap();
+        assert_eq!(days(&imported, utc(2026, 1, 1), utc(2026, 3, 1)), days(&fortnightly, utc(2026, 1, 1), utc(2026, 3, 1)));
+
+        // Open ends are left out
+        let open = PeriodicityBuilder::new().daily(1).starting_from(utc(2026, 3, 1)).build().unwrap();
+        let rule = open.to_rrule(Weekday::Mon).unwrap();
+        assert_eq!((rule.dtstart, rule.rrule.as_str()), (Some(utc(2026, 3, 1)), "FREQ=DAILY"));
+
+        let ending = PeriodicityBuilder::new().daily(1).until(utc(2026, 3, 1)).build().unwrap();
+        let rule = ending.to_rrule(Weekday::Mon).unwrap();
+        assert_eq!((rule.dtstart, rule.rrule.as_str()), (None, "FREQ=DAILY;UNTIL=20260228T235959Z"));
+    }
+
+    #[test]
+    fn test_unsupported_parts_are_all_listed() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon])
+            .on_weeks_of_month(vec![1])
+            .in_years(vec![2026, 2027])
+            .build()
+            .unwrap();
+        let error = periodicity.to_rrule(Weekday::Sun).unwrap_err();
+        assert_eq!(error.unsupported.len(), 2);
+        assert_eq!(error.unsupported[0].part, "constraints.week");
+        assert!(error.unsupported[0].reason.contains("first SU"));
+        assert_eq!(error.unsupported[1].part, "constraints.year");
+        assert!(error.to_string().starts_with("Periodicity can't be expressed as an RRULE: constraints.week ("));
+
+        let two_intervals = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_days(3)
+            .every_n_months(2)
+            .build()
+            .unwrap();
+        assert_eq!(unsupported_parts(&two_intervals, Weekday::Mon), vec!["constraints.month"]);
+
+        let nth_every_3_days = PeriodicityBuilder::new()
+            .daily(1)
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::first(Weekday::Mon)])
+            .every_n_weeks(3)
+            .build()
+            .unwrap();
+        assert_eq!(unsupported_parts(&nth_every_3_days, Weekday::Mon), vec!["constraints.week"]);
+
+        let iso_weeks = PeriodicityBuilder::new().weekly(1).on_iso_weeks(vec![1, 53]).build().unwrap();
+        assert_eq!(unsupported_parts(&iso_weeks, Weekday::Mon), vec!["constraints.week"]);
+        let odd_weeks = PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Fri]).in_odd_iso_weeks().build().unwrap();
+        assert_eq!(unsupported_parts(&odd_weeks, Weekday::Mon), vec!["constraints.week"]);
+
+        let weekly_month_days = PeriodicityBuilder::new().weekly(1).on_month_days(vec![1]).build().unwrap();
+        assert_eq!(unsupported_parts(&weekly_month_days, Weekday::Mon), vec!["constraints.day"]);
+
+        let unique = Periodicity::unique(utc(2026, 12, 25)).unwrap();
+        assert_eq!(unsupported_parts(&unique, Weekday::Mon), vec!["special_pattern"]);
+
+        let christmas = NaiveDate::from_ymd_opt(2026, 12, 25).unwrap();
+        let except_christmas = PeriodicityBuilder::new().daily(1).except(vec![christmas]).build().unwrap();
+        assert_eq!(unsupported_parts(&except_christmas, Weekday::Mon), vec!["exceptions"]);
+
+        let payday = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![25])
+            .with_roll_policy(RollPolicy::PreviousBusinessDay)
+            .build()
+            .unwrap();
+        assert_eq!(unsupported_parts(&payday, Weekday::Mon), vec!["roll_policy"]);
+
+        let watering = PeriodicityBuilder::new()
+            .daily(1)
+            .after_completion(4, RepetitionUnit::Day)
+            .build()
+            .unwrap();
+        assert_eq!(unsupported_parts(&watering, Weekday::Mon), vec!["after_completion"]);
+
+        let payroll = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1, 15])
+            .or()
+            .on_nth_weekdays(vec![NthWeekdayOfMonth::last(Weekday::Fri)])
+            .build()
+            .unwrap();
+        assert_eq!(unsupported_parts(&payroll, Weekday::Mon), vec!["alternatives"]);
+    }
+
+    // ── Import ───────────────────────────────────────────────
+
+    fn import(text: &str) -> Result<Periodicity, RRuleImportError> {
+        Periodicity::from_rrule(&RecurrenceRule::parse(text)?, Weekday::Mon)
+    }
+
+    fn days(periodicity: &Periodicity, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        periodicity.occurrences_between(start, end, Weekday::Mon).collect()
+    }
+
+    #[test]
+    fn test_parse_content_lines() {
+        let rule = RecurrenceRule::parse("DTSTART;TZID=Europe/Paris:20260105T090000\nRRULE:FREQ=DAILY").unwrap();
+        assert_eq!(rule.dtstart, Some(Utc.with_ymd_and_hms(2026, 1, 5, 8, 0, 0).unwrap()));
+        assert_eq!(rule.start_date, NaiveDate::from_ymd_opt(2026, 1, 5));
+        assert_eq!(rule.rrule, "FREQ=DAILY");
+
+        let rule = RecurrenceRule::parse("DTSTART;VALUE=DATE:20260105\r\nRRULE:FREQ=WEEKLY\r\n").unwrap();
+        assert_eq!(rule.dtstart, Some(utc(2026, 1, 5)));
+        assert_eq!(rule.start_date, NaiveDate::from_ymd_opt(2026, 1, 5));
+
+        let rule = RecurrenceRule::parse("DTSTART:20260105T230000Z\nRRULE:FREQ=DAILY").unwrap();
+        assert_eq!(rule.start_date, None);
+
+        let bare = RecurrenceRule::parse("FREQ=MONTHLY;BYMONTHDAY=1").unwrap();
+        assert_eq!((bare.dtstart, bare.rrule.as_str()), (None, "FREQ=MONTHLY;BYMONTHDAY=1"));
+
+        assert!(matches!(
+            RecurrenceRule::parse("DTSTART:20260105T000000Z"),
+            Err(RRuleImportError::Syntax { part, .. }) if part == "RRULE"
+        ));
+        assert!(matches!(
+            RecurrenceRule::parse("RRULE:FREQ=DAILY\nEXDATE:20260106T000000Z"),
+            Err(RRuleImportError::Unsupported { part, .. }) if part == "EXDATE"
+        ));
+        assert!(matches!(
+            RecurrenceRule::parse("DTSTART;TZID=Mars/Olympus:20260105T090000\nRRULE:FREQ=DAILY"),
+            Err(RRuleImportError::Unsupported { part, .. }) if part == "DTSTART"
+        ));
+    }
+
+    #[test]
+    fn test_import_calendar_rules() {
+        let weekdays = import("DTSTART:20260105T000000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR;UNTIL=20260111T000000Z").unwrap();
+        assert_eq!(weekdays.constraints.day_constraint, Some(DayConstraint::SpecificDaysWeek(vec![Weekday::Mon, Weekday::Wed, Weekday::Fri])));
+        assert_eq!(days(&weekdays, utc(2026, 1, 1), utc(2026, 2, 1)), vec![utc(2026, 1, 5), utc(2026, 1, 7), utc(2026, 1, 9)]);
+
+        // A bare FREQ repeats on DTSTART's day
+        let monthly = import("DTSTART:20260115T000000Z\nRRULE:FREQ=MONTHLY;COUNT=3").unwrap();
+        assert_eq!(days(&monthly, utc(2026, 1, 1), utc(2027, 1, 1)), vec![utc(2026, 1, 15), utc(2026, 2, 15), utc(2026, 3, 15)]);
+        assert_eq!(monthly.timeframe, Some((utc(2026, 1, 15), utc(2026, 3, 15) + Duration::seconds(1))));
+
+        let yearly = import("DTSTART;VALUE=DATE:20240229\nRRULE:FREQ=YEARLY;UNTIL=20320229").unwrap();
+        assert_eq!(days(&yearly, utc(2024, 1, 1), utc(2033, 1, 1)), vec![utc(2024, 2, 29), utc(2028, 2, 29), utc(2032, 2, 29)]);
+
+        let nth = import("RRULE:FREQ=MONTHLY;INTERVAL=3;BYDAY=1MO,-1FR").unwrap();
+        assert_eq!(
+            nth.constraints.day_constraint,
+            Some(DayConstraint::SpecificNthWeekdaysMonth(vec![NthWeekdayOfMonth::first(Weekday::Mon), NthWeekdayOfMonth::last(Weekday::Fri)]))
+        );
+        assert_eq!(nth.constraints.month_constraint, Some(MonthConstraint::EveryNMonths(3)));
+
+        // Once a week, on any day
+        let weekly = import("RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR,SA,SU;BYSETPOS=1").unwrap();
+        assert_eq!((weekly.rep_unit, &weekly.constraints.day_constraint), (RepetitionUnit::Week, &None));
+    }
+
+    #[test]
+    fn test_dtstart_day_is_the_first_occurrence() {
+        // DTSTART's time of day doesn't push its day out of the timeframe
+        let daily = import("DTSTART:20260105T080000Z\nRRULE:FREQ=DAILY;COUNT=3").unwrap();
+        assert_eq!(days(&daily, utc(2026, 1, 1), utc(2026, 2, 1)), vec![utc(2026, 1, 5), utc(2026, 1, 6), utc(2026, 1, 7)]);
+        assert_eq!(daily.timeframe, Some((utc(2026, 1, 5), utc(2026, 1, 7) + Duration::seconds(1))));
+
+        // 08:00 in Tokyo is the day before in UTC: the local date is DTSTART's day
+        let local = |rrule: &str| import(&format!("DTSTART;TZID=Asia/Tokyo:20260105T080000\nRRULE:{}", rrule)).unwrap();
+        assert_eq!(local("FREQ=WEEKLY").constraints.day_constraint, Some(DayConstraint::SpecificDaysWeek(vec![Weekday::Mon])));
+        assert_eq!(days(&local("FREQ=MONTHLY"), utc(2026, 1, 1), utc(2026, 3, 1)), vec![utc(2026, 1, 5), utc(2026, 2, 5)]);
+        let yearly = local("FREQ=YEARLY");
+        assert_eq!(yearly.constraints.month_constraint, Some(MonthConstraint::SpecificMonths(vec![Month::January])));
+        assert_eq!(days(&yearly, utc(2026, 1, 1), utc(2028, 1, 1)), vec![utc(2026, 1, 5), utc(2027, 1, 5)]);
+    }
+
+    #[test]
+    fn test_import_on_the_users_calendar() {
+        let user = |timezone: &str| {
+            User::new(
+                "user".to_string(),
+                "user@example.com".to_string(),
+                PasswordHash::locked(),
+                Timezone::new(timezone.to_string()).unwrap(),
+            )
+            .unwrap()
+        };
+        let local_days = |periodicity: &Periodicity, user: &User| -> Vec<NaiveDate> {
+            periodicity.occurrences_between_for(utc(2026, 1, 1), utc(2026, 2, 1), user).map(|day| user.logical_date(&day)).collect()
+        };
+        let date = |day: u32| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+
+        // Tuesday Jan 6 starts before 08:00 in Paris
+        let paris = user("Europe/Paris");
+        let rule = RecurrenceRule::parse("DTSTART;TZID=Europe/Paris:20260106T080000\nRRULE:FREQ=DAILY;BYDAY=TU,TH;COUNT=3").unwrap();
+        let periodicity = Periodicity::from_rrule_for(&rule, &paris).unwrap();
+        assert_eq!(local_days(&periodicity, &paris), vec![date(6), date(8), date(13)]);
+
+        // A UTC DTSTART falls on the user's logical day
+        let tokyo = user("Asia/Tokyo");
+        let rule = RecurrenceRule::parse("DTSTART:20260105T230000Z\nRRULE:FREQ=WEEKLY;COUNT=2").unwrap();
+        let periodicity = Periodicity::from_rrule_for(&rule, &tokyo).unwrap();
+        assert_eq!(periodicity.constraints.day_constraint, Some(DayConstraint::SpecificDaysWeek(vec![Weekday::Tue])));
+        assert_eq!(local_days(&periodicity, &tokyo), vec![date(6), date(13)]);
+    }
+
+    #[test]
+    fn test_import_errors_name_the_parts() {
+        let error = |text: &str| import(text).unwrap_err();
+
+        assert_eq!(error("RRULE:INTERVAL=2"), syntax("FREQ", "missing"));
+        assert!(matches!(error("RRULE:FREQ=DAILY;INTERVAL=0"), RRuleImportError::Syntax { part, .. } if part == "INTERVAL"));
+        assert!(matches!(error("RRULE:FREQ=DAILY;BYDAY=XX"), RRuleImportError::Syntax { part, .. } if part == "BYDAY"));
+        assert!(matches!(error("RRULE:FREQ=DAILY;FREQ=WEEKLY"), RRuleImportError::Syntax { part, .. } if part == "FREQ"));
+
+        assert!(matches!(
+            error("DTSTART:20260105T000000Z\nRRULE:FREQ=DAILY;COUNT=3;UNTIL=20260110T000000Z"),
+            RRuleImportError::Contradictory { parts, .. } if parts == ["COUNT", "UNTIL"]
+        ));
+        assert!(matches!(
+            error("DTSTART:20260105T000000Z\nRRULE:FREQ=DAILY;UNTIL=20251231"),
+            RRuleImportError::Contradictory { parts, .. } if parts == ["DTSTART", "UNTIL"]
+        ));
+        assert!(matches!(error("RRULE:FREQ=WEEKLY;BYDAY=1MO"), RRuleImportError::Contradictory { parts, .. } if parts == ["FREQ", "BYDAY"]));
+
+        assert!(matches!(error("RRULE:FREQ=DAILY;BYHOUR=9"), RRuleImportError::Unsupported { part, .. } if part == "BYHOUR"));
+        assert!(matches!(error("RRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"), RRuleImportError::Unsupported { part, .. } if part == "BYSETPOS"));
+        assert!(matches!(error("RRULE:FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13"), RRuleImportError::Unsupported { part, .. } if part == "BYMONTHDAY"));
+        assert!(matches!(error("RRULE:FREQ=YEARLY;BYDAY=20MO"), RRuleImportError::Unsupported { part, .. } if part == "BYDAY"));
+        assert!(matches!(error("RRULE:FREQ=WEEKLY;COUNT=4"), RRuleImportError::Unsupported { part, .. } if part == "COUNT"));
+        assert!(matches!(
+            error("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;WKST=SU"),
+            RRuleImportError::Unsupported { part, reason } if part == "WKST" && reason.contains("start on MO")
+        ));
+
+        // Parsed, but rejected by the validator
+        assert!(matches!(error("RRULE:FREQ=WEEKLY;INTERVAL=60;BYDAY=TU"), RRuleImportError::Invalid(_)));
+    }
+
+    // ── Round trip ───────────────────────────────────────────
+
+    /// Raw choices for a periodicity: unit, day constraint, months, interval,
+    /// timeframe (days after 2026-01-01, length in days), reference date
+    type RawPeriodicity = (
+        (u8, u8, u8, Vec<u8>, Vec<(bool, u8, u8)>),
+        (Option<Vec<u8>>, (u8, u8), Option<u16>, Option<u16>, Option<u16>),
+    );
+
+    fn build_raw(((unit, day_kind, weekday_mask, month_days, nth), (months, (level, n), start, length, reference)): RawPeriodicity) -> Option<Periodicity> {
+        let origin = utc(2026, 1, 1);
+        let builder = PeriodicityBuilder::new();
+        let mut builder = match unit {
+            0 => builder.daily(1),
+            1 => builder.weekly(1),
+            2 => builder.monthly(1),
+            _ => builder.yearly(1),
+        };
+        builder = match day_kind {
+            1 => builder.on_weekdays(ALL_WEEKDAYS.iter().enumerate().filter(|(i, _)| weekday_mask & (1 << i) != 0).map(|(_, day)| *day).collect()),
+            2 => builder.on_month_days(month_days),
+            3 => builder.on_month_days_from_end(month_days),
+            4 => builder.on_nth_weekdays(
+                nth.iter()
+                    .map(|(from_last, weekday, n)| NthWeekdayOfMonth {
+                        weekday: ALL_WEEKDAYS[*weekday as usize],
+                        position: if *from_last { MonthWeekPosition::FromLast(*n) } else { MonthWeekPosition::FromFirst(*n) },
+                    })
+                    .collect(),
+            ),
+            _ => builder,
+        };
+        if let Some(months) = months {
+            builder = builder.in_months(months.iter().map(|month| Month::try_from(*month).unwrap()).collect());
+        }
+        builder = match level {
+            1 => builder.every_n_days(n as u16),
+            2 => builder.every_n_weeks(n),
+            3 => builder.every_n_months(n),
+            4 => builder.every_n_years(n),
+            _ => builder,
+        };
+        let start = start.map(|days| origin + Duration::days(days as i64));
+        let end = length.map(|days| start.unwrap_or(origin) + Duration::days(days as i64 + 1));
+        builder = with_timeframe(builder, start, end);
+        if let Some(days) = reference {
+            builder = builder.with_reference_date(origin + Duration::days(days as i64));
+        }
+        builder.build().ok()
+    }
+
+    fn arb_periodicity() -> impl Strategy<Value = Option<Periodicity>> {
+        use proptest::collection::{btree_set, vec};
+        let days = (
+            0..4u8,
+            0..5u8,
+            1..128u8,
+            btree_set(1..=31u8, 1..4).prop_map(|set| set.into_iter().collect()),
+            vec((any::<bool>(), 0..7u8, 0..4u8), 1..3),
+        );
+        let rest = (
+            proptest::option::of(btree_set(1..=12u8, 1..4).prop_map(|set| set.into_iter().collect())),
+            (0..5u8, 2..5u8),
+            proptest::option::of(0..400u16),
+            proptest::option::of(0..400u16),
+            proptest::option::of(0..400u16),
+        );
+        (days, rest).prop_map(build_raw)
+    }
+
+    proptest! {
+        /// Exporting what was imported gives the same rule, and the same days
+        #[test]
+        fn prop_import_inverts_export(periodicity in arb_periodicity(), sunday in any::<bool>()) {
+            let week_start = if sunday { Weekday::Sun } else { Weekday::Mon };
+            // Rejected by the validator, or unsupported by RRULE: covered above
+            let Some(periodicity) = periodicity else { return Ok(()) };
+            let Ok(rule) = periodicity.to_rrule(week_start) else { return Ok(()) };
+
+            let imported = Periodicity::from_rrule(&rule, week_start);
+            prop_assert!(imported.is_ok(), "{} gave {:?}", rule, imported);
+            let imported = imported.unwrap();
+            prop_assert_eq!(imported.to_rrule(week_start).unwrap(), rule.clone());
+
+            let from = rule.dtstart.unwrap_or(utc(2026, 1, 1));
+            let to = from + Duration::days(800);
+            let expected: Vec<_> = periodicity.occurrences_between(from, to, week_start).collect();
+            let actual: Vec<_> = imported.occurrences_between(from, to, week_start).collect();
+            prop_assert_eq!(actual, expected, "{}", rule);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use crate::domain::entities::task::periodicity::*;
+use crate::domain::entities::task::periodicity_json::*;
+
+// ========================================================================
+// SERDE SUPPORT (feature "serde")
+// Serialize / Deserialize through the versioned JSON representation
+// ========================================================================
+//
+// Every impl goes through the same encoders/decoders as `Periodicity::to_json`
+// and `Periodicity::from_json`, so serde output is exactly the documented JSON
+// format (a `Periodicity` carries its "version" field; component types use
+// the shape they have inside that document).
+//
+// Deserializing a `Periodicity` runs `validate_periodicity`: invalid input
+// is rejected with the `PeriodicityJsonError` message, never turned into an
+// invalid value. Component types are only checked for shape, since most
+// rules need the whole Periodicity.
+//
+// Decoding buffers a `serde_json::Value`, so the format must be
+// self-describing (JSON, YAML, TOML, MessagePack, ...).
+
+macro_rules! serde_via_json {
+    ($type:ty, $to_json:expr, $from_json:expr) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let to_json: fn(&$type) -> Value = $to_json;
+                to_json(self).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let from_json: fn(&Value, &str) -> Result<$type, PeriodicityJsonError> = $from_json;
+                let value = Value::deserialize(deserializer)?;
+                from_json(&value, "$").map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+serde_via_json!(Periodicity, Periodicity::to_json, |value, _| Periodicity::from_json(value));
+serde_via_json!(RepetitionUnit, |unit| Value::from(rep_unit_name(*unit)), rep_unit_from_json);
+serde_via_json!(PeriodicityConstraints, constraints_to_json, constraints_from_json);
+serde_via_json!(DayConstraint, day_constraint_to_json, day_constraint_from_json);
+serde_via_json!(NthWeekdayOfMonth, nth_weekday_to_json, nth_weekday_from_json);
+serde_via_json!(MonthWeekPosition, month_week_position_to_json, month_week_position_from_json);
+serde_via_json!(WeekConstraint, week_constraint_to_json, week_constraint_from_json);
+serde_via_json!(MonthConstraint, month_constraint_to_json, month_constraint_from_json);
+serde_via_json!(YearConstraint, year_constraint_to_json, year_constraint_from_json);
+serde_via_json!(SpecialPattern, special_pattern_to_json, special_pattern_from_json);
+serde_via_json!(CustomDates, custom_dates_to_json, custom_dates_from_json);
+serde_via_json!(UniqueDate, unique_date_to_json, unique_date_from_json);
+serde_via_json!(OccurrenceTimingSettings, occurrence_settings_to_json, occurrence_settings_from_json);
+serde_via_json!(RepTimingSettings, rep_timing_to_json, rep_timing_from_json);
+serde_via_json!(PeriodicityExceptions, exceptions_to_json, |value, path| match value {
+    // No exceptions, as in the document
+    Value::Null => Ok(PeriodicityExceptions::default()),
+    _ => exceptions_from_json(value, path),
+});
+serde_via_json!(HolidayCalendar, holiday_calendar_to_json, holiday_calendar_from_json);
+serde_via_json!(RollPolicy, |policy| roll_policy_to_json(*policy), roll_policy_from_json);
+serde_via_json!(AfterCompletion, after_completion_to_json, after_completion_from_json);
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Month, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+    use serde_json::json;
+    use crate::domain::builders::PeriodicityBuilder;
+
+    #[test]
+    fn test_serde_matches_versioned_json() {
+        let periodicity = PeriodicityBuilder::new()
+            .weekly(1)
+            .on_weekdays(vec![Weekday::Mon, Weekday::Thu])
+            .with_reference_date(Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+
+        let value = serde_json::to_value(&periodicity).unwrap();
+        assert_eq!(value, periodicity.to_json());
+        assert_eq!(value["version"], json!(PERIODICITY_JSON_VERSION));
+
+        let text = serde_json::to_string(&periodicity).unwrap();
+        assert_eq!(serde_json::from_str::<Periodicity>(&text).unwrap(), periodicity);
+    }
+
+    #[test]
+    fn test_deserialize_rejects_invalid_periodicity() {
+        // Well-formed, but an every-N-days constraint with N = 0 is invalid
+        let mut value = Periodicity::daily().unwrap().to_json();
+        value["constraints"]["day"] = json!({ "type": "every_n_days", "value": 0 });
+
+        let error = serde_json::from_value::<Periodicity>(value).unwrap_err();
+        assert!(error.to_string().starts_with("Invalid periodicity:"), "{}", error);
+    }
+
+    #[test]
+    fn test_deserialize_reports_path_and_version() {
+        let mut value = Periodicity::daily().unwrap().to_json();
+        value["rep_unit"] = json!("fortnight");
+        let error = serde_json::from_value::<Periodicity>(value).unwrap_err();
+        assert!(error.to_string().contains("'rep_unit'"), "{}", error);
+
+        let mut value = Periodicity::daily().unwrap().to_json();
+        value["version"] = json!(PERIODICITY_JSON_VERSION + 1);
+        assert!(serde_json::from_value::<Periodicity>(value).is_err());
+    }
+
+    #[test]
+    fn test_component_types_round_trip() {
+        let day = DayConstraint::SpecificNthWeekdaysMonth(vec![NthWeekdayOfMonth::last(Weekday::Fri)]);
+        assert_eq!(serde_json::from_value::<DayConstraint>(serde_json::to_value(&day).unwrap()).unwrap(), day);
+
+        let months = MonthConstraint::SpecificMonths(vec![Month::March, Month::September]);
+        assert_eq!(
+            serde_json::to_value(&months).unwrap(),
+            json!({ "type": "specific_months", "value": ["March", "September"] })
+        );
+
+        let settings = OccurrenceTimingSettings {
+            duration: Some(30),
+            not_before: NaiveTime::from_hms_opt(6, 0, 0),
+            best_before: None,
+            rep_timing_settings: Some(vec![RepTimingSettings {
+                rep_index: 0,
+                not_before: None,
+                best_before: NaiveTime::from_hms_opt(8, 0, 0),
+            }]),
+        };
+        let text = serde_json::to_string(&settings).unwrap();
+        assert_eq!(serde_json::from_str::<OccurrenceTimingSettings>(&text).unwrap(), settings);
+
+        assert_eq!(serde_json::to_value(RepetitionUnit::Week).unwrap(), json!("week"));
+        assert_eq!(serde_json::to_value(RollPolicy::NextBusinessDay).unwrap(), json!("next_business_day"));
+        let rule = AfterCompletion { interval: 4, unit: RepetitionUnit::Day };
+        assert_eq!(serde_json::to_value(rule).unwrap(), json!({ "interval": 4, "unit": "day" }));
+        assert!(serde_json::from_value::<YearConstraint>(json!({ "type": "every_century" })).is_err());
+    }
+
+    #[test]
+    fn test_exceptions_and_dates_round_trip() {
+        let day = |d: u32| NaiveDate::from_ymd_opt(2026, 12, d).unwrap();
+        let holidays = HolidayCalendar::new("Christmas".to_string(), vec![day(25), day(26)]).unwrap();
+        let exceptions = PeriodicityExceptions { dates: vec![day(24)], holidays: vec![holidays.clone()], roll: RollPolicy::NextBusinessDay };
+        let text = serde_json::to_string(&exceptions).unwrap();
+        assert_eq!(serde_json::from_str::<PeriodicityExceptions>(&text).unwrap(), exceptions);
+        assert_eq!(serde_json::to_value(&holidays).unwrap(), json!({ "name": "Christmas", "dates": ["2026-12-25", "2026-12-26"] }));
+        // Empty exceptions are null, as in the document
+        assert_eq!(serde_json::to_value(PeriodicityExceptions::default()).unwrap(), Value::Null);
+        assert_eq!(serde_json::from_value::<PeriodicityExceptions>(Value::Null).unwrap(), PeriodicityExceptions::default());
+
+        assert_eq!(serde_json::to_value(MonthWeekPosition::FromLast(0)).unwrap(), json!({ "type": "from_last", "value": 0 }));
+        let noon = |d: u32| Utc.with_ymd_and_hms(2026, 12, d, 12, 0, 0).unwrap();
+        let custom = CustomDates::new(vec![noon(24), noon(31)]).unwrap();
+        assert_eq!(serde_json::from_value::<CustomDates>(serde_json::to_value(&custom).unwrap()).unwrap(), custom);
+        let unique = UniqueDate { date: noon(31) };
+        assert_eq!(serde_json::from_value::<UniqueDate>(serde_json::to_value(&unique).unwrap()).unwrap(), unique);
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc, Weekday};
 use crate::domain::entities::task::periodicity::Periodicity;
+use crate::domain::entities::user::User;
 
 // ========================================================================
 // VALIDATION ERRORS
@@ -154,6 +155,25 @@ impl Task {
         })
     }
 
+    /// Rebuilds a Task from persisted state (validates like the setters,
+    /// but keeps `updated_at` as stored)
+    pub fn restore(
+        title: String,
+        description: Option<String>,
+        status: TaskStatus,
+        priority: TaskPriority,
+        periodicity: Periodicity,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Result<Self, TaskValidationError> {
+        let mut task = Self::with_timestamps(title, periodicity, created_at, updated_at)?;
+        task.set_description(description)?;
+        task.status = status;
+        task.priority = priority;
+        task.updated_at = updated_at;
+        Ok(task)
+    }
+
     // ── GETTERS ─────────────────────────────────────────────
 
     pub fn title(&self) -> &str {
@@ -253,6 +273,24 @@ impl Task {
         self.periodicity.is_within_timeframe(date)
     }
 
+    /// Check if this task should occur at an instant, evaluated in the
+    /// user's logical calendar (timezone, `day_start` and `week_start`)
+    /// 
+    /// # Parameters
+    /// - `instant`: The instant to check (e.g. a completion time)
+    /// - `user`: The user whose calendar settings apply
+    pub fn should_occur_for(&self, instant: &DateTime<Utc>, user: &User) -> bool {
+        if self.status != TaskStatus::Active {
+            return false;
+        }
+
+        if !self.periodicity.matches_constraints_for(instant, user) {
+            return false;
+        }
+
+        self.periodicity.is_within_timeframe(instant)
+    }
+
     /// Check if task is currently active
     pub fn is_active(&self) -> bool {
         self.status == TaskStatus::Active
@@ -365,4 +403,48 @@ mod tests {
         task.set_priority(TaskPriority::Urgent);
         assert_eq!(task.priority(), TaskPriority::Urgent);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_task_should_occur_for_uses_logical_day() {
+        use chrono::{NaiveTime, TimeZone};
+        use crate::domain::entities::user::{PasswordHash, Timezone};
+
+        let periodicity = Periodicity::on_weekdays(vec![Weekday::Sun]).unwrap();
+        let task = Task::new("Weekly review".to_string(), periodicity).unwrap();
+        let mut user = User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new("Europe/London".to_string()).unwrap(),
+        ).unwrap();
+        user.set_day_start(NaiveTime::from_hms_opt(5, 0, 0).unwrap());
+
+        // Monday 02:00 still belongs to Sunday's logical day
+        let completion = Utc.with_ymd_and_hms(2026, 2, 9, 2, 0, 0).unwrap();
+        assert!(!task.should_occur_on(&completion, Weekday::Mon));
+        assert!(task.should_occur_for(&completion, &user));
+
+        let monday_morning = Utc.with_ymd_and_hms(2026, 2, 9, 6, 0, 0).unwrap();
+        assert!(!task.should_occur_for(&monday_morning, &user));
+    }
+
+    #[test]
+    fn test_task_restore_keeps_persisted_state() {
+        let created = Utc::now() - chrono::Duration::days(10);
+        let updated = created + chrono::Duration::days(1);
+        let task = Task::restore(
+            "Water plants".to_string(),
+            Some("Balcony".to_string()),
+            TaskStatus::Paused,
+            TaskPriority::High,
+            Periodicity::daily().unwrap(),
+            created,
+            updated,
+        ).unwrap();
+
+        assert_eq!(task.description(), Some("Balcony"));
+        assert_eq!(task.status(), TaskStatus::Paused);
+        assert_eq!(task.priority(), TaskPriority::High);
+        assert_eq!(task.updated_at(), updated);
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use super::OccurenceRep;
 
 // ========================================================================
@@ -66,6 +66,12 @@ pub enum OccurrenceStatus {
 /// - Task is the aggregate root
 /// - TaskOccurrence cannot exist without a Task
 /// - In persistence layer, task_id would link back to Task
+/// 
+/// # Generation
+/// Occurrences are produced by `services::generate_occurrences`, which
+/// aligns windows on the user's day_start, week_start and year_start.
+/// When a roll policy moves an occurrence, its window is the day it was
+/// moved to and `nominal_date` the day it was scheduled on.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TaskOccurrence {
     // Note: task_id would be added by persistence layer to link back to Task
@@ -79,9 +85,14 @@ pub struct TaskOccurrence {
     /// For daily task: 23:59:59 of the same day
     /// For weekly task: 23:59:59 of the last day of week
     /// For monthly task: 23:59:59 of the last day of month
-    /// For yearly task: 23:59:59 of Dec 31
+    /// For yearly task: 23:59:59 of the day before the next year start
+    /// (all shifted by the user's day_start)
     window_end: DateTime<Utc>,
     
+    /// Day the periodicity scheduled this occurrence on, when its roll
+    /// policy moved it to another day (None when it wasn't moved)
+    nominal_date: Option<NaiveDate>,
+    
     // ── REPETITIONS ─────────────────────────────────────────
     
     /// All repetitions for this occurrence
@@ -123,11 +134,35 @@ impl TaskOccurrence {
         Ok(Self {
             window_start,
             window_end,
+            nominal_date: None,
             repetitions,
             notes: None,
         })
     }
 
+    /// Rebuilds a TaskOccurrence from persisted state
+    /// 
+    /// Repetitions must be indexed 0..n in order.
+    pub fn restore(
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        repetitions: Vec<OccurenceRep>,
+        notes: Option<String>,
+    ) -> Result<Self, TaskOccurrenceValidationError> {
+        let mut occurrence = Self::new(window_start, window_end, 0)?;
+        for (expected, rep) in repetitions.iter().enumerate() {
+            if rep.rep_index() as usize != expected {
+                return Err(TaskOccurrenceValidationError::InvalidRepIndex {
+                    expected: repetitions.len() as u8,
+                    actual: rep.rep_index(),
+                });
+            }
+        }
+        occurrence.repetitions = repetitions;
+        occurrence.set_notes(notes)?;
+        Ok(occurrence)
+    }
+
     // ── GETTERS ─────────────────────────────────────────────
 
     pub fn window_start(&self) -> DateTime<Utc> {
@@ -138,6 +173,10 @@ impl TaskOccurrence {
         self.window_end
     }
 
+    pub fn nominal_date(&self) -> Option<NaiveDate> {
+        self.nominal_date
+    }
+
     pub fn repetitions(&self) -> &[OccurenceRep] {
         &self.repetitions
     }
@@ -178,6 +217,11 @@ impl TaskOccurrence {
 
     // ── DOMAIN BEHAVIORS ────────────────────────────────────
 
+    /// Records the day this occurrence was scheduled on before being moved
+    pub fn set_nominal_date(&mut self, date: Option<NaiveDate>) {
+        self.nominal_date = date;
+    }
+
     /// Mark a specific repetition as complete
     pub fn mark_rep_complete(&mut self, rep_index: u8) -> Result<(), TaskOccurrenceValidationError> {
         let rep_count = self.rep_count();
@@ -464,4 +508,30 @@ mod tests {
         let result = occurrence.set_rep_notes(0, Some(long_rep_notes));
         assert!(matches!(result, Err(TaskOccurrenceValidationError::NotesTooLong { .. })));
     }
+
+    #[test]
+    fn test_restore_occurrence() {
+        let start = Utc.with_ymd_and_hms(2026, 2, 7, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 2, 7, 23, 59, 59).unwrap();
+        let done_at = Utc.with_ymd_and_hms(2026, 2, 7, 9, 30, 0).unwrap();
+
+        let occurrence = TaskOccurrence::restore(
+            start,
+            end,
+            vec![
+                OccurenceRep::restore(0, Some(done_at), Some("Morning".to_string())).unwrap(),
+                OccurenceRep::restore(1, None, None).unwrap(),
+            ],
+            Some("Half done".to_string()),
+        ).unwrap();
+
+        assert_eq!(occurrence.status(), OccurrenceStatus::InProgress);
+        assert_eq!(occurrence.last_completed_at(), Some(done_at));
+        assert_eq!(occurrence.repetitions()[0].notes(), Some("Morning"));
+        assert_eq!(occurrence.notes(), Some("Half done"));
+
+        // Reps out of order are rejected
+        let result = TaskOccurrence::restore(start, end, vec![OccurenceRep::new(1)], None);
+        assert!(matches!(result, Err(TaskOccurrenceValidationError::InvalidRepIndex { .. })));
+    }
 }
@@ -4,5 +4,8 @@ pub use timezone::{Timezone, TimezoneError};
 pub mod location;
 pub use location::{Location, LocationError, GeoCoordinates, GeoCoordinatesError};
 
+pub mod password;
+pub use password::{Password, PasswordHash, PasswordError};
+
 pub mod user;
-pub use user::User;
\ No newline at end of file
+pub use user::{User, UserValidationError};
\ No newline at end of file
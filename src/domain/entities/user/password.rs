@@ -0,0 +1,258 @@
+use std::fmt;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash as PhcString, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params};
+
+// ========================================================================
+// PASSWORD VALUE OBJECTS
+// A plaintext password only lives long enough to be hashed or verified;
+// what a User keeps is its argon2 hash
+// ========================================================================
+
+/// A plaintext password, as typed by the user
+///
+/// Never stored: hash it with `hash()` or check it with `PasswordHash::verify`.
+/// `Debug` doesn't print it.
+///
+/// # Domain Rules
+/// - At least `MIN_LENGTH` characters
+/// - At most `MAX_LENGTH` characters (bounds the hashing work)
+#[derive(Clone)]
+pub struct Password(String);
+
+impl Password {
+    pub const MIN_LENGTH: usize = 8;
+    pub const MAX_LENGTH: usize = 1024;
+
+    /// Wraps a new password, checking its length
+    pub fn new(plaintext: String) -> Result<Self, PasswordError> {
+        let length = plaintext.chars().count();
+        if length < Self::MIN_LENGTH {
+            return Err(PasswordError::TooShort { min: Self::MIN_LENGTH, actual: length });
+        }
+        if length > Self::MAX_LENGTH {
+            return Err(PasswordError::TooLong { max: Self::MAX_LENGTH });
+        }
+        Ok(Self(plaintext))
+    }
+
+    /// Wraps a password to check against a stored hash
+    ///
+    /// No length rules: they apply when a password is chosen, and a login
+    /// attempt should fail by not matching, not by being rejected up front.
+    pub fn attempt(plaintext: String) -> Self {
+        Self(plaintext)
+    }
+
+    /// Hashes with argon2id (default parameters) and a fresh random salt
+    pub fn hash(&self) -> Result<PasswordHash, PasswordError> {
+        let salt = SaltString::generate(&mut OsRng);
+        let phc = Argon2::default()
+            .hash_password(self.0.as_bytes(), &salt)
+            .map_err(|e| PasswordError::Hashing(e.to_string()))?;
+        Ok(PasswordHash(phc.to_string()))
+    }
+}
+
+impl fmt::Debug for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Password(***)")
+    }
+}
+
+/// An argon2 password hash in PHC string format
+/// (e.g. "$argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>")
+///
+/// Can only be built from a well-formed argon2 PHC string, so plaintext
+/// can't end up where a hash is expected.
+///
+/// # Example
+/// ```
+/// use tsadaash::domain::entities::user::{Password, PasswordHash};
+///
+/// assert!(PasswordHash::from_phc("hunter2".to_string()).is_err());
+///
+/// let phc = "$argon2id$v=19$m=19456,t=2,p=1$dGVzdHNhbHR0ZXN0c2FsdA$4kBl/zrrvHnVH5qdfy6Ndjp8Q5Ki81vzKYQRJcEC7qg";
+/// let hash = PasswordHash::from_phc(phc.to_string()).unwrap();
+/// assert!(hash.verify(&Password::attempt("correct horse battery staple".to_string())));
+/// assert!(!hash.verify(&Password::attempt("wrong".to_string())));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct PasswordHash(String);
+
+impl PasswordHash {
+    /// Parses a PHC string, accepting only argon2 (argon2id, argon2i, argon2d)
+    /// hashes with valid parameters, a salt and a hash
+    pub fn from_phc(phc: String) -> Result<Self, PasswordError> {
+        let invalid = |reason: &str| PasswordError::InvalidHash(reason.to_string());
+
+        let parsed = PhcString::new(&phc).map_err(|_| invalid("not a PHC string"))?;
+        Algorithm::new(parsed.algorithm.as_str()).map_err(|_| invalid("not an argon2 hash"))?;
+        Params::try_from(&parsed).map_err(|_| invalid("invalid argon2 parameters"))?;
+        if parsed.salt.is_none() || parsed.hash.is_none() {
+            return Err(invalid("missing salt or hash"));
+        }
+        Ok(Self(phc))
+    }
+
+    /// Checks `password` against this hash
+    ///
+    /// The password is hashed with this hash's own parameters and salt, and
+    /// the outputs are compared in constant time.
+    pub fn verify(&self, password: &Password) -> bool {
+        match PhcString::new(&self.0) {
+            Ok(parsed) => Argon2::default().verify_password(password.0.as_bytes(), &parsed).is_ok(),
+            // Checked by from_phc
+            Err(_) => false,
+        }
+    }
+
+    /// A valid hash that no password matches
+    ///
+    /// Stands in until a real hash is set, and keeps logins to unknown
+    /// accounts as slow as real ones.
+    pub fn locked() -> Self {
+        Self(Self::LOCKED_PHC.to_string())
+    }
+
+    /// PHC string of `locked()`: default argon2id parameters, all-zero hash
+    pub const LOCKED_PHC: &'static str =
+        "$argon2id$v=19$m=19456,t=2,p=1$bG9ja2VkbG9ja2VkbG9jaw$AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+
+    /// The PHC string (for storage)
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for PasswordHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PasswordHash(***)")
+    }
+}
+
+// ========================================================================
+// PASSWORD ERRORS
+// ========================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordError {
+    /// Password has fewer than `min` characters
+    TooShort { min: usize, actual: usize },
+
+    /// Password has more than `max` characters
+    TooLong { max: usize },
+
+    /// String is not an argon2 PHC hash
+    InvalidHash(String),
+
+    /// Hashing failed (e.g. no randomness available for the salt)
+    Hashing(String),
+}
+
+impl fmt::Display for PasswordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordError::TooShort { min, actual } => {
+                write!(f, "Password is too short: {} characters (minimum {})", actual, min)
+            }
+            PasswordError::TooLong { max } => {
+                write!(f, "Password is too long (maximum {} characters)", max)
+            }
+            PasswordError::InvalidHash(reason) => {
+                write!(f, "Invalid password hash: {}", reason)
+            }
+            PasswordError::Hashing(reason) => {
+                write!(f, "Could not hash password: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PasswordError {}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// argon2id, default parameters, password "correct horse battery staple"
+    const PHC: &str = "$argon2id$v=19$m=19456,t=2,p=1$dGVzdHNhbHR0ZXN0c2FsdA$4kBl/zrrvHnVH5qdfy6Ndjp8Q5Ki81vzKYQRJcEC7qg";
+
+    #[test]
+    fn test_password_length_rules() {
+        assert_eq!(
+            Password::new("short".to_string()).unwrap_err(),
+            PasswordError::TooShort { min: 8, actual: 5 }
+        );
+        assert!(Password::new("ééééééé".to_string()).is_err()); // 7 characters, 14 bytes
+        assert!(Password::new("long enough".to_string()).is_ok());
+        assert_eq!(
+            Password::new("x".repeat(1025)).unwrap_err(),
+            PasswordError::TooLong { max: 1024 }
+        );
+    }
+
+    #[test]
+    fn test_hash_then_verify() {
+        let password = Password::new("correct horse battery staple".to_string()).unwrap();
+        let hash = password.hash().unwrap();
+
+        assert!(hash.as_str().starts_with("$argon2id$"));
+        assert!(hash.verify(&password));
+        assert!(!hash.verify(&Password::attempt("Correct horse battery staple".to_string())));
+
+        // Fresh salt every time
+        assert_ne!(password.hash().unwrap(), hash);
+        // Round-trips through storage
+        assert_eq!(PasswordHash::from_phc(hash.as_str().to_string()).unwrap(), hash);
+    }
+
+    #[test]
+    fn test_verify_known_hash() {
+        let hash = PasswordHash::from_phc(PHC.to_string()).unwrap();
+        assert!(hash.verify(&Password::attempt("correct horse battery staple".to_string())));
+        assert!(!hash.verify(&Password::attempt(String::new())));
+    }
+
+    #[test]
+    fn test_locked_hash_matches_nothing() {
+        let locked = PasswordHash::locked();
+        assert_eq!(PasswordHash::from_phc(PasswordHash::LOCKED_PHC.to_string()).unwrap(), locked);
+        for attempt in ["", "password", "correct horse battery staple"] {
+            assert!(!locked.verify(&Password::attempt(attempt.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_only_argon2_phc_strings() {
+        for bad in [
+            "",
+            "correct horse battery staple",
+            "password_hash",
+            // Not argon2
+            "$scrypt$ln=16,r=8,p=1$aM15713r3Xsvxbi31lqr1Q$nFNh2CVHVjNldFVKDHDlm4CbdRSCdEBsjjJxD+iCs5E",
+            // No hash part
+            "$argon2id$v=19$m=19456,t=2,p=1$dGVzdHNhbHR0ZXN0c2FsdA",
+            // Memory cost below the argon2 minimum
+            "$argon2id$v=19$m=1,t=2,p=1$dGVzdHNhbHR0ZXN0c2FsdA$4kBl/zrrvHnVH5qdfy6Ndjp8Q5Ki81vzKYQRJcEC7qg",
+        ] {
+            assert!(
+                matches!(PasswordHash::from_phc(bad.to_string()), Err(PasswordError::InvalidHash(_))),
+                "accepted {:?}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_debug_hides_secrets() {
+        let password = Password::attempt("hunter2hunter2".to_string());
+        assert_eq!(format!("{:?}", password), "Password(***)");
+        let hash = PasswordHash::from_phc(PHC.to_string()).unwrap();
+        assert!(!format!("{:?}", hash).contains("argon2"));
+    }
+}
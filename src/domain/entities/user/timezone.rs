@@ -1,9 +1,12 @@
 use std::fmt;
 use std::ops::Deref;
+use std::str::FromStr;
+use chrono_tz::Tz;
 
 // ========================================================================
 // TIMEZONE VALUE OBJECT
-// Encapsulates IANA timezone identifier with format validation only
+// Encapsulates IANA timezone identifier with format validation, and checked
+// resolution against the IANA database (chrono-tz)
 // ========================================================================
 
 /// Represents a timezone identifier (e.g., "America/New_York", "Europe/Paris")
@@ -37,9 +40,9 @@ use std::ops::Deref;
 /// assert!(tz.starts_with("America"));
 /// ```
 /// 
-/// # Application Layer Responsibility
-/// The application layer should validate that the timezone actually exists
-/// using the tz_cities.json data or chrono-tz crate (infrastructure concern)
+/// # Existence Check
+/// `Timezone::new` validates the format only. Use `Timezone::resolve` to also
+/// check the identifier against the IANA database, and `to_tz` to convert.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Timezone(String);
 
@@ -98,6 +101,55 @@ impl Timezone {
         Ok(Self(trimmed.to_string()))
     }
     
+    /// Creates a timezone that must exist in the IANA database
+    /// 
+    /// Runs the format validation of `new`, then parses the identifier with
+    /// chrono-tz. Unknown identifiers come back with the closest known zone
+    /// from `data/timezones.json`, if any is close enough.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tsadaash::domain::entities::user::{Timezone, TimezoneError};
+    /// 
+    /// assert!(Timezone::resolve("Europe/Paris".to_string()).is_ok());
+    /// 
+    /// // Valid format, but not a real zone
+    /// assert_eq!(
+    ///     Timezone::resolve("Europe/Pari".to_string()),
+    ///     Err(TimezoneError::UnknownZone {
+    ///         identifier: "Europe/Pari".to_string(),
+    ///         suggestion: Some("Europe/Paris".to_string()),
+    ///     }),
+    /// );
+    /// ```
+    pub fn resolve(identifier: String) -> Result<Self, TimezoneError> {
+        let timezone = Self::new(identifier)?;
+        timezone.resolve_tz()?;
+        Ok(timezone)
+    }
+    
+    /// Like `to_tz`, but an unknown zone comes back with the closest known
+    /// one as its suggestion (slow on failure: reads `data/timezones.json`)
+    pub fn resolve_tz(&self) -> Result<Tz, TimezoneError> {
+        Tz::from_str(&self.0).map_err(|_| TimezoneError::UnknownZone {
+            identifier: self.0.clone(),
+            suggestion: suggest_zone(&self.0),
+        })
+    }
+    
+    /// Converts to a `chrono_tz::Tz` for date/time conversions
+    /// 
+    /// Fails with `TimezoneError::UnknownZone` when the identifier is well-formed
+    /// but not in the IANA database (only possible for values built with `new`).
+    /// The error carries no suggestion: `to_tz` runs for every converted date,
+    /// so the did-you-mean search is left to `resolve_tz`.
+    pub fn to_tz(&self) -> Result<Tz, TimezoneError> {
+        Tz::from_str(&self.0).map_err(|_| TimezoneError::UnknownZone {
+            identifier: self.0.clone(),
+            suggestion: None,
+        })
+    }
+    
     /// Returns the timezone identifier as a string slice
     /// 
     /// Note: You can also use `&*tz` or `tz.as_ref()` due to `Deref` implementation
@@ -111,6 +163,49 @@ impl Timezone {
     }
 }
 
+// ========================================================================
+// "DID YOU MEAN" SUGGESTIONS
+// ========================================================================
+
+/// Known "Area/City" zones, grouped by area
+const KNOWN_ZONES_JSON: &str = include_str!("../../../../data/timezones.json");
+
+/// Returns the known zone closest to `identifier` (case-insensitive edit
+/// distance), or None if nothing is reasonably close
+fn suggest_zone(identifier: &str) -> Option<String> {
+    let zones: std::collections::BTreeMap<String, Vec<String>> =
+        serde_json::from_str(KNOWN_ZONES_JSON).ok()?;
+    let wanted = identifier.to_lowercase();
+    
+    // Allow roughly one typo every three characters
+    let max_distance = (wanted.chars().count() / 3).max(2);
+    
+    zones.iter()
+        .flat_map(|(area, cities)| cities.iter().map(move |city| format!("{}/{}", area, city)))
+        .filter(|zone| Tz::from_str(zone).is_ok())
+        .map(|zone| (edit_distance(&wanted, &zone.to_lowercase()), zone))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, zone)| zone)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    
+    previous[b.len()]
+}
+
 // ========================================================================
 // TRAIT IMPLEMENTATIONS FOR ERGONOMICS
 // ========================================================================
@@ -174,6 +269,13 @@ pub enum TimezoneError {
     /// Timezone identifier contains invalid characters
     /// Valid characters: alphanumeric, underscore, slash, hyphen, plus
     InvalidCharacters(String),
+    
+    /// Timezone identifier is well-formed but not in the IANA database
+    /// `suggestion` is the closest known zone, if any
+    UnknownZone {
+        identifier: String,
+        suggestion: Option<String>,
+    },
 }
 
 impl fmt::Display for TimezoneError {
@@ -203,6 +305,13 @@ impl fmt::Display for TimezoneError {
                     tz
                 )
             }
+            TimezoneError::UnknownZone { identifier, suggestion } => {
+                write!(f, "Unknown timezone '{}'", identifier)?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " (did you mean '{}'?)", suggestion)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -317,6 +426,81 @@ mod tests {
         assert!(Timezone::new("Mars/Olympus_Mons".to_string()).is_ok());
     }
     
+    // ========================================================================
+    // RESOLUTION TESTS (IANA database)
+    // ========================================================================
+    
+    #[test]
+    fn test_resolve_known_zones() {
+        assert!(Timezone::resolve("America/New_York".to_string()).is_ok());
+        assert!(Timezone::resolve("America/Argentina/Buenos_Aires".to_string()).is_ok());
+        assert!(Timezone::resolve("Etc/GMT+5".to_string()).is_ok());
+    }
+    
+    #[test]
+    fn test_resolve_still_checks_format() {
+        let result = Timezone::resolve("Paris".to_string());
+        assert!(matches!(result, Err(TimezoneError::MissingAreaSeparator(_))));
+    }
+    
+    #[test]
+    fn test_resolve_unknown_zone_without_suggestion() {
+        let result = Timezone::resolve("Mars/Olympus_Mons".to_string());
+        assert_eq!(result, Err(TimezoneError::UnknownZone {
+            identifier: "Mars/Olympus_Mons".to_string(),
+            suggestion: None,
+        }));
+    }
+    
+    #[test]
+    fn test_resolve_unknown_zone_with_suggestion() {
+        let cases = [
+            ("America/NewYork", "America/New_York"),
+            ("europe/paris", "Europe/Paris"),
+            ("Asia/Tokio", "Asia/Tokyo"),
+        ];
+        for (typo, expected) in cases {
+            match Timezone::resolve(typo.to_string()) {
+                Err(TimezoneError::UnknownZone { suggestion, .. }) => {
+                    assert_eq!(suggestion.as_deref(), Some(expected), "for {}", typo);
+                }
+                other => panic!("expected UnknownZone for {}, got {:?}", typo, other),
+            }
+        }
+    }
+    
+    #[test]
+    fn test_unknown_zone_display() {
+        let error = TimezoneError::UnknownZone {
+            identifier: "Asia/Tokio".to_string(),
+            suggestion: Some("Asia/Tokyo".to_string()),
+        };
+        assert_eq!(error.to_string(), "Unknown timezone 'Asia/Tokio' (did you mean 'Asia/Tokyo'?)");
+    }
+    
+    #[test]
+    fn test_to_tz() {
+        let tz = Timezone::new("Europe/Paris".to_string()).unwrap();
+        assert_eq!(tz.to_tz(), Ok(chrono_tz::Europe::Paris));
+        
+        let fake = Timezone::new("FakeContinent/FakeCity".to_string()).unwrap();
+        assert!(matches!(fake.to_tz(), Err(TimezoneError::UnknownZone { .. })));
+        
+        // Cheap error: the suggestion is only looked up by resolve
+        let typo = Timezone::new("Asia/Tokio".to_string()).unwrap();
+        assert_eq!(typo.to_tz(), Err(TimezoneError::UnknownZone {
+            identifier: "Asia/Tokio".to_string(),
+            suggestion: None,
+        }));
+    }
+    
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+    
     // ========================================================================
     // ERGONOMICS TESTS (Deref, AsRef, PartialEq)
     // ========================================================================
@@ -1,29 +1,87 @@
-use chrono::{Month, NaiveTime, Weekday};
-use super::timezone::Timezone;
+use std::fmt;
+use chrono::{DateTime, Duration, Month, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use super::timezone::{Timezone, TimezoneError};
 use super::location::Location;
+use super::password::PasswordHash;
 
+// ========================================================================
+// USER VALIDATION ERRORS
+// ========================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserValidationError {
+    EmptyUsername,
+    UsernameTooLong { max: usize, actual: usize },
+    /// Usernames are limited to ASCII letters, digits, '.', '_' and '-'
+    InvalidUsernameCharacter { character: char },
+    EmailTooLong { max: usize, actual: usize },
+    InvalidEmail { reason: String },
+    /// The timezone isn't in the IANA database
+    UnknownTimezone(TimezoneError),
+}
+
+impl fmt::Display for UserValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserValidationError::EmptyUsername => write!(f, "Username cannot be empty"),
+            UserValidationError::UsernameTooLong { max, actual } => {
+                write!(f, "Username too long: {} characters (max: {})", actual, max)
+            }
+            UserValidationError::InvalidUsernameCharacter { character } => write!(
+                f,
+                "Username cannot contain {:?} (use letters, digits, '.', '_' and '-')",
+                character
+            ),
+            UserValidationError::EmailTooLong { max, actual } => {
+                write!(f, "Email too long: {} characters (max: {})", actual, max)
+            }
+            UserValidationError::InvalidEmail { reason } => write!(f, "Invalid email: {}", reason),
+            UserValidationError::UnknownTimezone(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for UserValidationError {}
+
+// ========================================================================
+// USER ENTITY
+// ========================================================================
+
+/// A user and their calendar settings
+///
+/// # Domain Rules
+/// - Username: 1 to `MAX_USERNAME_LENGTH` ASCII letters, digits, '.', '_' or '-'
+///   (surrounding whitespace is trimmed)
+/// - Email: at most `MAX_EMAIL_LENGTH` characters, `local@domain` with a dotted
+///   domain and no whitespace
+/// - Timezone: must exist in the IANA database
 #[derive(Debug, Clone)]
 pub struct User {
-    pub username: String,
-    pub email: String,
-    pub password_hash: String,
+    username: String,
+    email: String,
+    /// argon2 hash of the password (never the password itself)
+    password_hash: PasswordHash,
 
     // ── TIMEZONE & LOCATION ──────────────────────────────────
     /// User's timezone (e.g., "America/New_York", "Europe/London")
-    pub timezone: Timezone,
+    timezone: Timezone,
+
+    /// `timezone` resolved once, when set
+    tz: Tz,
     
     /// User's physical location (optional)
-    pub location: Option<Vec<Location>>,  // Changed to Vec<Location> to allow multiple locations (e.g., home, work)
+    location: Option<Vec<Location>>,  // Changed to Vec<Location> to allow multiple locations (e.g., home, work)
 
     // ── CALENDAR SETTINGS ────────────────────────────────────
     
     /// First day of the week (for week-based calculations)
     /// Default: Monday
-    pub week_start: Weekday,
+    week_start: Weekday,
     
     /// First month of the year (for year-based calculations)
     /// Default: January (for fiscal years, could be different)
-    pub year_start: Month,
+    year_start: Month,
     
     /// Time of day when a new day begins (for daily task boundaries)
     /// Default: 00:00:00 (midnight)
@@ -32,57 +90,119 @@ pub struct User {
     /// If set to 05:00:00, then "February 7th" runs from Feb 7 05:00:00 to Feb 8 04:59:59.
     /// Useful for users who consider their "day" to start at a different time
     /// (e.g., night shift workers, or "today ends when I go to sleep at 5 AM").
-    pub day_start: NaiveTime,
+    day_start: NaiveTime,
 }
 
 impl User {
-    /// Creates a new user with the given timezone
+    /// Maximum length for usernames
+    pub const MAX_USERNAME_LENGTH: usize = 32;
+
+    /// Maximum length for emails (RFC 5321 path limit)
+    pub const MAX_EMAIL_LENGTH: usize = 254;
+
+    /// Creates a new user with the given timezone and default calendar settings
     pub fn new(
         username: String,
         email: String,
-        password_hash: String,
+        password_hash: PasswordHash,
         timezone: Timezone,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, UserValidationError> {
+        Self::with_all_settings(
             username,
             email,
             password_hash,
             timezone,
-            location: None,
-            week_start: Weekday::Mon,
-            year_start: Month::January,
-            day_start: NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
-        }
+            None,
+            Weekday::Mon,
+            Month::January,
+            NaiveTime::MIN,
+        )
     }
     
     /// Create a user with custom calendar settings and optional location
     pub fn with_all_settings(
         username: String,
         email: String,
-        password_hash: String,
+        password_hash: PasswordHash,
         timezone: Timezone,
         location: Option<Vec<Location>>,
         week_start: Weekday,
         year_start: Month,
         day_start: NaiveTime,
-    ) -> Self {
-        Self {
-            username,
-            email,
+    ) -> Result<Self, UserValidationError> {
+        Ok(Self {
+            username: validate_username(&username)?,
+            email: validate_email(&email)?,
             password_hash,
+            tz: timezone.resolve_tz().map_err(UserValidationError::UnknownTimezone)?,
             timezone,
             location,
             week_start,
             year_start,
             day_start,
-        }
+        })
+    }
+
+    // ── GETTERS ─────────────────────────────────────────────
+
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn password_hash(&self) -> &PasswordHash {
+        &self.password_hash
+    }
+
+    pub fn timezone(&self) -> &Timezone {
+        &self.timezone
+    }
+
+    pub fn location(&self) -> Option<&[Location]> {
+        self.location.as_deref()
+    }
+
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    pub fn year_start(&self) -> Month {
+        self.year_start
+    }
+
+    pub fn day_start(&self) -> NaiveTime {
+        self.day_start
+    }
+
+    // ── ACCOUNT SETTERS ─────────────────────────────────────
+
+    /// Changes the username (same rules as at creation)
+    pub fn set_username(&mut self, username: String) -> Result<(), UserValidationError> {
+        self.username = validate_username(&username)?;
+        Ok(())
+    }
+
+    /// Changes the email (same rules as at creation)
+    pub fn set_email(&mut self, email: String) -> Result<(), UserValidationError> {
+        self.email = validate_email(&email)?;
+        Ok(())
+    }
+
+    /// Replaces the password hash (after a password change)
+    pub fn set_password_hash(&mut self, password_hash: PasswordHash) {
+        self.password_hash = password_hash;
     }
     
     // ── TIMEZONE & LOCATION SETTERS ─────────────────────────
     
     /// Updates the user's timezone
-    pub fn set_timezone(&mut self, timezone: Timezone) {
+    pub fn set_timezone(&mut self, timezone: Timezone) -> Result<(), UserValidationError> {
+        self.tz = timezone.resolve_tz().map_err(UserValidationError::UnknownTimezone)?;
         self.timezone = timezone;
+        Ok(())
     }
     
     /// Sets or updates the user's location
@@ -106,15 +226,15 @@ impl User {
     /// 
     /// # Example
     /// ```
-    /// # use tsadaash::domain::entities::user::{User, Timezone};
+    /// # use tsadaash::domain::entities::user::{PasswordHash, User, Timezone};
     /// # use chrono::NaiveTime;
     /// let timezone = Timezone::new("America/New_York".to_string()).unwrap();
     /// let mut user = User::new(
     ///     "user".to_string(),
     ///     "user@example.com".to_string(),
-    ///     "password_hash".to_string(),
+    ///     PasswordHash::locked(),
     ///     timezone,
-    /// );
+    /// ).unwrap();
     /// 
     /// // Night shift worker: day starts at 6 PM
     /// user.set_day_start(NaiveTime::from_hms_opt(18, 0, 0).unwrap());
@@ -122,4 +242,244 @@ impl User {
     pub fn set_day_start(&mut self, time: NaiveTime) {
         self.day_start = time;
     }
-}
\ No newline at end of file
+    
+    // ── LOGICAL CALENDAR ────────────────────────────────────
+    
+    /// Returns the user's timezone for conversions
+    /// 
+    /// Always known: users can't be built or updated with an unknown zone.
+    pub fn tz(&self) -> Tz {
+        self.tz
+    }
+    
+    /// Converts a UTC instant to the user's local wall-clock time
+    pub fn local_datetime(&self, instant: &DateTime<Utc>) -> NaiveDateTime {
+        instant.with_timezone(&self.tz()).naive_local()
+    }
+    
+    /// Returns the UTC instant at which the logical day `date` begins
+    /// (`day_start` on that local date)
+    /// 
+    /// # DST Transitions
+    /// - Repeated local time (fall back): the earliest instant is used
+    /// - Skipped local time (spring forward): the day begins once clocks have
+    ///   jumped, e.g. 02:30 on the spring-forward day becomes 03:30
+    pub fn day_start_instant(&self, date: NaiveDate) -> DateTime<Utc> {
+        let tz = self.tz();
+        let local = date.and_time(self.day_start);
+        tz.from_local_datetime(&local)
+            .earliest()
+            .or_else(|| tz.from_local_datetime(&(local + Duration::hours(1))).earliest())
+            .map(|instant| instant.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+    }
+    
+    /// Returns the "day" an instant belongs to for this user
+    /// 
+    /// The instant is converted to local time, then shifted back by `day_start`:
+    /// with day_start = 05:00, Feb 8 02:00 (local) still belongs to Feb 7.
+    /// 
+    /// # Example
+    /// ```
+    /// # use tsadaash::domain::entities::user::{PasswordHash, User, Timezone};
+    /// # use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+    /// let timezone = Timezone::new("Europe/London".to_string()).unwrap();
+    /// let mut user = User::new(
+    ///     "user".to_string(),
+    ///     "user@example.com".to_string(),
+    ///     PasswordHash::locked(),
+    ///     timezone,
+    /// ).unwrap();
+    /// user.set_day_start(NaiveTime::from_hms_opt(5, 0, 0).unwrap());
+    /// 
+    /// let instant = Utc.with_ymd_and_hms(2026, 2, 8, 2, 0, 0).unwrap();
+    /// assert_eq!(user.logical_date(&instant), NaiveDate::from_ymd_opt(2026, 2, 7).unwrap());
+    /// ```
+    pub fn logical_date(&self, instant: &DateTime<Utc>) -> NaiveDate {
+        let offset = self.day_start.signed_duration_since(NaiveTime::MIN);
+        (self.local_datetime(instant) - offset).date()
+    }
+}
+
+// ── FIELD VALIDATION ────────────────────────────────────────
+
+/// Returns the trimmed username, or why it is rejected
+fn validate_username(username: &str) -> Result<String, UserValidationError> {
+    let username = username.trim();
+    if username.is_empty() {
+        return Err(UserValidationError::EmptyUsername);
+    }
+    let length = username.chars().count();
+    if length > User::MAX_USERNAME_LENGTH {
+        return Err(UserValidationError::UsernameTooLong { max: User::MAX_USERNAME_LENGTH, actual: length });
+    }
+    if let Some(character) = username.chars().find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))) {
+        return Err(UserValidationError::InvalidUsernameCharacter { character });
+    }
+    Ok(username.to_string())
+}
+
+/// Returns the trimmed email, or why it is rejected
+///
+/// A shape check, not full RFC 5322: one '@', something before it, and a
+/// domain made of non-empty dot-separated labels.
+fn validate_email(email: &str) -> Result<String, UserValidationError> {
+    let email = email.trim();
+    let invalid = |reason: &str| UserValidationError::InvalidEmail { reason: reason.to_string() };
+
+    let length = email.chars().count();
+    if length > User::MAX_EMAIL_LENGTH {
+        return Err(UserValidationError::EmailTooLong { max: User::MAX_EMAIL_LENGTH, actual: length });
+    }
+    if email.is_empty() {
+        return Err(invalid("empty"));
+    }
+    if email.chars().any(char::is_whitespace) {
+        return Err(invalid("contains whitespace"));
+    }
+    let (local, domain) = match email.split_once('@') {
+        Some((local, domain)) if !domain.contains('@') => (local, domain),
+        Some(_) => return Err(invalid("more than one '@'")),
+        None => return Err(invalid("missing '@'")),
+    };
+    if local.is_empty() {
+        return Err(invalid("nothing before '@'"));
+    }
+    if !domain.contains('.') || domain.split('.').any(str::is_empty) {
+        return Err(invalid("domain must look like example.com"));
+    }
+    Ok(email.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(timezone: &str) -> User {
+        User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new(timezone.to_string()).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_logical_date_defaults_to_local_calendar_date() {
+        let user = user("Europe/London");
+        let instant = Utc.with_ymd_and_hms(2026, 2, 8, 0, 0, 0).unwrap();
+        assert_eq!(user.logical_date(&instant), date(2026, 2, 8));
+    }
+
+    #[test]
+    fn test_logical_date_shifted_by_day_start() {
+        let mut user = user("Europe/London");
+        user.set_day_start(NaiveTime::from_hms_opt(5, 0, 0).unwrap());
+
+        let before = Utc.with_ymd_and_hms(2026, 2, 8, 4, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 2, 8, 5, 0, 0).unwrap();
+        assert_eq!(user.logical_date(&before), date(2026, 2, 7));
+        assert_eq!(user.logical_date(&after), date(2026, 2, 8));
+    }
+
+    #[test]
+    fn test_logical_date_uses_timezone() {
+        let user = user("America/Los_Angeles");
+        // Monday 03:00 UTC is still Sunday evening in Los Angeles
+        let instant = Utc.with_ymd_and_hms(2026, 2, 9, 3, 0, 0).unwrap();
+        assert_eq!(user.logical_date(&instant), date(2026, 2, 8));
+    }
+
+    #[test]
+    fn test_unknown_timezone_is_rejected() {
+        let new = |timezone: &str| User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new(timezone.to_string()).unwrap(),
+        );
+        assert!(matches!(
+            new("Mars/Olympus_Mons"),
+            Err(UserValidationError::UnknownTimezone(TimezoneError::UnknownZone { .. })),
+        ));
+        assert_eq!(
+            new("Europe/Pari").unwrap_err().to_string(),
+            "Unknown timezone 'Europe/Pari' (did you mean 'Europe/Paris'?)",
+        );
+
+        let mut user = user("Europe/Paris");
+        assert!(user.set_timezone(Timezone::new("Mars/Olympus_Mons".to_string()).unwrap()).is_err());
+        assert_eq!(user.timezone(), "Europe/Paris");
+        assert_eq!(user.tz(), chrono_tz::Europe::Paris);
+    }
+
+    #[test]
+    fn test_day_start_instant_follows_dst() {
+        let user = user("Europe/Paris");
+        // Winter: UTC+1, summer: UTC+2
+        assert_eq!(
+            user.day_start_instant(date(2026, 3, 28)),
+            Utc.with_ymd_and_hms(2026, 3, 27, 23, 0, 0).unwrap(),
+        );
+        assert_eq!(
+            user.day_start_instant(date(2026, 3, 30)),
+            Utc.with_ymd_and_hms(2026, 3, 29, 22, 0, 0).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_day_start_instant_in_dst_gap() {
+        let mut user = user("America/New_York");
+        user.set_day_start(NaiveTime::from_hms_opt(2, 30, 0).unwrap());
+
+        // 02:30 does not exist on 2026-03-08: the day begins at 03:30 EDT
+        assert_eq!(
+            user.day_start_instant(date(2026, 3, 8)),
+            Utc.with_ymd_and_hms(2026, 3, 8, 7, 30, 0).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_username_rules() {
+        let make = |name: &str| {
+            User::new(name.to_string(), "user@example.com".to_string(), PasswordHash::locked(), Timezone::new("Etc/UTC".to_string()).unwrap())
+        };
+
+        assert_eq!(make("  alice.b-c_1 ").unwrap().username(), "alice.b-c_1");
+        assert_eq!(make("   ").unwrap_err(), UserValidationError::EmptyUsername);
+        assert_eq!(
+            make(&"a".repeat(33)).unwrap_err(),
+            UserValidationError::UsernameTooLong { max: 32, actual: 33 }
+        );
+        assert_eq!(make("alice smith").unwrap_err(), UserValidationError::InvalidUsernameCharacter { character: ' ' });
+        assert_eq!(make("zoë").unwrap_err(), UserValidationError::InvalidUsernameCharacter { character: 'ë' });
+    }
+
+    #[test]
+    fn test_email_rules() {
+        let mut user = user("Etc/UTC");
+
+        user.set_email(" alice@mail.example.org ".to_string()).unwrap();
+        assert_eq!(user.email(), "alice@mail.example.org");
+
+        for bad in ["", "alice", "@example.com", "alice@", "alice@localhost", "alice@example..com",
+                    "alice@example.com.", "a@b@example.com", "alice smith@example.com"] {
+            assert!(
+                matches!(user.set_email(bad.to_string()), Err(UserValidationError::InvalidEmail { .. })),
+                "accepted {:?}",
+                bad
+            );
+        }
+        assert!(matches!(
+            user.set_email(format!("{}@example.com", "a".repeat(250))),
+            Err(UserValidationError::EmailTooLong { max: 254, .. })
+        ));
+        // Rejected changes leave the email as it was
+        assert_eq!(user.email(), "alice@mail.example.org");
+    }
+}
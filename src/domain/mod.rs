@@ -1,4 +1,5 @@
 pub mod entities;
 pub mod builders;
 pub mod validators;
-pub mod tests;
\ No newline at end of file
+pub mod services;
+pub mod tests;
@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use crate::domain::entities::task::{Task, TaskOccurrence, TaskOccurrenceValidationError};
+use crate::domain::entities::user::User;
+use super::generate_occurrences_with_history;
+
+// ========================================================================
+// AGENDA
+// The occurrences due in a range, across all of a user's tasks
+// ========================================================================
+
+/// One occurrence on the agenda
+///
+/// `key` identifies the task for the caller (e.g. a database id): the domain
+/// doesn't know task identities.
+#[derive(Debug, Clone)]
+pub struct AgendaEntry<'a, K> {
+    pub key: K,
+    pub task: &'a Task,
+    pub occurrence: TaskOccurrence,
+    /// Logical day the entry is listed under: the day its window starts,
+    /// or the first day of the range for windows that started earlier
+    pub day: NaiveDate,
+}
+
+impl<K> AgendaEntry<'_, K> {
+    /// Earliest time of day to do it (`OccurrenceTimingSettings::not_before`)
+    pub fn not_before(&self) -> Option<NaiveTime> {
+        self.task.periodicity().occurrence_settings.as_ref().and_then(|s| s.not_before)
+    }
+}
+
+/// Expands `tasks` into their occurrences overlapping `[from, to)`, in agenda order
+///
+/// # Order
+/// By day, then by priority (most urgent first), then by `not_before`
+/// (earliest first, unset last), then by title.
+///
+/// Paused and archived tasks contribute nothing.
+pub fn build_agenda<'a, K: Clone>(
+    tasks: &'a [(K, Task)],
+    user: &User,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<AgendaEntry<'a, K>>, TaskOccurrenceValidationError> {
+    build_agenda_with_history(tasks, user, from, to, |_| &[])
+}
+
+/// Like `build_agenda`, with the stored occurrences of each task
+/// (see `generate_occurrences_with_history`)
+///
+/// `history` is only asked for tasks with a completion-relative periodicity.
+pub fn build_agenda_with_history<'a, 'h, K: Clone>(
+    tasks: &'a [(K, Task)],
+    user: &User,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    history: impl Fn(&K) -> &'h [TaskOccurrence],
+) -> Result<Vec<AgendaEntry<'a, K>>, TaskOccurrenceValidationError> {
+    let first_day = user.logical_date(&from);
+
+    let mut entries = Vec::new();
+    for (key, task) in tasks {
+        let stored = match task.periodicity().after_completion {
+            Some(_) => history(key),
+            None => &[],
+        };
+        for occurrence in generate_occurrences_with_history(task, user, from, to, stored)? {
+            let day = user.logical_date(&occurrence.window_start()).max(first_day);
+            entries.push(AgendaEntry { key: key.clone(), task, occurrence, day });
+        }
+    }
+
+    entries.sort_by(agenda_order);
+    Ok(entries)
+}
+
+fn agenda_order<K>(a: &AgendaEntry<K>, b: &AgendaEntry<K>) -> Ordering {
+    a.day
+        .cmp(&b.day)
+        .then_with(|| b.task.priority().cmp(&a.task.priority()))
+        .then_with(|| match (a.not_before(), b.not_before()) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        })
+        .then_with(|| a.task.title().cmp(b.task.title()))
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::domain::builders::PeriodicityBuilder;
+    use chrono::Duration;
+    use crate::domain::entities::task::periodicity::{OccurrenceTimingSettings, RepetitionUnit};
+    use crate::domain::entities::task::{OccurenceRep, Periodicity, TaskPriority};
+    use crate::domain::entities::user::{PasswordHash, Timezone};
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn user() -> User {
+        User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new("Etc/UTC".to_string()).unwrap(),
+        ).unwrap()
+    }
+
+    fn task(title: &str, periodicity: Periodicity, priority: TaskPriority) -> Task {
+        let created = utc(2026, 1, 1);
+        let mut task = Task::with_timestamps(title.to_string(), periodicity, created, created).unwrap();
+        task.set_priority(priority);
+        task
+    }
+
+    fn daily_not_before(hour: u32) -> Periodicity {
+        PeriodicityBuilder::new()
+            .daily(1)
+            .with_occurrence_settings(OccurrenceTimingSettings {
+                duration: None,
+                not_before: NaiveTime::from_hms_opt(hour, 0, 0),
+                best_before: None,
+                rep_timing_settings: None,
+            })
+            .build()
+            .unwrap()
+    }
+
+    fn titles<'a, K>(entries: &[AgendaEntry<'a, K>]) -> Vec<&'a str> {
+        entries.iter().map(|e| e.task.title()).collect()
+    }
+
+    #[test]
+    fn test_sorted_by_priority_then_not_before() {
+        let tasks = vec![
+            (1, task("Evening walk", daily_not_before(18), TaskPriority::Medium)),
+            (2, task("Medication", daily_not_before(8), TaskPriority::Urgent)),
+            (3, task("Stretch", Periodicity::daily().unwrap(), TaskPriority::Medium)),
+            (4, task("Breakfast", daily_not_before(7), TaskPriority::Medium)),
+        ];
+
+        let entries = build_agenda(&tasks, &user(), utc(2026, 2, 7), utc(2026, 2, 8)).unwrap();
+        assert_eq!(titles(&entries), vec!["Medication", "Breakfast", "Evening walk", "Stretch"]);
+        assert_eq!(entries[0].key, 2);
+    }
+
+    #[test]
+    fn test_grouped_by_day() {
+        let tasks = vec![
+            (1, task("Daily", Periodicity::daily().unwrap(), TaskPriority::Low)),
+            (2, task("Weekly", Periodicity::weekly().unwrap(), TaskPriority::Urgent)),
+        ];
+
+        // Saturday to Monday: the weekly window that began on Monday Feb 2 is
+        // listed on the first day, the next one on Monday Feb 9
+        let entries = build_agenda(&tasks, &user(), utc(2026, 2, 7), utc(2026, 2, 10)).unwrap();
+        let days: Vec<_> = entries.iter().map(|e| (e.day.to_string(), e.task.title())).collect();
+        assert_eq!(days, vec![
+            ("2026-02-07".to_string(), "Weekly"),
+            ("2026-02-07".to_string(), "Daily"),
+            ("2026-02-08".to_string(), "Daily"),
+            ("2026-02-09".to_string(), "Weekly"),
+            ("2026-02-09".to_string(), "Daily"),
+        ]);
+    }
+
+    #[test]
+    fn test_inactive_tasks_are_left_out() {
+        let mut paused = task("Paused", Periodicity::daily().unwrap(), TaskPriority::High);
+        paused.pause();
+        let tasks = vec![(1, paused), (2, task("Active", Periodicity::daily().unwrap(), TaskPriority::Low))];
+
+        let entries = build_agenda(&tasks, &user(), utc(2026, 2, 7), utc(2026, 2, 8)).unwrap();
+        assert_eq!(titles(&entries), vec!["Active"]);
+    }
+
+    #[test]
+    fn test_completion_relative_tasks_use_their_history() {
+        let watering = PeriodicityBuilder::new()
+            .daily(1)
+            .after_completion(4, RepetitionUnit::Day)
+            .build()
+            .unwrap();
+        let tasks = vec![(1, task("Water plants", watering, TaskPriority::Medium))];
+
+        let rep = OccurenceRep::restore(0, Some(utc(2026, 2, 3)), None).unwrap();
+        let history = vec![TaskOccurrence::restore(utc(2026, 2, 3), utc(2026, 2, 4) - Duration::seconds(1), vec![rep], None).unwrap()];
+
+        // Never done, it's overdue; watered on Feb 3rd, it's due again on Feb 7th
+        let entries = build_agenda(&tasks, &user(), utc(2026, 2, 5), utc(2026, 2, 6)).unwrap();
+        assert_eq!(titles(&entries), vec!["Water plants"]);
+        let entries = build_agenda_with_history(&tasks, &user(), utc(2026, 2, 5), utc(2026, 2, 6), |_| &history).unwrap();
+        assert!(entries.is_empty());
+    }
+}
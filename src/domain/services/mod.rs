@@ -0,0 +1,5 @@
+pub mod occurrence_generator;
+pub use occurrence_generator::{generate_occurrences, generate_occurrences_with_history, occurrence_window};
+
+pub mod agenda;
+pub use agenda::{build_agenda, build_agenda_with_history, AgendaEntry};
\ No newline at end of file
@@ -0,0 +1,540 @@
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, Utc};
+use crate::domain::entities::task::{Periodicity, Task, TaskOccurrence, TaskOccurrenceValidationError};
+use crate::domain::entities::task::periodicity::RepetitionUnit;
+use crate::domain::entities::user::User;
+
+// ========================================================================
+// OCCURRENCE GENERATOR
+// Turns a Task's periodicity into TaskOccurrence windows for a User
+// ========================================================================
+
+/// Generates the TaskOccurrences of `task` whose time window overlaps `[from, to)`
+///
+/// # Window Rules
+/// - Window size comes from `rep_unit` (one day, week, month or year)
+/// - Number of reps comes from `rep_per_unit` (1 for special patterns)
+/// - Windows follow the user's local calendar: days start at `User::day_start`
+///   in `User::timezone`, weeks at `User::week_start`, years at `User::year_start`
+/// - A window is generated when at least one of its logical days matches the
+///   periodicity (constraints AND timeframe)
+/// - Special patterns (unique/custom dates) get one daily window per date
+/// - Exceptions drop days; with a roll policy, windows follow the day each
+///   occurrence is moved to and the occurrence records its `nominal_date`
+///   (for week/month/year windows, the one of the window's first occurrence)
+/// - Completion-relative periodicities need the task's completion history,
+///   see `generate_occurrences_with_history` (here they only get their first
+///   occurrence, as if the task had never been done)
+///
+/// Paused and archived tasks generate nothing.
+///
+/// EveryN* patterns without reference date or timeframe are anchored on
+/// the task's creation date, so windows don't depend on the requested range.
+///
+/// # Example
+/// ```
+/// use tsadaash::domain::entities::task::{Periodicity, Task};
+/// use tsadaash::domain::entities::user::{PasswordHash, Timezone, User};
+/// use tsadaash::domain::services::generate_occurrences;
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Europe::Paris;
+///
+/// let task = Task::new("Stretch".to_string(), Periodicity::weekly().unwrap()).unwrap();
+/// let user = User::new(
+///     "user".to_string(),
+///     "user@example.com".to_string(),
+///     PasswordHash::locked(),
+///     Timezone::new("Europe/Paris".to_string()).unwrap(),
+/// ).unwrap();
+///
+/// let from = Paris.with_ymd_and_hms(2026, 2, 2, 0, 0, 0).unwrap().with_timezone(&Utc);
+/// let to = Paris.with_ymd_and_hms(2026, 2, 16, 0, 0, 0).unwrap().with_timezone(&Utc);
+/// let occurrences = generate_occurrences(&task, &user, from, to).unwrap();
+///
+/// // Two Monday-to-Sunday windows, starting at midnight in Paris
+/// assert_eq!(occurrences.len(), 2);
+/// assert_eq!(occurrences[1].window_start(), Utc.with_ymd_and_hms(2026, 2, 8, 23, 0, 0).unwrap());
+/// ```
+pub fn generate_occurrences(
+    task: &Task,
+    user: &User,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<TaskOccurrence>, TaskOccurrenceValidationError> {
+    generate_occurrences_with_history(task, user, from, to, &[])
+}
+
+/// Like `generate_occurrences`, knowing the task's stored occurrences
+///
+/// Calendar-anchored periodicities ignore `history`. For completion-relative
+/// ones (`Periodicity::after_completion`):
+/// - Completed occurrences of `history` overlapping `[from, to)` are returned as is
+/// - The next occurrence gets the window of `rep_unit` holding the day it's
+///   due (`Periodicity::next_due_for` from the latest completion); it stays
+///   listed once its window is over, until it's done
+/// - Partly done occurrences don't count as completions
+pub fn generate_occurrences_with_history(
+    task: &Task,
+    user: &User,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    history: &[TaskOccurrence],
+) -> Result<Vec<TaskOccurrence>, TaskOccurrenceValidationError> {
+    if !task.is_active() || from >= to {
+        return Ok(Vec::new());
+    }
+
+    let mut periodicity = task.periodicity().clone();
+    if periodicity.reference_date.is_none() && periodicity.timeframe.is_none() {
+        periodicity.reference_date = Some(task.created_at());
+    }
+    let rep_count = periodicity.rep_per_unit.unwrap_or(1);
+
+    if periodicity.after_completion.is_some() {
+        return completion_relative_occurrences(&periodicity, user, from, to, history);
+    }
+
+    let mut occurrences = Vec::new();
+
+    // Special patterns: one daily window per date
+    if let Some(pattern) = &periodicity.special_pattern {
+        let mut previous_start = None;
+        for date in pattern.dates() {
+            if !periodicity.is_within_timeframe(date) {
+                continue;
+            }
+            let nominal = user.logical_date(date);
+            let Some(day) = periodicity.exceptions.roll(nominal) else {
+                continue;
+            };
+            let (start, end) = occurrence_window(RepetitionUnit::None, &user.day_start_instant(day), user);
+            if end < from || start >= to || previous_start == Some(start) {
+                continue;
+            }
+            previous_start = Some(start);
+            let mut occurrence = TaskOccurrence::new(start, end, rep_count)?;
+            if day != nominal {
+                occurrence.set_nominal_date(Some(nominal));
+            }
+            occurrences.push(occurrence);
+        }
+        return Ok(occurrences);
+    }
+
+    // Regular patterns: walk the windows covering the range
+    let mut first_day = window_first_day(periodicity.rep_unit, user.logical_date(&from), user);
+    loop {
+        let start = user.day_start_instant(first_day);
+        if start >= to {
+            break;
+        }
+        let next_first_day = next_window_first_day(periodicity.rep_unit, first_day);
+        let next_start = user.day_start_instant(next_first_day);
+
+        let first_match = periodicity
+            .occurrences_between_for(start, next_start, user)
+            .next_with_nominal();
+        if let Some((date, nominal)) = first_match {
+            let mut occurrence = TaskOccurrence::new(start, next_start - Duration::seconds(1), rep_count)?;
+            if user.logical_date(&date) != nominal {
+                occurrence.set_nominal_date(Some(nominal));
+            }
+            occurrences.push(occurrence);
+        }
+
+        first_day = next_first_day;
+    }
+
+    Ok(occurrences)
+}
+
+/// Completed windows of `history`, then the window of the next due day
+fn completion_relative_occurrences(
+    periodicity: &Periodicity,
+    user: &User,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    history: &[TaskOccurrence],
+) -> Result<Vec<TaskOccurrence>, TaskOccurrenceValidationError> {
+    let completed: Vec<&TaskOccurrence> = history.iter().filter(|o| o.is_completed()).collect();
+    let mut occurrences: Vec<TaskOccurrence> = completed
+        .iter()
+        .filter(|o| o.window_end() >= from && o.window_start() < to)
+        .map(|o| (*o).clone())
+        .collect();
+
+    let last_completed = completed.iter().filter_map(|o| o.last_completed_at()).max();
+    if let Some(due) = periodicity.next_due_for(last_completed.as_ref(), user) {
+        let (start, end) = occurrence_window(periodicity.rep_unit, &due, user);
+        if start < to && occurrences.iter().all(|o| o.window_start() != start) {
+            occurrences.push(TaskOccurrence::new(start, end, periodicity.rep_per_unit.unwrap_or(1))?);
+        }
+    }
+
+    occurrences.sort_by_key(|o| o.window_start());
+    Ok(occurrences)
+}
+
+/// Returns the window (start, end_inclusive) of the given repetition unit
+/// that contains `instant`, following the user's calendar settings
+///
+/// `RepetitionUnit::None` uses daily windows, like special patterns.
+pub fn occurrence_window(
+    rep_unit: RepetitionUnit,
+    instant: &DateTime<Utc>,
+    user: &User,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    let first_day = window_first_day(rep_unit, user.logical_date(instant), user);
+    let next_first_day = next_window_first_day(rep_unit, first_day);
+    (
+        user.day_start_instant(first_day),
+        user.day_start_instant(next_first_day) - Duration::seconds(1),
+    )
+}
+
+// ── CALENDAR HELPERS ────────────────────────────────────────
+
+/// First day of the window of `rep_unit` containing `date`
+fn window_first_day(rep_unit: RepetitionUnit, date: NaiveDate, user: &User) -> NaiveDate {
+    match rep_unit {
+        RepetitionUnit::Day | RepetitionUnit::None => date,
+        RepetitionUnit::Week => {
+            let days_back = (date.weekday().num_days_from_monday() + 7
+                - user.week_start().num_days_from_monday()) % 7;
+            date - Duration::days(days_back as i64)
+        }
+        RepetitionUnit::Month => date.with_day(1).unwrap(),
+        RepetitionUnit::Year => {
+            let year_start = user.year_start().number_from_month();
+            let year = if date.month() >= year_start { date.year() } else { date.year() - 1 };
+            NaiveDate::from_ymd_opt(year, year_start, 1).unwrap()
+        }
+    }
+}
+
+/// First day of the window following the one starting on `first_day`
+fn next_window_first_day(rep_unit: RepetitionUnit, first_day: NaiveDate) -> NaiveDate {
+    match rep_unit {
+        RepetitionUnit::Day | RepetitionUnit::None => first_day + Duration::days(1),
+        RepetitionUnit::Week => first_day + Duration::days(7),
+        RepetitionUnit::Month => first_day + Months::new(1),
+        RepetitionUnit::Year => first_day + Months::new(12),
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Month, NaiveTime, TimeZone, Weekday};
+    use crate::domain::builders::PeriodicityBuilder;
+    use crate::domain::entities::task::{OccurenceRep, RollPolicy};
+    use crate::domain::entities::user::{PasswordHash, Timezone};
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, min, sec).unwrap()
+    }
+
+    fn user() -> User {
+        User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new("Etc/UTC".to_string()).unwrap(),
+        ).unwrap()
+    }
+
+    fn task(periodicity: Periodicity) -> Task {
+        let created = utc(2026, 1, 1, 0, 0, 0);
+        Task::with_timestamps("Test task".to_string(), periodicity, created, created).unwrap()
+    }
+
+    #[test]
+    fn test_daily_windows_and_rep_count() {
+        let task = task(PeriodicityBuilder::new().daily(3).build().unwrap());
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 2, 7, 0, 0, 0), utc(2026, 2, 10, 0, 0, 0),
+        ).unwrap();
+
+        assert_eq!(occurrences.len(), 3);
+        assert_eq!(occurrences[0].window_start(), utc(2026, 2, 7, 0, 0, 0));
+        assert_eq!(occurrences[0].window_end(), utc(2026, 2, 7, 23, 59, 59));
+        assert!(occurrences.iter().all(|o| o.rep_count() == 3));
+    }
+
+    #[test]
+    fn test_daily_windows_only_on_matching_days() {
+        let task = task(Periodicity::on_weekdays(vec![Weekday::Mon, Weekday::Wed]).unwrap());
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 2, 2, 0, 0, 0), utc(2026, 2, 9, 0, 0, 0),
+        ).unwrap();
+
+        let starts: Vec<_> = occurrences.iter().map(|o| o.window_start()).collect();
+        assert_eq!(starts, vec![utc(2026, 2, 2, 0, 0, 0), utc(2026, 2, 4, 0, 0, 0)]);
+    }
+
+    #[test]
+    fn test_daily_windows_respect_day_start() {
+        let mut user = user();
+        user.set_day_start(NaiveTime::from_hms_opt(5, 0, 0).unwrap());
+        let task = task(Periodicity::daily().unwrap());
+
+        // 02:00 on Feb 8 still belongs to the Feb 7 logical day
+        let occurrences = generate_occurrences(
+            &task, &user, utc(2026, 2, 8, 2, 0, 0), utc(2026, 2, 8, 3, 0, 0),
+        ).unwrap();
+
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].window_start(), utc(2026, 2, 7, 5, 0, 0));
+        assert_eq!(occurrences[0].window_end(), utc(2026, 2, 8, 4, 59, 59));
+    }
+
+    #[test]
+    fn test_weekly_windows_respect_week_start() {
+        let mut user = user();
+        user.set_week_start(Weekday::Sun);
+        let task = task(PeriodicityBuilder::new().weekly(2).build().unwrap());
+
+        let occurrences = generate_occurrences(
+            &task, &user, utc(2026, 2, 4, 0, 0, 0), utc(2026, 2, 5, 0, 0, 0),
+        ).unwrap();
+
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].window_start(), utc(2026, 2, 1, 0, 0, 0)); // Sunday
+        assert_eq!(occurrences[0].window_end(), utc(2026, 2, 7, 23, 59, 59)); // Saturday
+        assert_eq!(occurrences[0].rep_count(), 2);
+    }
+
+    #[test]
+    fn test_weekly_windows_every_other_week() {
+        let task = task(PeriodicityBuilder::new()
+            .weekly(1)
+            .every_n_weeks(2)
+            .with_reference_date(utc(2026, 1, 5, 0, 0, 0))
+            .build()
+            .unwrap());
+
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 1, 5, 0, 0, 0), utc(2026, 2, 2, 0, 0, 0),
+        ).unwrap();
+
+        let starts: Vec<_> = occurrences.iter().map(|o| o.window_start()).collect();
+        assert_eq!(starts, vec![utc(2026, 1, 5, 0, 0, 0), utc(2026, 1, 19, 0, 0, 0)]);
+    }
+
+    #[test]
+    fn test_monthly_windows() {
+        let task = task(PeriodicityBuilder::new()
+            .monthly(2)
+            .in_months(vec![Month::February, Month::March])
+            .build()
+            .unwrap());
+
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 1, 1, 0, 0, 0), utc(2027, 1, 1, 0, 0, 0),
+        ).unwrap();
+
+        assert_eq!(occurrences.len(), 2);
+        assert_eq!(occurrences[0].window_start(), utc(2026, 2, 1, 0, 0, 0));
+        assert_eq!(occurrences[0].window_end(), utc(2026, 2, 28, 23, 59, 59));
+        assert_eq!(occurrences[1].window_end(), utc(2026, 3, 31, 23, 59, 59));
+        assert_eq!(occurrences[0].rep_count(), 2);
+    }
+
+    #[test]
+    fn test_yearly_windows_respect_year_start() {
+        let mut user = user();
+        user.set_year_start(Month::April);
+        let task = task(Periodicity::yearly().unwrap());
+
+        let occurrences = generate_occurrences(
+            &task, &user, utc(2026, 2, 1, 0, 0, 0), utc(2026, 5, 1, 0, 0, 0),
+        ).unwrap();
+
+        assert_eq!(occurrences.len(), 2);
+        assert_eq!(occurrences[0].window_start(), utc(2025, 4, 1, 0, 0, 0));
+        assert_eq!(occurrences[0].window_end(), utc(2026, 3, 31, 23, 59, 59));
+        assert_eq!(occurrences[1].window_start(), utc(2026, 4, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_unique_date_window() {
+        let task = task(Periodicity::unique(utc(2026, 12, 25, 18, 0, 0)).unwrap());
+
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 12, 1, 0, 0, 0), utc(2027, 1, 1, 0, 0, 0),
+        ).unwrap();
+
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].window_start(), utc(2026, 12, 25, 0, 0, 0));
+        assert_eq!(occurrences[0].rep_count(), 1);
+
+        let outside = generate_occurrences(
+            &task, &user(), utc(2026, 11, 1, 0, 0, 0), utc(2026, 12, 1, 0, 0, 0),
+        ).unwrap();
+        assert!(outside.is_empty());
+    }
+
+    #[test]
+    fn test_paused_task_generates_nothing() {
+        let mut task = task(Periodicity::daily().unwrap());
+        task.pause();
+
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 2, 1, 0, 0, 0), utc(2026, 3, 1, 0, 0, 0),
+        ).unwrap();
+        assert!(occurrences.is_empty());
+    }
+
+    #[test]
+    fn test_occurrence_window() {
+        let user = user();
+        let instant = utc(2026, 2, 18, 15, 0, 0);
+
+        assert_eq!(
+            occurrence_window(RepetitionUnit::Week, &instant, &user),
+            (utc(2026, 2, 16, 0, 0, 0), utc(2026, 2, 22, 23, 59, 59)),
+        );
+        assert_eq!(
+            occurrence_window(RepetitionUnit::Month, &instant, &user),
+            (utc(2026, 2, 1, 0, 0, 0), utc(2026, 2, 28, 23, 59, 59)),
+        );
+    }
+
+    #[test]
+    fn test_daily_windows_across_paris_dst() {
+        let mut user = user();
+        user.set_timezone(Timezone::new("Europe/Paris".to_string()).unwrap()).unwrap();
+        let task = task(Periodicity::daily().unwrap());
+
+        // Clocks go forward on 2026-03-29: that local day lasts 23 hours
+        let occurrences = generate_occurrences(
+            &task, &user, utc(2026, 3, 28, 12, 0, 0), utc(2026, 3, 30, 12, 0, 0),
+        ).unwrap();
+
+        let windows: Vec<_> = occurrences.iter().map(|o| (o.window_start(), o.window_end())).collect();
+        assert_eq!(windows, vec![
+            (utc(2026, 3, 27, 23, 0, 0), utc(2026, 3, 28, 22, 59, 59)),
+            (utc(2026, 3, 28, 23, 0, 0), utc(2026, 3, 29, 21, 59, 59)),
+            (utc(2026, 3, 29, 22, 0, 0), utc(2026, 3, 30, 21, 59, 59)),
+        ]);
+    }
+
+    #[test]
+    fn test_weekly_windows_across_new_york_dst() {
+        let mut user = user();
+        user.set_timezone(Timezone::new("America/New_York".to_string()).unwrap()).unwrap();
+        let task = task(Periodicity::weekly().unwrap());
+
+        // Clocks go back on 2026-11-01 (Sunday): that week lasts 169 hours
+        let occurrences = generate_occurrences(
+            &task, &user, utc(2026, 10, 28, 12, 0, 0), utc(2026, 10, 29, 0, 0, 0),
+        ).unwrap();
+
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].window_start(), utc(2026, 10, 26, 4, 0, 0)); // Mon 00:00 EDT
+        assert_eq!(occurrences[0].window_end(), utc(2026, 11, 2, 4, 59, 59)); // Sun 23:59:59 EST
+    }
+
+    #[test]
+    fn test_rolled_windows_record_their_nominal_date() {
+        let task = task(PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![1])
+            .with_roll_policy(RollPolicy::PreviousBusinessDay)
+            .build()
+            .unwrap());
+
+        // Wednesday Jul 1st stays, Saturday Aug 1st moves to Friday Jul 31st
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 7, 1, 0, 0, 0), utc(2026, 8, 2, 0, 0, 0),
+        ).unwrap();
+
+        let windows: Vec<_> = occurrences.iter().map(|o| (o.window_start(), o.nominal_date())).collect();
+        assert_eq!(windows, vec![
+            (utc(2026, 7, 1, 0, 0, 0), None),
+            (utc(2026, 7, 31, 0, 0, 0), NaiveDate::from_ymd_opt(2026, 8, 1)),
+        ]);
+    }
+
+    #[test]
+    fn test_special_pattern_windows_follow_exceptions() {
+        let saturday = utc(2026, 8, 1, 18, 0, 0);
+        let christmas = utc(2026, 12, 25, 9, 0, 0);
+        let task = task(PeriodicityBuilder::new()
+            .custom_dates(vec![saturday, christmas])
+            .unwrap()
+            .except(vec![NaiveDate::from_ymd_opt(2026, 12, 25).unwrap()])
+            .with_roll_policy(RollPolicy::NextBusinessDay)
+            .build()
+            .unwrap());
+
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 7, 1, 0, 0, 0), utc(2027, 1, 1, 0, 0, 0),
+        ).unwrap();
+
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].window_start(), utc(2026, 8, 3, 0, 0, 0));
+        assert_eq!(occurrences[0].nominal_date(), NaiveDate::from_ymd_opt(2026, 8, 1));
+    }
+
+    fn done_at(window_day: u32, completed_at: DateTime<Utc>) -> TaskOccurrence {
+        let start = utc(2026, 6, window_day, 0, 0, 0);
+        let rep = OccurenceRep::restore(0, Some(completed_at), None).unwrap();
+        TaskOccurrence::restore(start, start + Duration::days(1) - Duration::seconds(1), vec![rep], None).unwrap()
+    }
+
+    #[test]
+    fn test_completion_relative_windows_follow_the_last_completion() {
+        let task = task(PeriodicityBuilder::new()
+            .daily(1)
+            .after_completion(4, RepetitionUnit::Day)
+            .build()
+            .unwrap());
+
+        // Due Jun 1st, done late on Jun 2nd: next due Jun 6th
+        let history = vec![done_at(1, utc(2026, 6, 2, 10, 0, 0))];
+        let occurrences = generate_occurrences_with_history(
+            &task, &user(), utc(2026, 6, 1, 0, 0, 0), utc(2026, 6, 15, 0, 0, 0), &history,
+        ).unwrap();
+        let windows: Vec<_> = occurrences.iter().map(|o| (o.window_start(), o.is_completed())).collect();
+        assert_eq!(windows, vec![
+            (utc(2026, 6, 1, 0, 0, 0), true),
+            (utc(2026, 6, 6, 0, 0, 0), false),
+        ]);
+
+        // The latest completion counts, whatever the order of the history
+        let history = vec![done_at(6, utc(2026, 6, 6, 9, 0, 0)), done_at(1, utc(2026, 6, 2, 10, 0, 0))];
+        let occurrences = generate_occurrences_with_history(
+            &task, &user(), utc(2026, 6, 8, 0, 0, 0), utc(2026, 6, 15, 0, 0, 0), &history,
+        ).unwrap();
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].window_start(), utc(2026, 6, 10, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_completion_relative_occurrence_stays_until_done() {
+        let task = task(PeriodicityBuilder::new()
+            .weekly(1)
+            .after_completion(10, RepetitionUnit::Day)
+            .build()
+            .unwrap());
+
+        // Never done: due since the creation week, still listed in March
+        let occurrences = generate_occurrences(
+            &task, &user(), utc(2026, 3, 2, 0, 0, 0), utc(2026, 3, 9, 0, 0, 0),
+        ).unwrap();
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].window_start(), utc(2025, 12, 29, 0, 0, 0));
+        assert_eq!(occurrences[0].window_end(), utc(2026, 1, 4, 23, 59, 59));
+
+        // Not due yet: nothing before the window of the due day
+        let history = vec![done_at(1, utc(2026, 6, 1, 12, 0, 0))];
+        let occurrences = generate_occurrences_with_history(
+            &task, &user(), utc(2026, 6, 2, 0, 0, 0), utc(2026, 6, 8, 0, 0, 0), &history,
+        ).unwrap();
+        assert!(occurrences.is_empty());
+    }
+}
@@ -12,7 +12,7 @@ mod periodicity_tests {
     use crate::domain::builders::periodicity_builder::PeriodicityBuilder;
     use crate::domain::validators::periodicity_validator::ValidationError;
     use crate::domain::entities::task::periodicity::{Periodicity, DayConstraint, MonthConstraint,
-        NthWeekdayOfMonth, RepetitionUnit};
+        NthWeekdayOfMonth, PeriodicityExceptions, RepetitionUnit};
     use chrono::{Utc, Weekday, Month, TimeZone};
 
     // ========================================================================
@@ -238,8 +238,11 @@ mod periodicity_tests {
             rep_per_unit: None, // Missing!
             occurrence_settings: None,
             constraints: PeriodicityConstraints::default(),
+            alternatives: Vec::new(),
             timeframe: None,
             special_pattern: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
@@ -263,8 +266,11 @@ mod periodicity_tests {
             rep_per_unit: Some(0), // Zero is invalid!
             occurrence_settings: None,
             constraints: PeriodicityConstraints::default(),
+            alternatives: Vec::new(),
             timeframe: None,
             special_pattern: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
@@ -299,8 +305,11 @@ mod periodicity_tests {
                 ])),
                 ..Default::default()
             },
+            alternatives: Vec::new(),
             timeframe: None,
             special_pattern: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
@@ -321,8 +330,11 @@ mod periodicity_tests {
                 day_constraint: Some(DayConstraint::SpecificDaysMonthFromFirst(vec![31])),
                 ..Default::default()
             },
+            alternatives: Vec::new(),
             timeframe: None,
             special_pattern: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
@@ -343,8 +355,11 @@ mod periodicity_tests {
                 day_constraint: Some(DayConstraint::EveryNDays(0)),
                 ..Default::default()
             },
+            alternatives: Vec::new(),
             timeframe: None,
             special_pattern: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
@@ -365,8 +380,11 @@ mod periodicity_tests {
                 day_constraint: Some(DayConstraint::EveryNDays(367)),
                 ..Default::default()
             },
+            alternatives: Vec::new(),
             timeframe: None,
             special_pattern: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
@@ -402,7 +420,10 @@ mod periodicity_tests {
                 day_constraint: Some(DayConstraint::EveryDay),
                 ..Default::default()
             },
+            alternatives: Vec::new(),
             timeframe: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
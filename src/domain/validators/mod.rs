@@ -1 +1,2 @@
-pub mod periodicity_validator;
\ No newline at end of file
+pub mod periodicity_validator;
+pub mod periodicity_satisfiability;
@@ -0,0 +1,283 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
+use crate::domain::entities::task::{
+    DayConstraint, MonthConstraint, Periodicity, PeriodicityConstraints, WeekConstraint, YearConstraint,
+};
+
+// ========================================================================
+// SATISFIABILITY ANALYSIS
+// Valid periodicities that never (or rarely) fire
+// ========================================================================
+//
+// `validate_periodicity` checks each rule on its own; the combination may
+// still never match ("the 30th" in February, a fifth Monday in a week of the
+// month that can't hold one, years outside the timeframe). The analysis
+// expands the periodicity over a bounded horizon from a given day and
+// reports `ValidationWarning`s, which don't make the periodicity invalid.
+//
+// The horizon is 28 years (the weekday calendar repeats), longer for wide
+// rolling intervals; when every constraint set has a SpecificYears
+// constraint it is the listed years.
+// Both are clipped to the timeframe. Special patterns list their dates on
+// purpose and aren't analysed.
+
+/// Weekdays fall on the same dates every 28 years (1901-2099)
+const HORIZON_DAYS: i64 = 28 * 365 + 7;
+
+/// Longest search, one Gregorian cycle
+const MAX_HORIZON_DAYS: i64 = 400 * 365 + 97;
+
+/// Gaps longer than this (and than twice the rolling interval) are sparse
+const SPARSE_GAP_DAYS: i64 = 4 * 366;
+
+/// Something likely wrong with a valid periodicity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationWarning {
+    /// No occurrence from `from` to `until` (both included)
+    NeverMatches { from: NaiveDate, until: NaiveDate },
+
+    /// The timeframe ended before the analysed day
+    TimeframeOver { last_day: NaiveDate },
+
+    /// Every occurrence falls in a leap year (e.g. on 29 February)
+    LeapYearOnly { first: NaiveDate, occurrences: usize },
+
+    /// Occurrences are years apart
+    Sparse { first: NaiveDate, occurrences: usize, longest_gap_days: i64 },
+}
+
+impl std::fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationWarning::NeverMatches { from, until } => {
+                write!(f, "Never happens: no occurrence from {} to {}", from, until)
+            }
+            ValidationWarning::TimeframeOver { last_day } => {
+                write!(f, "Never happens again: the timeframe ended on {}", last_day)
+            }
+            ValidationWarning::LeapYearOnly { first, occurrences } => {
+                write!(f, "Only happens in leap years: {} occurrence(s) from {}", occurrences, first)
+            }
+            ValidationWarning::Sparse { first, occurrences, longest_gap_days } => {
+                write!(f, "Rarely happens: {} occurrence(s) from {}, up to {} days apart",
+                    occurrences, first, longest_gap_days)
+            }
+        }
+    }
+}
+
+/// Searches the periodicity's occurrences from `from` and reports why it
+/// never or rarely fires (empty when it looks fine)
+///
+/// Week constraints are read with `week_start`, like `occurrences_between`.
+pub fn check_satisfiability(periodicity: &Periodicity, from: DateTime<Utc>, week_start: Weekday) -> Vec<ValidationWarning> {
+    if periodicity.special_pattern.is_some() {
+        return Vec::new();
+    }
+
+    let interval = rolling_interval_days(periodicity);
+    let horizon = Duration::days((3 * interval).clamp(HORIZON_DAYS, MAX_HORIZON_DAYS));
+    let (mut start, mut end) = (from, from + horizon);
+    if let Some((timeframe_start, timeframe_end)) = periodicity.timeframe {
+        if timeframe_end <= from {
+            return vec![ValidationWarning::TimeframeOver { last_day: last_day(timeframe_end) }];
+        }
+        start = start.max(timeframe_start);
+        end = (start + horizon).min(timeframe_end);
+    }
+
+    // Listed years are searched whole, whatever the horizon
+    let listed_years: Option<Vec<i32>> = periodicity.constraint_sets()
+        .map(|constraints| match &constraints.year_constraint {
+            Some(YearConstraint::SpecificYears(years)) => Some(years.clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|sets| sets.concat());
+    let windows: Vec<_> = match listed_years {
+        Some(mut years) => {
+            let end = periodicity.timeframe.map_or(end, |(_, timeframe_end)| timeframe_end);
+            years.sort_unstable();
+            years.dedup();
+            years.iter()
+                .filter_map(|year| Some((new_year(*year)?, new_year(year + 1)?)))
+                .map(|(first, next)| (first.max(start), next.min(end)))
+                .filter(|(first, next)| first < next)
+                .collect()
+        }
+        None => vec![(start, end)],
+    };
+
+    let occurrences: Vec<NaiveDate> = windows.iter()
+        .flat_map(|(first, next)| periodicity.occurrences_between(*first, *next, week_start))
+        .map(|date| date.date_naive())
+        .collect();
+
+    let Some(&first) = occurrences.first() else {
+        let (from, until) = match (windows.first(), windows.last()) {
+            (Some((first, _)), Some((_, next))) => (first.date_naive(), last_day(*next)),
+            _ => (start.date_naive(), last_day(end)),
+        };
+        return vec![ValidationWarning::NeverMatches { from, until }];
+    };
+
+    // Rarity only means something over a long single search
+    let [(start, end)] = windows[..] else {
+        return Vec::new();
+    };
+    if end - start < Duration::days(SPARSE_GAP_DAYS) {
+        return Vec::new();
+    }
+    if occurrences.iter().all(|date| is_leap_year(date.year())) {
+        return vec![ValidationWarning::LeapYearOnly { first, occurrences: occurrences.len() }];
+    }
+
+    let edges = std::iter::once(start.date_naive())
+        .chain(occurrences.iter().copied())
+        .chain(std::iter::once(end.date_naive()));
+    let longest_gap_days = edges.clone()
+        .zip(edges.skip(1))
+        .map(|(earlier, later)| (later - earlier).num_days())
+        .max()
+        .unwrap_or(0);
+    if longest_gap_days > SPARSE_GAP_DAYS.max(2 * interval) {
+        return vec![ValidationWarning::Sparse { first, occurrences: occurrences.len(), longest_gap_days }];
+    }
+    Vec::new()
+}
+
+/// Longest EveryN* interval of any constraint set, in days (1 without one)
+fn rolling_interval_days(periodicity: &Periodicity) -> i64 {
+    periodicity.constraint_sets().map(set_interval_days).max().unwrap_or(1)
+}
+
+fn set_interval_days(constraints: &PeriodicityConstraints) -> i64 {
+    let day = match constraints.day_constraint {
+        Some(DayConstraint::EveryNDays(n)) => n as i64,
+        _ => 1,
+    };
+    let week = match constraints.week_constraint {
+        Some(WeekConstraint::EveryNWeeks(n)) => 7 * n as i64,
+        _ => 1,
+    };
+    let month = match constraints.month_constraint {
+        Some(MonthConstraint::EveryNMonths(n)) => 31 * n as i64,
+        _ => 1,
+    };
+    let year = match constraints.year_constraint {
+        Some(YearConstraint::EveryNYears(n)) => 366 * n as i64,
+        _ => 1,
+    };
+    day.max(week).max(month).max(year)
+}
+
+fn new_year(year: i32) -> Option<DateTime<Utc>> {
+    Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).single()
+}
+
+/// Calendar day before an exclusive end
+fn last_day(end: DateTime<Utc>) -> NaiveDate {
+    (end - Duration::seconds(1)).date_naive()
+}
+
+fn is_leap_year(year: i32) -> bool {
+    NaiveDate::from_ymd_opt(year, 2, 29).is_some()
+}
+
+// ========================================================================
+// UNIT TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Month;
+    use crate::domain::builders::PeriodicityBuilder;
+    use crate::domain::entities::task::{MonthWeekPosition, NthWeekdayOfMonth};
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn day(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn warnings(builder: PeriodicityBuilder) -> Vec<ValidationWarning> {
+        check_satisfiability(&builder.build().unwrap(), utc(2026, 1, 1), Weekday::Mon)
+    }
+
+    #[test]
+    fn test_ordinary_schedules_have_no_warning() {
+        assert!(warnings(PeriodicityBuilder::new().daily(1)).is_empty());
+        assert!(warnings(PeriodicityBuilder::new().daily(1).on_month_days(vec![31])).is_empty());
+        assert!(warnings(PeriodicityBuilder::new().yearly(1).every_n_years(5)).is_empty());
+        assert!(warnings(PeriodicityBuilder::new().daily(1).on_month_days(vec![13]).in_years(vec![2026])).is_empty());
+        assert!(warnings(PeriodicityBuilder::new().daily(1).on_month_days(vec![29]).in_months(vec![Month::February])
+            .between(utc(2028, 1, 1), utc(2029, 1, 1))).is_empty());
+    }
+
+    #[test]
+    fn test_never_matching_schedules() {
+        // 30 February
+        assert_eq!(
+            warnings(PeriodicityBuilder::new().daily(1).on_month_days(vec![30]).in_months(vec![Month::February])),
+            vec![ValidationWarning::NeverMatches { from: day(2026, 1, 1), until: day(2053, 12, 31) }],
+        );
+
+        // A fifth Monday is never in the first week of the month
+        let fifth_monday = NthWeekdayOfMonth { weekday: Weekday::Mon, position: MonthWeekPosition::FromFirst(4) };
+        assert!(matches!(
+            warnings(PeriodicityBuilder::new().daily(1).on_nth_weekdays(vec![fifth_monday]).on_weeks_of_month(vec![1]))[..],
+            [ValidationWarning::NeverMatches { .. }],
+        ));
+
+        // Years outside the timeframe
+        assert_eq!(
+            warnings(PeriodicityBuilder::new().yearly(1).in_years(vec![2020, 2021]).between(utc(2026, 3, 1), utc(2027, 3, 1))),
+            vec![ValidationWarning::NeverMatches { from: day(2026, 3, 1), until: day(2027, 2, 28) }],
+        );
+
+        assert_eq!(
+            warnings(PeriodicityBuilder::new().daily(1).between(utc(2025, 1, 1), utc(2025, 7, 1))),
+            vec![ValidationWarning::TimeframeOver { last_day: day(2025, 6, 30) }],
+        );
+    }
+
+    #[test]
+    fn test_leap_year_only_schedules() {
+        // 2028, 2032, ..., 2052
+        assert_eq!(
+            warnings(PeriodicityBuilder::new().daily(1).on_month_days(vec![29]).in_months(vec![Month::February])),
+            vec![ValidationWarning::LeapYearOnly { first: day(2028, 2, 29), occurrences: 7 }],
+        );
+    }
+
+    #[test]
+    fn test_alternatives_are_searched_together() {
+        // 29 February, or 1 March: every year
+        assert!(warnings(PeriodicityBuilder::new().daily(1).on_month_days(vec![29]).in_months(vec![Month::February])
+            .or().on_month_days(vec![1]).in_months(vec![Month::March])).is_empty());
+
+        // Both sets list years: only those are searched
+        assert_eq!(
+            warnings(PeriodicityBuilder::new().daily(1).on_month_days(vec![30]).in_months(vec![Month::February]).in_years(vec![2027])
+                .or().on_month_days(vec![31]).in_months(vec![Month::April]).in_years(vec![2026])),
+            vec![ValidationWarning::NeverMatches { from: day(2026, 1, 1), until: day(2027, 12, 31) }],
+        );
+    }
+
+    #[test]
+    fn test_sparse_schedules() {
+        // 1 January on a Monday: 2029, 2035, 2046, 2052
+        assert_eq!(
+            warnings(PeriodicityBuilder::new().daily(1).on_month_days(vec![1]).on_weeks_of_month(vec![1]).in_months(vec![Month::January])),
+            vec![ValidationWarning::Sparse { first: day(2029, 1, 1), occurrences: 4, longest_gap_days: 4018 }],
+        );
+
+        // ISO week 53 only exists in some years: 2026, 2032, 2037, 2043, 2048
+        assert_eq!(
+            warnings(PeriodicityBuilder::new().weekly(1).on_weekdays(vec![Weekday::Mon]).on_iso_weeks(vec![53])),
+            vec![ValidationWarning::Sparse { first: day(2026, 12, 28), occurrences: 5, longest_gap_days: 2191 }],
+        );
+    }
+}
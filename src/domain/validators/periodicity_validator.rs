@@ -1,8 +1,8 @@
 use std::collections::HashSet;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Utc, Weekday};
 use crate::domain::entities::task::{
     DayConstraint, MonthConstraint, MonthWeekPosition, Periodicity, PeriodicityConstraints,
-    SpecialPattern, WeekConstraint, YearConstraint,
+    PeriodicityExceptions, SpecialPattern, WeekConstraint, YearConstraint,
 };
 use crate::domain::entities::task::periodicity::{OccurrenceTimingSettings, RepTimingSettings, RepetitionUnit};
 
@@ -108,14 +108,17 @@ impl std::error::Error for ValidationError {}
 pub fn validate_periodicity(periodicity: &Periodicity) -> Result<(), ValidationError> {
     // 1. Validate special patterns first (short-circuit if present)
     if let Some(pattern) = &periodicity.special_pattern {
-        return validate_special_pattern(periodicity, pattern);
+        validate_special_pattern(periodicity, pattern)?;
+        validate_after_completion(periodicity)?;
+        return validate_exceptions(periodicity);
     }
     
     // 2. Validate repetition unit and count
     validate_repetition(periodicity)?;
     
-    // 3. Validate individual constraints
+    // 3. Validate individual constraints, then how the alternatives combine
     validate_constraints(&periodicity.constraints)?;
+    validate_alternatives(periodicity)?;
     
     // 4. Validate constraint compatibility with repetition unit
     validate_constraint_compatibility(periodicity)?;
@@ -126,6 +129,12 @@ pub fn validate_periodicity(periodicity: &Periodicity) -> Result<(), ValidationE
     // 6. Validate occurrence settings if present
     validate_occurrence_settings(&periodicity.occurrence_settings, periodicity.rep_per_unit)?;
     
+    // 7. Validate exceptions against what they exclude
+    validate_exceptions(periodicity)?;
+    
+    // 8. Validate completion-relative recurrence against the calendar rules
+    validate_after_completion(periodicity)?;
+    
     Ok(())
 }
 
@@ -212,6 +221,37 @@ fn validate_constraints(constraints: &PeriodicityConstraints) -> Result<(), Vali
     Ok(())
 }
 
+fn validate_alternatives(periodicity: &Periodicity) -> Result<(), ValidationError> {
+    if periodicity.alternatives.is_empty() {
+        return Ok(());
+    }
+    
+    for constraints in &periodicity.alternatives {
+        validate_constraints(constraints)?;
+    }
+    
+    // An empty set matches every day and swallows the others
+    if periodicity.constraint_sets().any(|constraints| *constraints == PeriodicityConstraints::default()) {
+        return Err(ValidationError::EmptyCollection {
+            field: "alternatives".into(),
+            reason: "Every constraint set of a union must have at least one constraint".into(),
+        });
+    }
+    
+    let mut seen = Vec::new();
+    for constraints in periodicity.constraint_sets() {
+        if seen.contains(&constraints) {
+            return Err(ValidationError::DuplicateValues {
+                field: "alternatives".into(),
+                reason: format!("Constraint set {:?} is listed more than once", constraints),
+            });
+        }
+        seen.push(constraints);
+    }
+    
+    Ok(())
+}
+
 fn validate_day_constraint(constraint: &DayConstraint) -> Result<(), ValidationError> {
     match constraint {
         DayConstraint::EveryDay => Ok(()),
@@ -366,37 +406,44 @@ fn validate_week_constraint(constraint: &WeekConstraint) -> Result<(), Validatio
         }
         
         WeekConstraint::SpecificWeeksOfMonthFromFirst(weeks) => {
-            validate_weeks_of_month(weeks, "SpecificWeeksOfMonthFromFirst")
+            validate_weeks(weeks, "SpecificWeeksOfMonthFromFirst", 5)
         }
         
         WeekConstraint::SpecificWeeksOfMonthFromLast(weeks) => {
-            validate_weeks_of_month(weeks, "SpecificWeeksOfMonthFromLast")
+            validate_weeks(weeks, "SpecificWeeksOfMonthFromLast", 5)
         }
+        
+        WeekConstraint::SpecificWeeksOfYear(weeks) => validate_weeks(weeks, "SpecificWeeksOfYear", 53),
+        
+        WeekConstraint::SpecificIsoWeeks(weeks) => validate_weeks(weeks, "SpecificIsoWeeks", 53),
+        
+        WeekConstraint::EvenIsoWeeks | WeekConstraint::OddIsoWeeks => Ok(()),
     }
 }
 
-fn validate_weeks_of_month(weeks: &[u8], field_name: &str) -> Result<(), ValidationError> {
+/// 0-indexed weeks, at most `count` of them (5 in a month, 53 in a year)
+fn validate_weeks(weeks: &[u8], field_name: &str, count: u8) -> Result<(), ValidationError> {
     if weeks.is_empty() {
         return Err(ValidationError::EmptyCollection {
             field: field_name.into(),
             reason: "Must contain at least one week".into(),
         });
     }
-    if weeks.len() > 5 {
+    if weeks.len() > count as usize {
         return Err(ValidationError::OutOfRange {
             field: field_name.into(),
             value: weeks.len().to_string(),
             min: "1".into(),
-            max: "5".into(),
+            max: count.to_string(),
         });
     }
     for &week in weeks {
-        if week > 4 {
+        if week >= count {
             return Err(ValidationError::OutOfRange {
                 field: field_name.into(),
                 value: week.to_string(),
                 min: "0".into(),
-                max: "4".into(),
+                max: (count - 1).to_string(),
             });
         }
     }
@@ -530,8 +577,6 @@ fn validate_year_constraint(constraint: &YearConstraint) -> Result<(), Validatio
 // ========================================================================
 
 fn validate_constraint_compatibility(periodicity: &Periodicity) -> Result<(), ValidationError> {
-    let constraints = &periodicity.constraints;
-    
     // Special patterns must have RepetitionUnit::None
     if periodicity.special_pattern.is_some() && periodicity.rep_unit != RepetitionUnit::None {
         return Err(ValidationError::IncompatibleConstraint {
@@ -553,6 +598,16 @@ fn validate_constraint_compatibility(periodicity: &Periodicity) -> Result<(), Va
             Ok(())
         }
         
+        rep_unit => periodicity.constraint_sets()
+            .try_for_each(|constraints| validate_set_compatibility(rep_unit, constraints)),
+    }
+}
+
+/// Checks one constraint set against a regular repetition unit
+fn validate_set_compatibility(rep_unit: RepetitionUnit, constraints: &PeriodicityConstraints) -> Result<(), ValidationError> {
+    match rep_unit {
+        RepetitionUnit::None => Ok(()),
+        
         RepetitionUnit::Day => {
             // Day repetition is compatible with all constraints
             // No specific incompatibilities
@@ -563,7 +618,7 @@ fn validate_constraint_compatibility(periodicity: &Periodicity) -> Result<(), Va
             // Week-level repetition shouldn't have EveryNDays
             if let Some(DayConstraint::EveryNDays(_)) = constraints.day_constraint {
                 return Err(ValidationError::IncompatibleConstraint {
-                    rep_unit: periodicity.rep_unit,
+                    rep_unit,
                     constraint_type: "EveryNDays".into(),
                     reason: "Use Week repetition unit instead".into(),
                 });
@@ -575,7 +630,7 @@ fn validate_constraint_compatibility(periodicity: &Periodicity) -> Result<(), Va
             // Month-level repetition shouldn't have EveryNWeeks
             if let Some(WeekConstraint::EveryNWeeks(_)) = constraints.week_constraint {
                 return Err(ValidationError::IncompatibleConstraint {
-                    rep_unit: periodicity.rep_unit,
+                    rep_unit,
                     constraint_type: "EveryNWeeks".into(),
                     reason: "Use Month repetition unit instead".into(),
                 });
@@ -587,7 +642,7 @@ fn validate_constraint_compatibility(periodicity: &Periodicity) -> Result<(), Va
             // Year-level repetition shouldn't have EveryNMonths
             if let Some(MonthConstraint::EveryNMonths(_)) = constraints.month_constraint {
                 return Err(ValidationError::IncompatibleConstraint {
-                    rep_unit: periodicity.rep_unit,
+                    rep_unit,
                     constraint_type: "EveryNMonths".into(),
                     reason: "Use Year repetition unit instead".into(),
                 });
@@ -629,6 +684,7 @@ fn validate_special_pattern(
         || constraints.week_constraint.is_some()
         || constraints.month_constraint.is_some()
         || constraints.year_constraint.is_some()
+        || !periodicity.alternatives.is_empty()
     {
         return Err(ValidationError::ConflictingConstraints {
             constraint1: "special_pattern".into(),
@@ -656,6 +712,85 @@ fn validate_special_pattern(
     Ok(())
 }
 
+// ========================================================================
+// COMPLETION-RELATIVE RECURRENCE VALIDATION
+// ========================================================================
+
+fn validate_after_completion(periodicity: &Periodicity) -> Result<(), ValidationError> {
+    let Some(rule) = periodicity.after_completion else {
+        return Ok(());
+    };
+    
+    if rule.interval == 0 {
+        return Err(ValidationError::InvalidValue {
+            field: "after_completion.interval".into(),
+            value: "0".into(),
+            reason: "Must be at least 1".into(),
+        });
+    }
+    if rule.unit == RepetitionUnit::None {
+        return Err(ValidationError::InvalidValue {
+            field: "after_completion.unit".into(),
+            value: format!("{:?}", rule.unit),
+            reason: "Must be Day, Week, Month or Year".into(),
+        });
+    }
+    
+    // Special patterns list their own dates, nothing to count from
+    if periodicity.special_pattern.is_some() {
+        return Err(ValidationError::ConflictingConstraints {
+            constraint1: "after_completion".into(),
+            constraint2: "special_pattern".into(),
+            reason: "Completion-relative recurrence needs a repetition unit, not fixed dates".into(),
+        });
+    }
+    
+    // Rolling patterns count from the calendar anchor, not the completion
+    let rolling = periodicity.constraint_sets().find_map(|constraints| {
+        match (&constraints.day_constraint, &constraints.week_constraint,
+               &constraints.month_constraint, &constraints.year_constraint) {
+            (Some(DayConstraint::EveryNDays(_)), _, _, _) => Some("EveryNDays"),
+            (_, Some(WeekConstraint::EveryNWeeks(_)), _, _) => Some("EveryNWeeks"),
+            (_, _, Some(MonthConstraint::EveryNMonths(_)), _) => Some("EveryNMonths"),
+            (_, _, _, Some(YearConstraint::EveryNYears(_))) => Some("EveryNYears"),
+            _ => None,
+        }
+    });
+    if let Some(rolling) = rolling {
+        return Err(ValidationError::ConflictingConstraints {
+            constraint1: "after_completion".into(),
+            constraint2: rolling.into(),
+            reason: "A rolling pattern already sets the interval between occurrences".into(),
+        });
+    }
+    
+    // The next due day must fall past the window holding the completion
+    let (days, weeks, months) = match periodicity.rep_unit {
+        RepetitionUnit::Week => (7, 1, 1),
+        RepetitionUnit::Month => (31, 5, 1),
+        RepetitionUnit::Year => (366, 53, 12),
+        _ => (1, 1, 1),
+    };
+    let minimum = match rule.unit {
+        RepetitionUnit::Day => days,
+        RepetitionUnit::Week => weeks,
+        RepetitionUnit::Month => months,
+        _ => 1,
+    };
+    if rule.interval < minimum {
+        return Err(ValidationError::InvalidValue {
+            field: "after_completion.interval".into(),
+            value: rule.interval.to_string(),
+            reason: format!(
+                "Must be at least {} {:?} units to reach past the {:?} window of the completion",
+                minimum, rule.unit, periodicity.rep_unit
+            ),
+        });
+    }
+    
+    Ok(())
+}
+
 // ========================================================================
 // TIMEFRAME VALIDATION
 // ========================================================================
@@ -673,6 +808,73 @@ fn validate_timeframe(
     Ok(())
 }
 
+// ========================================================================
+// EXCEPTIONS VALIDATION
+// ========================================================================
+
+fn validate_exceptions(periodicity: &Periodicity) -> Result<(), ValidationError> {
+    let exceptions = &periodicity.exceptions;
+    
+    let mut names = HashSet::new();
+    for calendar in &exceptions.holidays {
+        if calendar.name.trim().is_empty() {
+            return Err(ValidationError::InvalidValue {
+                field: "exceptions.holidays".into(),
+                value: calendar.name.clone(),
+                reason: "Holiday calendar name must not be empty".into(),
+            });
+        }
+        if !names.insert(calendar.name.as_str()) {
+            return Err(ValidationError::DuplicateValues {
+                field: "exceptions.holidays".into(),
+                reason: format!("Holiday calendar '{}' is listed more than once", calendar.name),
+            });
+        }
+    }
+    
+    if !exceptions.is_empty() && excludes_every_occurrence(periodicity, exceptions) {
+        let bound = if periodicity.special_pattern.is_some() { "special_pattern" } else { "timeframe" };
+        return Err(ValidationError::ConflictingConstraints {
+            constraint1: "exceptions".into(),
+            constraint2: bound.into(),
+            reason: "Every occurrence is excluded".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks if the exceptions remove every occurrence of a finite periodicity:
+/// the dates of a special pattern, or the matching days of a timeframe
+/// (a rolled occurrence isn't removed, it only moves)
+/// 
+/// Week constraints depend on the user's week start, which isn't known here,
+/// so those periodicities are only rejected when emptied for every week start.
+/// A periodicity with no occurrence to begin with isn't the exceptions' doing.
+fn excludes_every_occurrence(periodicity: &Periodicity, exceptions: &PeriodicityExceptions) -> bool {
+    if let Some(pattern) = &periodicity.special_pattern {
+        let mut dates = pattern.dates().iter().filter(|d| periodicity.is_within_timeframe(d)).peekable();
+        return dates.peek().is_some() && dates.all(|d| exceptions.roll(d.date_naive()).is_none());
+    }
+    
+    let Some((start, end)) = periodicity.timeframe else {
+        return false;
+    };
+    let unrestricted = Periodicity {
+        exceptions: PeriodicityExceptions::default(),
+        ..periodicity.clone()
+    };
+    let weekly = periodicity.constraint_sets().any(|constraints| constraints.week_constraint.is_some());
+    let week_starts: &[Weekday] = if weekly {
+        &[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun]
+    } else {
+        &[Weekday::Mon]
+    };
+    week_starts.iter().all(|week_start| {
+        let mut occurrences = unrestricted.occurrences_between(start, end, *week_start).peekable();
+        occurrences.peek().is_some() && occurrences.all(|d| exceptions.roll(d.date_naive()).is_none())
+    })
+}
+
 // ========================================================================
 // OCCURRENCE SETTINGS VALIDATION
 // ========================================================================
@@ -782,8 +984,9 @@ fn validate_rep_timing_settings(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{NaiveTime, Utc, Weekday};
-    use crate::domain::entities::task::periodicity::UniqueDate;
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+    use crate::domain::builders::PeriodicityBuilder;
+    use crate::domain::entities::task::periodicity::{HolidayCalendar, RollPolicy, UniqueDate};
     
     #[test]
     fn test_validate_repetition_none_requires_none_count() {
@@ -792,10 +995,13 @@ mod tests {
             rep_per_unit: Some(1),
             occurrence_settings: None,
             constraints: PeriodicityConstraints::default(),
+            alternatives: Vec::new(),
             timeframe: None,
             special_pattern: Some(SpecialPattern::Unique(UniqueDate {
                 date: Utc::now(),
             })),
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
@@ -809,8 +1015,11 @@ mod tests {
             rep_per_unit: None,
             occurrence_settings: None,
             constraints: PeriodicityConstraints::default(),
+            alternatives: Vec::new(),
             timeframe: None,
             special_pattern: None,
+            after_completion: None,
+            exceptions: PeriodicityExceptions::default(),
             reference_date: None,
         };
         
@@ -1088,4 +1297,158 @@ mod tests {
         // Should pass because we don't know the valid range
         assert!(validate_occurrence_settings(&Some(settings), None).is_ok());
     }
+
+    #[test]
+    fn test_validate_exceptions_removing_every_occurrence() {
+        let day = |month, day| NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+        let at = |month, day| Utc.with_ymd_and_hms(2026, month, day, 0, 0, 0).unwrap();
+
+        // The two Mondays of a bounded timeframe
+        let mondays = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon])
+            .between(at(1, 5), at(1, 19));
+        assert!(mondays.clone().except(vec![day(1, 5)]).build().is_ok());
+        match mondays.except(vec![day(1, 5), day(1, 12)]).build().unwrap_err() {
+            ValidationError::ConflictingConstraints { constraint1, constraint2, .. } => {
+                assert_eq!(constraint1, "exceptions");
+                assert_eq!(constraint2, "timeframe");
+            }
+            other => panic!("Expected ConflictingConstraints error, got {:?}", other),
+        }
+
+        // Without an end there's always a later Monday
+        let open_ended = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon])
+            .except(vec![day(1, 5), day(1, 12)])
+            .build();
+        assert!(open_ended.is_ok());
+
+        let holidays = HolidayCalendar::new("summer".to_string(), vec![day(6, 1)]).unwrap();
+        let custom = PeriodicityBuilder::new().custom_dates(vec![at(3, 1), at(6, 1)]).unwrap();
+        assert!(custom.clone().except(vec![day(3, 1)]).build().is_ok());
+        assert!(matches!(
+            custom.except(vec![day(3, 1)]).skip_holidays(holidays).build(),
+            Err(ValidationError::ConflictingConstraints { constraint2, .. }) if constraint2 == "special_pattern"
+        ));
+    }
+
+    #[test]
+    fn test_validate_exceptions_with_week_constraints_try_every_week_start() {
+        let january = |from: u32, to: u32| (from..=to)
+            .map(|day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap())
+            .collect::<Vec<_>>();
+        let first_week = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weeks_of_month(vec![1])
+            .between(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(), Utc.with_ymd_and_hms(2026, 1, 12, 0, 0, 0).unwrap());
+
+        // Empty for Monday weeks, but Thursday weeks still have Jan 1-4
+        assert!(first_week.clone().except(january(5, 11)).build().is_ok());
+        assert!(first_week.except(january(1, 11)).build().is_err());
+    }
+
+    #[test]
+    fn test_validate_exceptions_holiday_names() {
+        let holidays = |name: &str| HolidayCalendar {
+            name: name.to_string(),
+            dates: vec![NaiveDate::from_ymd_opt(2026, 5, 1).unwrap()],
+        };
+        let daily = || PeriodicityBuilder::new().daily(1);
+
+        assert!(daily().skip_holidays(holidays("a")).skip_holidays(holidays("b")).build().is_ok());
+        assert!(matches!(
+            daily().skip_holidays(holidays("a")).skip_holidays(holidays("a")).build(),
+            Err(ValidationError::DuplicateValues { .. })
+        ));
+        assert!(matches!(
+            daily().skip_holidays(holidays(" ")).build(),
+            Err(ValidationError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_exceptions_rolled_occurrences_are_kept() {
+        let may_day = NaiveDate::from_ymd_opt(2026, 5, 1).unwrap();
+        let holidays = HolidayCalendar::new("fr-public".to_string(), vec![may_day]).unwrap();
+        let only_may_day = PeriodicityBuilder::new()
+            .daily(1)
+            .between(Utc.with_ymd_and_hms(2026, 5, 1, 0, 0, 0).unwrap(), Utc.with_ymd_and_hms(2026, 5, 2, 0, 0, 0).unwrap())
+            .skip_holidays(holidays);
+
+        assert!(only_may_day.clone().build().is_err());
+        assert!(only_may_day.with_roll_policy(RollPolicy::NextBusinessDay).build().is_ok());
+    }
+
+    #[test]
+    fn test_validate_after_completion() {
+        let invalid_field = |builder: PeriodicityBuilder| match builder.build() {
+            Err(ValidationError::InvalidValue { field, .. }) => field,
+            other => panic!("Expected InvalidValue error, got {:?}", other),
+        };
+        let conflict = |builder: PeriodicityBuilder| match builder.build() {
+            Err(ValidationError::ConflictingConstraints { constraint2, .. }) => constraint2,
+            other => panic!("Expected ConflictingConstraints error, got {:?}", other),
+        };
+
+        assert!(PeriodicityBuilder::new().daily(1).after_completion(4, RepetitionUnit::Day).build().is_ok());
+        assert!(PeriodicityBuilder::new().weekly(1).after_completion(10, RepetitionUnit::Day).build().is_ok());
+        assert!(PeriodicityBuilder::new().monthly(1).after_completion(1, RepetitionUnit::Month).build().is_ok());
+        assert_eq!(invalid_field(PeriodicityBuilder::new().daily(1).after_completion(0, RepetitionUnit::Day)), "after_completion.interval");
+        assert_eq!(invalid_field(PeriodicityBuilder::new().daily(1).after_completion(2, RepetitionUnit::None)), "after_completion.unit");
+
+        // The next due day would still be inside the completion's window
+        assert_eq!(invalid_field(PeriodicityBuilder::new().weekly(1).after_completion(3, RepetitionUnit::Day)), "after_completion.interval");
+        assert_eq!(invalid_field(PeriodicityBuilder::new().yearly(1).after_completion(6, RepetitionUnit::Month)), "after_completion.interval");
+
+        let date = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        assert_eq!(conflict(PeriodicityBuilder::new().unique(date).after_completion(1, RepetitionUnit::Day)), "special_pattern");
+        assert_eq!(conflict(PeriodicityBuilder::new().daily(1).every_n_days(3).after_completion(4, RepetitionUnit::Day)), "EveryNDays");
+    }
+
+    #[test]
+    fn test_validate_weeks_of_year() {
+        let daily = || PeriodicityBuilder::new().daily(1);
+        assert!(daily().on_iso_weeks(vec![1, 53]).build().is_ok());
+        assert!(daily().on_weeks_of_year(vec![53]).build().is_ok());
+        assert!(daily().in_even_iso_weeks().build().is_ok());
+        
+        assert!(matches!(
+            daily().on_iso_weeks(vec![54]).build(),
+            Err(ValidationError::OutOfRange { field, .. }) if field == "SpecificIsoWeeks",
+        ));
+        assert!(matches!(daily().on_weeks_of_year(vec![]).build(), Err(ValidationError::EmptyCollection { .. })));
+        assert!(matches!(daily().on_weeks_of_year(vec![2, 2]).build(), Err(ValidationError::DuplicateValues { .. })));
+    }
+
+    #[test]
+    fn test_validate_alternatives() {
+        let union = || PeriodicityBuilder::new().weekly(1).on_weekdays(vec![Weekday::Mon]).or();
+        assert!(union().on_month_days(vec![1]).build().is_ok());
+
+        // Each set is validated on its own
+        assert!(matches!(union().on_weekdays(vec![]).build(), Err(ValidationError::EmptyCollection { .. })));
+        assert!(matches!(
+            union().every_n_days(3).build(),
+            Err(ValidationError::IncompatibleConstraint { rep_unit: RepetitionUnit::Week, .. }),
+        ));
+
+        // A set without constraints matches every day
+        assert!(matches!(union().build(), Err(ValidationError::EmptyCollection { field, .. }) if field == "alternatives"));
+        assert!(matches!(
+            union().on_weekdays(vec![Weekday::Mon]).build(),
+            Err(ValidationError::DuplicateValues { field, .. }) if field == "alternatives",
+        ));
+
+        let date = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        assert!(matches!(
+            PeriodicityBuilder::new().unique(date).or().on_weekdays(vec![Weekday::Mon]).build(),
+            Err(ValidationError::ConflictingConstraints { .. }),
+        ));
+        assert!(matches!(
+            union().every_n_weeks(2).after_completion(2, RepetitionUnit::Week).build(),
+            Err(ValidationError::ConflictingConstraints { constraint2, .. }) if constraint2 == "EveryNWeeks",
+        ));
+    }
 }
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use chrono::NaiveDate;
+use crate::domain::entities::task::HolidayCalendar;
+
+// ========================================================================
+// HOLIDAY FILES
+// Named holiday calendars from a local text file
+// ========================================================================
+//
+// Format: a "[name]" header starts each calendar, followed by one date per
+// line (YYYY-MM-DD); the rest of a date line is a label and is ignored.
+// Blank lines and lines starting with '#' are skipped.
+//
+//   # Public holidays
+//   [fr-public]
+//   2026-01-01  Jour de l'an
+//   2026-05-01  Fête du travail
+//
+//   [office-closed]
+//   2026-12-24
+//
+// A name may appear only once per file.
+
+#[derive(Debug)]
+pub enum HolidayFileError {
+    /// The file couldn't be read
+    Read { path: PathBuf, error: io::Error },
+
+    /// A line isn't a header, a date or a comment (1-based line)
+    Syntax { line: usize, reason: String },
+}
+
+impl fmt::Display for HolidayFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HolidayFileError::Read { path, error } => {
+                write!(f, "Can't read holiday file {}: {}", path.display(), error)
+            }
+            HolidayFileError::Syntax { line, reason } => {
+                write!(f, "Invalid holiday file at line {}: {}", line, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HolidayFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HolidayFileError::Read { error, .. } => Some(error),
+            HolidayFileError::Syntax { .. } => None,
+        }
+    }
+}
+
+/// Reads the holiday calendars of a file, in file order
+pub fn load_holiday_calendars(path: &Path) -> Result<Vec<HolidayCalendar>, HolidayFileError> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| HolidayFileError::Read { path: path.to_path_buf(), error })?;
+    parse_holiday_calendars(&text)
+}
+
+/// Parses holiday calendars (see the format above)
+pub fn parse_holiday_calendars(text: &str) -> Result<Vec<HolidayCalendar>, HolidayFileError> {
+    let mut calendars: Vec<HolidayCalendar> = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let syntax = |reason: String| HolidayFileError::Syntax { line: index + 1, reason };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header.strip_suffix(']')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .ok_or_else(|| syntax(format!("expected a [name] header, got '{}'", line)))?;
+            if calendars.iter().any(|calendar| calendar.name == name) {
+                return Err(syntax(format!("calendar '{}' is defined twice", name)));
+            }
+            let calendar = HolidayCalendar::new(name.to_string(), Vec::new()).map_err(|error| syntax(error.to_string()))?;
+            calendars.push(calendar);
+            continue;
+        }
+
+        let word = line.split_whitespace().next().unwrap_or(line);
+        let date = NaiveDate::parse_from_str(word, "%Y-%m-%d")
+            .map_err(|_| syntax(format!("expected a date as YYYY-MM-DD, got '{}'", word)))?;
+        match calendars.last_mut() {
+            Some(calendar) => calendar.dates.push(date),
+            None => return Err(syntax("date before the first [name] header".to_string())),
+        }
+    }
+
+    for calendar in &mut calendars {
+        calendar.dates.sort();
+        calendar.dates.dedup();
+    }
+    Ok(calendars)
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse_calendars() {
+        let text = "\
+# Public holidays
+[fr-public]
+2026-05-01  Fête du travail
+2026-01-01  Jour de l'an
+
+[ office-closed ]
+2026-12-24
+2026-12-24
+";
+        let calendars = parse_holiday_calendars(text).unwrap();
+        assert_eq!(calendars.len(), 2);
+        assert_eq!(calendars[0].name, "fr-public");
+        assert_eq!(calendars[0].dates, vec![day(2026, 1, 1), day(2026, 5, 1)]);
+        assert_eq!(calendars[1].name, "office-closed");
+        assert_eq!(calendars[1].dates, vec![day(2026, 12, 24)]);
+    }
+
+    #[test]
+    fn test_syntax_errors_name_the_line() {
+        let line_of = |text: &str| match parse_holiday_calendars(text) {
+            Err(HolidayFileError::Syntax { line, .. }) => line,
+            other => panic!("expected a syntax error, got {:?}", other),
+        };
+        assert_eq!(line_of("2026-01-01\n"), 1);
+        assert_eq!(line_of("[a]\n2026-01-01\n01/05/2026\n"), 3);
+        assert_eq!(line_of("[a]\n\n[a]\n"), 3);
+        assert_eq!(line_of("[]\n"), 1);
+        assert_eq!(line_of("[a\n"), 1);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let error = load_holiday_calendars(Path::new("/nonexistent/holidays.txt")).unwrap_err();
+        assert!(matches!(error, HolidayFileError::Read { ref error, .. } if error.kind() == io::ErrorKind::NotFound));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod writer;
+pub use writer::{write_calendar, CalendarTask, IcsCalendar};
+
+pub mod reader;
+pub use reader::{read_calendar, IcsImport, IcsReadError, ImportedTask, SkipReason, SkippedComponent};
@@ -0,0 +1,722 @@
+use std::fmt;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use crate::domain::builders::PeriodicityBuilder;
+use crate::domain::entities::task::periodicity::OccurrenceTimingSettings;
+use crate::domain::entities::task::periodicity_rrule::{parse_date_time, written_date};
+use crate::domain::entities::task::{
+    Periodicity, RRuleImportError, RecurrenceRule, Task, TaskPriority, TaskValidationError,
+};
+use crate::domain::entities::user::User;
+use crate::domain::validators::periodicity_validator::ValidationError;
+
+// ========================================================================
+// ICS READER
+// VTODO / VEVENT components of an RFC 5545 file as Tasks
+// ========================================================================
+//
+// Each VTODO or VEVENT becomes a task:
+// - SUMMARY → title, DESCRIPTION → description
+// - PRIORITY (1 highest .. 9 lowest, 0 undefined) → TaskPriority
+// - RRULE (+ DTSTART) → `Periodicity::from_rrule_for`, on the user's calendar
+// - RDATE (+ DTSTART) → SpecialPattern::Custom; DTSTART (or DUE) alone → Unique
+// - DURATION, or DTEND - DTSTART → OccurrenceTimingSettings::duration (up to a day)
+// - STATUS:COMPLETED / CANCELLED → archived
+// - EXDATE: removed from RDATE sets; excluded days (`except`) of RRULE tasks:
+//   the user's logical day of a date-time (`User::logical_date`), a DATE as written
+// Other components (VJOURNAL, ...), single-instance overrides (RECURRENCE-ID)
+// and components that don't map are skipped, each with its reason.
+
+/// A task read from a calendar component
+#[derive(Debug, Clone)]
+pub struct ImportedTask {
+    /// UID of the component, if any
+    pub uid: Option<String>,
+
+    pub task: Task,
+}
+
+/// A component that wasn't imported
+#[derive(Debug, Clone)]
+pub struct SkippedComponent {
+    /// Component name ("VEVENT", "VJOURNAL", ...)
+    pub kind: String,
+
+    /// Line of its BEGIN (1-based, before unfolding)
+    pub line: usize,
+
+    pub summary: Option<String>,
+
+    pub reason: SkipReason,
+}
+
+/// What `read_calendar` found
+#[derive(Debug, Clone)]
+pub struct IcsImport {
+    pub tasks: Vec<ImportedTask>,
+    pub skipped: Vec<SkippedComponent>,
+}
+
+/// Reads the VTODO and VEVENT components of an iCalendar file
+///
+/// Rules and excluded days are read on the user's calendar (see
+/// `Periodicity::from_rrule_for`).
+/// Fails only when the file isn't an iCalendar object; components that can't
+/// be imported are reported in `skipped`.
+pub fn read_calendar(text: &str, user: &User) -> Result<IcsImport, IcsReadError> {
+    let mut import = IcsImport { tasks: Vec::new(), skipped: Vec::new() };
+    for component in parse_components(text)? {
+        match import_component(&component, user) {
+            Ok(task) => import.tasks.push(task),
+            Err(reason) => import.skipped.push(SkippedComponent {
+                kind: component.kind.clone(),
+                line: component.line,
+                summary: component.value("SUMMARY").map(unescape_text),
+                reason,
+            }),
+        }
+    }
+    Ok(import)
+}
+
+// ========================================================================
+// ERRORS
+// ========================================================================
+
+/// The file isn't an iCalendar object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsReadError {
+    /// 1-based line where reading stopped
+    pub line: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for IcsReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid iCalendar file at line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for IcsReadError {}
+
+/// Why a component was skipped
+#[derive(Debug, Clone, PartialEq)]
+pub enum SkipReason {
+    /// Not a VTODO or VEVENT
+    NotATask,
+
+    /// Changes one instance of a recurring component (RECURRENCE-ID)
+    InstanceOverride,
+
+    MissingSummary,
+
+    /// No DTSTART, DUE, RRULE or RDATE: nothing to schedule
+    NoDate,
+
+    /// A property that doesn't parse, or that a task can't represent
+    Property { name: String, reason: String },
+
+    /// RRULE that doesn't map onto a periodicity
+    Rule(RRuleImportError),
+
+    Task(TaskValidationError),
+
+    Periodicity(ValidationError),
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkipReason::NotATask => write!(f, "not a task or event"),
+            SkipReason::InstanceOverride => write!(f, "changes a single instance (RECURRENCE-ID)"),
+            SkipReason::MissingSummary => write!(f, "no SUMMARY for the title"),
+            SkipReason::NoDate => write!(f, "no DTSTART, DUE, RRULE or RDATE"),
+            SkipReason::Property { name, reason } => write!(f, "{}: {}", name, reason),
+            SkipReason::Rule(error) => write!(f, "{}", error),
+            SkipReason::Task(error) => write!(f, "{}", error),
+            SkipReason::Periodicity(error) => write!(f, "Invalid periodicity: {}", error),
+        }
+    }
+}
+
+impl From<RRuleImportError> for SkipReason {
+    fn from(error: RRuleImportError) -> Self {
+        SkipReason::Rule(error)
+    }
+}
+
+impl From<TaskValidationError> for SkipReason {
+    fn from(error: TaskValidationError) -> Self {
+        SkipReason::Task(error)
+    }
+}
+
+impl From<ValidationError> for SkipReason {
+    fn from(error: ValidationError) -> Self {
+        SkipReason::Periodicity(error)
+    }
+}
+
+fn property_error(name: &str, reason: impl Into<String>) -> SkipReason {
+    SkipReason::Property { name: name.to_string(), reason: reason.into() }
+}
+
+// ========================================================================
+// COMPONENT → TASK
+// ========================================================================
+
+fn import_component(component: &Component, user: &User) -> Result<ImportedTask, SkipReason> {
+    if component.kind != "VTODO" && component.kind != "VEVENT" {
+        return Err(SkipReason::NotATask);
+    }
+    if component.get("RECURRENCE-ID").is_some() {
+        return Err(SkipReason::InstanceOverride);
+    }
+    let title = component.value("SUMMARY").map(unescape_text).ok_or(SkipReason::MissingSummary)?;
+
+    // ── Dates ───────────────────────────────────────────────
+    let start_property = component.get("DTSTART").or_else(|| component.get("DUE"));
+    let start = match start_property {
+        Some(property) => Some(property.date_time()?),
+        None => None,
+    };
+    let all_day = start_property.and_then(|property| property.param("VALUE")).is_some_and(|value| value.eq_ignore_ascii_case("DATE"));
+    let rdates = component.date_list("RDATE")?;
+    let exdates = component.date_list("EXDATE")?;
+
+    let mut periodicity = match component.get("RRULE") {
+        Some(rrule) => {
+            if !rdates.is_empty() {
+                return Err(property_error("RDATE", "extra dates beside an RRULE can't be represented"));
+            }
+            let rule = RecurrenceRule {
+                dtstart: start,
+                start_date: start_property.and_then(|property| written_date(&property.value)),
+                rrule: rrule.value.clone(),
+            };
+            let mut periodicity = Periodicity::from_rrule_for(&rule, user)?;
+            periodicity.exceptions.dates = component.excluded_days(user)?;
+            periodicity.validate()?;
+            periodicity
+        }
+        None => {
+            let mut dates: Vec<_> = start.into_iter().chain(rdates).collect();
+            if dates.is_empty() {
+                return Err(SkipReason::NoDate);
+            }
+            dates.retain(|date| !exdates.contains(date));
+            match dates.as_slice() {
+                [] => return Err(property_error("EXDATE", "excludes every date")),
+                [date] => PeriodicityBuilder::new().unique(*date).build()?,
+                _ => PeriodicityBuilder::new().custom_dates(dates)?.build()?,
+            }
+        }
+    };
+
+    // ── Duration ────────────────────────────────────────────
+    let duration = match (component.value("DURATION"), component.get("DTEND"), start) {
+        (Some(value), _, _) => Some(parse_duration(value).ok_or_else(|| property_error("DURATION", format!("invalid duration '{}'", value)))?),
+        (None, Some(end), Some(start)) => Some(end.date_time()? - start),
+        _ => None,
+    };
+    // All-day and multi-day events keep no duration
+    let minutes = duration.filter(|_| !all_day).map(|d| d.num_minutes()).filter(|m| (1..=1440).contains(m));
+    if let Some(minutes) = minutes {
+        periodicity.occurrence_settings = Some(OccurrenceTimingSettings {
+            duration: Some(minutes as u16),
+            not_before: None,
+            best_before: None,
+            rep_timing_settings: None,
+        });
+        periodicity.validate()?;
+    }
+
+    // ── Task ────────────────────────────────────────────────
+    let mut task = Task::new(title, periodicity)?;
+    task.set_description(component.value("DESCRIPTION").map(unescape_text))?;
+    if let Some(value) = component.value("PRIORITY") {
+        let level = value.trim().parse::<u8>().ok().filter(|level| *level <= 9)
+            .ok_or_else(|| property_error("PRIORITY", format!("expected 0 to 9, got '{}'", value)))?;
+        task.set_priority(priority(level));
+    }
+    if matches!(component.value("STATUS"), Some("COMPLETED" | "CANCELLED")) {
+        task.archive();
+    }
+
+    Ok(ImportedTask { uid: component.value("UID").map(unescape_text), task })
+}
+
+/// RFC 5545 PRIORITY onto TaskPriority (the export writes 1, 3, 5 and 9)
+fn priority(level: u8) -> TaskPriority {
+    match level {
+        1 => TaskPriority::Urgent,
+        2..=4 => TaskPriority::High,
+        6..=9 => TaskPriority::Low,
+        // 0 is undefined
+        _ => TaskPriority::Medium,
+    }
+}
+
+/// A DURATION value ("PT1H30M", "P1D", "P2W"); None when malformed or negative
+fn parse_duration(value: &str) -> Option<Duration> {
+    let rest = value.trim().strip_prefix('+').unwrap_or(value.trim()).strip_prefix('P')?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            _ => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match (c, in_time) {
+                    ('W', false) => Duration::weeks(n),
+                    ('D', false) => Duration::days(n),
+                    ('H', true) => Duration::hours(n),
+                    ('M', true) => Duration::minutes(n),
+                    ('S', true) => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+    number.is_empty().then_some(total)
+}
+
+// ========================================================================
+// CONTENT LINES
+// ========================================================================
+
+/// A top-level component of the VCALENDAR, with its own properties
+/// (those of nested components like VALARM are left out)
+struct Component {
+    kind: String,
+    line: usize,
+    properties: Vec<Property>,
+}
+
+struct Property {
+    name: String,
+    /// Raw parameters ("TZID=Europe/Paris")
+    params: Vec<String>,
+    value: String,
+}
+
+impl Component {
+    fn get(&self, name: &str) -> Option<&Property> {
+        self.properties.iter().find(|property| property.name == name)
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.get(name).map(|property| property.value.as_str())
+    }
+
+    /// Days excluded by EXDATE on the user's calendar (sorted, unique)
+    ///
+    /// A date-time (UTC, TZID or floating) excludes the user's logical day of
+    /// its instant, the day an occurrence at that instant matches on; a DATE
+    /// excludes itself.
+    fn excluded_days(&self, user: &User) -> Result<Vec<NaiveDate>, SkipReason> {
+        let mut days = Vec::new();
+        for property in self.properties.iter().filter(|property| property.name == "EXDATE") {
+            for value in property.value.split(',') {
+                let instant = parse_date_time("EXDATE", &property.param_refs(), value)?;
+                days.push(if value.trim().len() == 8 { instant.date_naive() } else { user.logical_date(&instant) });
+            }
+        }
+        days.sort();
+        days.dedup();
+        Ok(days)
+    }
+
+    /// Dates of every `name` property (RDATE and EXDATE may repeat)
+    fn date_list(&self, name: &str) -> Result<Vec<DateTime<Utc>>, SkipReason> {
+        let mut dates = Vec::new();
+        for property in self.properties.iter().filter(|property| property.name == name) {
+            if property.param("VALUE").is_some_and(|value| value.eq_ignore_ascii_case("PERIOD")) {
+                return Err(property_error(name, "periods are not supported"));
+            }
+            for value in property.value.split(',') {
+                dates.push(parse_date_time(name, &property.param_refs(), value)?);
+            }
+        }
+        Ok(dates)
+    }
+}
+
+impl Property {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            key.eq_ignore_ascii_case(name).then_some(value)
+        })
+    }
+
+    fn param_refs(&self) -> Vec<&str> {
+        self.params.iter().map(String::as_str).collect()
+    }
+
+    fn date_time(&self) -> Result<DateTime<Utc>, SkipReason> {
+        Ok(parse_date_time(&self.name, &self.param_refs(), &self.value)?)
+    }
+}
+
+/// Splits the VCALENDAR into its top-level components
+fn parse_components(text: &str) -> Result<Vec<Component>, IcsReadError> {
+    let mut components = Vec::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut current: Option<Component> = None;
+    let mut calendars = 0;
+
+    for (line, content) in unfold(text) {
+        let error = |reason: String| IcsReadError { line, reason };
+        let property = parse_line(&content).ok_or_else(|| error(format!("expected NAME:VALUE, got '{}'", content)))?;
+
+        match property.name.as_str() {
+            "BEGIN" => {
+                let kind = property.value.trim().to_ascii_uppercase();
+                match stack.len() {
+                    0 if kind != "VCALENDAR" => return Err(error(format!("expected BEGIN:VCALENDAR, got BEGIN:{}", kind))),
+                    0 => calendars += 1,
+                    1 => current = Some(Component { kind: kind.clone(), line, properties: Vec::new() }),
+                    _ => {}
+                }
+                stack.push(kind);
+            }
+            "END" => {
+                let kind = property.value.trim().to_ascii_uppercase();
+                match stack.pop() {
+                    Some(open) if open == kind => {}
+                    Some(open) => return Err(error(format!("END:{} closes BEGIN:{}", kind, open))),
+                    None => return Err(error(format!("END:{} without BEGIN", kind))),
+                }
+                if stack.len() == 1 {
+                    components.extend(current.take());
+                }
+            }
+            _ if stack.is_empty() => return Err(error("content outside BEGIN:VCALENDAR".to_string())),
+            _ if stack.len() == 2 => {
+                if let Some(component) = current.as_mut() {
+                    component.properties.push(property);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match stack.last() {
+        Some(open) => Err(IcsReadError { line: text.lines().count(), reason: format!("BEGIN:{} is never closed", open) }),
+        None if calendars == 0 => Err(IcsReadError { line: 1, reason: "no VCALENDAR".to_string() }),
+        None => Ok(components),
+    }
+}
+
+/// Logical lines with the number of their first physical line: lines starting
+/// with a space or tab continue the previous one
+fn unfold(text: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (index, physical) in text.lines().enumerate() {
+        let physical = physical.strip_suffix('\r').unwrap_or(physical);
+        match (physical.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, last))) => last.push_str(continuation),
+            _ if physical.trim().is_empty() => {}
+            _ => lines.push((index + 1, physical.to_string())),
+        }
+    }
+    lines
+}
+
+/// "NAME;PARAM=VALUE;...:value", with quoted parameter values
+fn parse_line(line: &str) -> Option<Property> {
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(i, c)| {
+        if c == '"' {
+            quoted = !quoted;
+        }
+        (c == ':' && !quoted).then_some(i)
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+    let mut params = Vec::new();
+    let mut name = None;
+    let mut quoted = false;
+    let mut start = 0;
+    for (i, c) in head.char_indices().chain(std::iter::once((head.len(), ';'))) {
+        match c {
+            '"' => quoted = !quoted,
+            ';' if !quoted => {
+                let piece = &head[start..i];
+                match name {
+                    None => name = Some(piece.trim().to_ascii_uppercase()),
+                    Some(_) => params.push(piece.replace('"', "")),
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let name = name.filter(|name| !name.is_empty())?;
+    Some(Property { name, params, value: value.to_string() })
+}
+
+/// TEXT value with its escapes resolved
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::task::{SpecialPattern, TaskStatus};
+    use crate::domain::entities::user::{PasswordHash, Timezone};
+    use chrono::{TimeZone, Weekday};
+
+    fn user(timezone: &str) -> User {
+        User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new(timezone.to_string()).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap()
+    }
+
+    fn calendar(components: &str) -> String {
+        format!("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//test//EN\r\n{}END:VCALENDAR\r\n", components)
+    }
+
+    #[test]
+    fn test_read_recurring_todo() {
+        let text = calendar(concat!(
+            "BEGIN:VTODO\r\n",
+            "UID:water@example.com\r\n",
+            "SUMMARY:Water the plants\\, all of them\r\n",
+            "DESCRIPTION:Balcony first\\nthen the kitchen\r\n",
+            "PRIORITY:2\r\n",
+            "DTSTART:20260105T080000Z\r\n",
+            "DURATION:PT15M\r\n",
+            "RRULE:FREQ=WEEKLY;BYDAY=MO,TH\r\n",
+            "EXDATE:20260108T080000Z,20260115T080000Z\r\n",
+            "BEGIN:VALARM\r\n",
+            "ACTION:DISPLAY\r\n",
+            "DESCRIPTION:Reminder\r\n",
+            "END:VALARM\r\n",
+            "END:VTODO\r\n",
+        ));
+        let import = read_calendar(&text, &user("Etc/UTC")).unwrap();
+        assert!(import.skipped.is_empty(), "{:?}", import.skipped);
+        assert_eq!(import.tasks.len(), 1);
+
+        let imported = &import.tasks[0];
+        assert_eq!(imported.uid.as_deref(), Some("water@example.com"));
+        let task = &imported.task;
+        assert_eq!(task.title(), "Water the plants, all of them");
+        assert_eq!(task.description(), Some("Balcony first\nthen the kitchen"));
+        assert_eq!(task.priority(), TaskPriority::High);
+        assert_eq!(task.status(), TaskStatus::Active);
+
+        let periodicity = task.periodicity();
+        assert!(periodicity.matches_constraints(&utc(2026, 1, 12, 8), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&utc(2026, 1, 13, 8), Weekday::Mon));
+        // EXDATEs
+        assert!(!periodicity.matches_constraints(&utc(2026, 1, 8, 8), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&utc(2026, 1, 15, 8), Weekday::Mon));
+        assert!(periodicity.matches_constraints(&utc(2026, 1, 22, 8), Weekday::Mon));
+        assert_eq!(periodicity.occurrence_settings.as_ref().and_then(|s| s.duration), Some(15));
+    }
+
+    #[test]
+    fn test_utc_exdate_excludes_the_users_day() {
+        // 23:00 UTC is already the next morning in Tokyo
+        let text = calendar(concat!(
+            "BEGIN:VEVENT\r\n",
+            "SUMMARY:Call home\r\n",
+            "DTSTART:20260105T230000Z\r\n",
+            "RRULE:FREQ=DAILY\r\n",
+            "EXDATE:20260107T230000Z\r\n",
+            "END:VEVENT\r\n",
+        ));
+        let day = |d: u32| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+
+        let tokyo = user("Asia/Tokyo");
+        let import = read_calendar(&text, &tokyo).unwrap();
+        let periodicity = import.tasks[0].task.periodicity();
+        assert_eq!(periodicity.exceptions.dates, vec![day(8)]);
+        assert!(periodicity.matches_constraints_for(&utc(2026, 1, 6, 23), &tokyo));
+        assert!(!periodicity.matches_constraints_for(&utc(2026, 1, 7, 23), &tokyo));
+        assert!(periodicity.matches_constraints_for(&utc(2026, 1, 8, 23), &tokyo));
+
+        let import = read_calendar(&text, &user("Etc/UTC")).unwrap();
+        assert_eq!(import.tasks[0].task.periodicity().exceptions.dates, vec![day(7)]);
+    }
+
+    #[test]
+    fn test_tzid_exdate_excludes_the_users_day() {
+        // 20:00 in New York is 02:00 the next day in Paris
+        let text = calendar(concat!(
+            "BEGIN:VTODO\r\n",
+            "SUMMARY:Review\r\n",
+            "DTSTART;TZID=America/New_York:20260105T200000\r\n",
+            "RRULE:FREQ=DAILY\r\n",
+            "EXDATE;TZID=America/New_York:20260107T200000,20260109T200000\r\n",
+            "EXDATE;VALUE=DATE:20260112\r\n",
+            "END:VTODO\r\n",
+        ));
+        let day = |d: u32| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+
+        let import = read_calendar(&text, &user("America/New_York")).unwrap();
+        assert_eq!(import.tasks[0].task.periodicity().exceptions.dates, vec![day(7), day(9), day(12)]);
+
+        let import = read_calendar(&text, &user("Europe/Paris")).unwrap();
+        assert_eq!(import.tasks[0].task.periodicity().exceptions.dates, vec![day(8), day(10), day(12)]);
+    }
+
+    #[test]
+    fn test_read_rdates_as_custom_dates() {
+        let text = calendar(concat!(
+            "BEGIN:VEVENT\r\n",
+            "SUMMARY:Dentist\r\n",
+            "DTSTART;VALUE=DATE:20260302\r\n",
+            "DTEND;VALUE=DATE:20260303\r\n",
+            "RDATE;VALUE=DATE:20260601,20260901\r\n",
+            "RDATE;VALUE=DATE:20261201\r\n",
+            "EXDATE;VALUE=DATE:20260901\r\n",
+            "PRIORITY:9\r\n",
+            "STATUS:CANCELLED\r\n",
+            "END:VEVENT\r\n",
+        ));
+        let import = read_calendar(&text, &user("Etc/UTC")).unwrap();
+        let task = &import.tasks[0].task;
+        match &task.periodicity().special_pattern {
+            Some(SpecialPattern::Custom(custom)) => assert_eq!(
+                custom.dates,
+                vec![utc(2026, 3, 2, 0), utc(2026, 6, 1, 0), utc(2026, 12, 1, 0)]
+            ),
+            other => panic!("expected custom dates, got {:?}", other),
+        }
+        // All-day: no duration
+        assert!(task.periodicity().occurrence_settings.is_none());
+        assert_eq!(task.priority(), TaskPriority::Low);
+        assert_eq!(task.status(), TaskStatus::Archived);
+        assert!(task.periodicity().exceptions.is_empty());
+    }
+
+    #[test]
+    fn test_read_single_date_and_folded_lines() {
+        let text = calendar(concat!(
+            "BEGIN:VTODO\r\n",
+            "SUMMARY:File the \r\n",
+            " tax return\r\n",
+            "DUE;TZID=Europe/Paris:20260515T120000\r\n",
+            "END:VTODO\r\n",
+        ));
+        let import = read_calendar(&text, &user("Etc/UTC")).unwrap();
+        let task = &import.tasks[0].task;
+        assert_eq!(task.title(), "File the tax return");
+        assert_eq!(task.priority(), TaskPriority::Medium);
+        match &task.periodicity().special_pattern {
+            Some(SpecialPattern::Unique(unique)) => assert_eq!(unique.date, utc(2026, 5, 15, 10)),
+            other => panic!("expected a unique date, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_skipped_components_and_reasons() {
+        let text = calendar(concat!(
+            "BEGIN:VJOURNAL\r\n",
+            "SUMMARY:Notes\r\n",
+            "END:VJOURNAL\r\n",
+            "BEGIN:VEVENT\r\n",
+            "SUMMARY:Standup\r\n",
+            "RECURRENCE-ID:20260106T090000Z\r\n",
+            "DTSTART:20260106T100000Z\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VTODO\r\n",
+            "DTSTART:20260106T100000Z\r\n",
+            "END:VTODO\r\n",
+            "BEGIN:VTODO\r\n",
+            "SUMMARY:Someday\r\n",
+            "END:VTODO\r\n",
+            "BEGIN:VEVENT\r\n",
+            "SUMMARY:Every hour\r\n",
+            "DTSTART:20260106T100000Z\r\n",
+            "RRULE:FREQ=HOURLY\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "SUMMARY:Cancelled twice\r\n",
+            "DTSTART:20260106T100000Z\r\n",
+            "EXDATE:20260106T100000Z\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "SUMMARY:Bad priority\r\n",
+            "DTSTART:20260106T100000Z\r\n",
+            "PRIORITY:high\r\n",
+            "END:VEVENT\r\n",
+        ));
+        let import = read_calendar(&text, &user("Etc/UTC")).unwrap();
+        assert!(import.tasks.is_empty());
+
+        let skipped: Vec<_> = import.skipped.iter()
+            .map(|s| (s.kind.as_str(), s.line, s.summary.as_deref()))
+            .collect();
+        assert_eq!(skipped, vec![
+            ("VJOURNAL", 4, Some("Notes")),
+            ("VEVENT", 7, Some("Standup")),
+            ("VTODO", 12, None),
+            ("VTODO", 15, Some("Someday")),
+            ("VEVENT", 18, Some("Every hour")),
+            ("VEVENT", 23, Some("Cancelled twice")),
+            ("VEVENT", 28, Some("Bad priority")),
+        ]);
+
+        let reasons: Vec<_> = import.skipped.iter().map(|s| &s.reason).collect();
+        assert_eq!(reasons[0], &SkipReason::NotATask);
+        assert_eq!(reasons[1], &SkipReason::InstanceOverride);
+        assert_eq!(reasons[2], &SkipReason::MissingSummary);
+        assert_eq!(reasons[3], &SkipReason::NoDate);
+        assert!(matches!(reasons[4], SkipReason::Rule(RRuleImportError::Unsupported { .. })));
+        assert!(matches!(reasons[5], SkipReason::Property { name, .. } if name == "EXDATE"));
+        assert_eq!(reasons[6].to_string(), "PRIORITY: expected 0 to 9, got 'high'");
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        assert_eq!(read_calendar("", &user("Etc/UTC")).unwrap_err().line, 1);
+        assert_eq!(read_calendar("BEGIN:VTODO\r\nEND:VTODO\r\n", &user("Etc/UTC")).unwrap_err().line, 1);
+
+        let unbalanced = "BEGIN:VCALENDAR\r\nBEGIN:VTODO\r\nSUMMARY:x\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let error = read_calendar(unbalanced, &user("Etc/UTC")).unwrap_err();
+        assert_eq!(error.line, 4);
+        assert_eq!(error.reason, "END:VEVENT closes BEGIN:VTODO");
+
+        let unclosed = "BEGIN:VCALENDAR\r\nBEGIN:VTODO\r\n";
+        assert!(read_calendar(unclosed, &user("Etc/UTC")).unwrap_err().reason.contains("never closed"));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1DT2H"), Some(Duration::hours(26)));
+        assert_eq!(parse_duration("P2W"), Some(Duration::weeks(2)));
+        assert_eq!(parse_duration("-PT15M"), None);
+        assert_eq!(parse_duration("PT15"), None);
+        assert_eq!(parse_duration("P1H"), None);
+    }
+}
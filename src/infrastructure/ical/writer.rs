@@ -0,0 +1,600 @@
+use std::fmt::Display;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use crate::domain::entities::task::task_occurrence::OccurrenceStatus;
+use crate::domain::entities::task::{Periodicity, PeriodicityExceptions, RRuleExportError, Task, TaskOccurrence, TaskPriority, TaskStatus};
+use crate::domain::entities::user::User;
+
+// ========================================================================
+// ICS WRITER
+// Tasks as RFC 5545 VTODOs, with the completion state of their occurrences
+// ========================================================================
+//
+// One VTODO per task:
+// - DTSTART is the first matching day, at `not_before` (else the user's
+//   day_start), in the user's timezone (TZID, no VTIMEZONE: IANA names are
+//   resolved by calendar apps, RFC 7809)
+// - RRULE from `Periodicity::to_rrule_for`; periodicities RRULE can't express are
+//   written as RDATEs for a year on each side of the export
+// - Exceptions (explicit dates and holidays) as EXDATEs of the RRULE instances
+// - Completion-relative periodicities have no recurrence set: DTSTART is the
+//   next due day (from the last completed stored occurrence), nothing repeats
+// - DURATION from `OccurrenceTimingSettings::duration`
+// Stored occurrences with completed reps add an override VTODO for their
+// instance (RECURRENCE-ID): STATUS, PERCENT-COMPLETE and COMPLETED.
+
+/// Identifies tasks in the calendar ("UID:task-12@tsadaash")
+const UID_DOMAIN: &str = "tsadaash";
+
+/// Expansion on each side of the export time, for periodicities without RRULE
+const RDATE_HORIZON_DAYS: i64 = 366;
+
+/// A task to write, with its stored occurrences
+///
+/// `key` identifies the task for the caller (e.g. a database id): it becomes
+/// the UID, so that re-exports update the same calendar entries.
+#[derive(Debug, Clone)]
+pub struct CalendarTask<'a, K> {
+    pub key: K,
+    pub task: &'a Task,
+    pub occurrences: Vec<TaskOccurrence>,
+}
+
+/// A written calendar
+#[derive(Debug, Clone)]
+pub struct IcsCalendar<K> {
+    /// The VCALENDAR, with CRLF line endings
+    pub text: String,
+
+    /// Tasks written as RDATEs because RRULE can't express their periodicity
+    pub expanded: Vec<(K, RRuleExportError)>,
+}
+
+/// Writes `tasks` as a VCALENDAR of VTODOs, in the user's calendar
+///
+/// `stamp` is the export time (DTSTAMP). Archived tasks are CANCELLED; active
+/// and paused ones NEEDS-ACTION (pick which tasks to export beforehand).
+pub fn write_calendar<K: Display + Clone>(
+    tasks: &[CalendarTask<K>],
+    user: &User,
+    stamp: DateTime<Utc>,
+) -> IcsCalendar<K> {
+    let mut lines = ContentLines::default();
+    lines.push("BEGIN", "VCALENDAR");
+    lines.push("VERSION", "2.0");
+    lines.push("PRODID", "-//tsadaash//tsadaash//EN");
+
+    let mut expanded = Vec::new();
+    for entry in tasks {
+        let result = write_task(&mut lines, entry, user, stamp);
+        // One-off dates are RDATE material anyway, and completion-relative
+        // tasks only have a next due day
+        let periodicity = entry.task.periodicity();
+        if let (Err(error), None, None) = (result, &periodicity.special_pattern, periodicity.after_completion) {
+            expanded.push((entry.key.clone(), error));
+        }
+    }
+
+    lines.push("END", "VCALENDAR");
+    IcsCalendar { text: lines.text, expanded }
+}
+
+/// Writes the VTODO of a task and its completion overrides
+///
+/// Returns why the periodicity had to be written as RDATEs, if it did.
+fn write_task<K: Display>(
+    lines: &mut ContentLines,
+    entry: &CalendarTask<K>,
+    user: &User,
+    stamp: DateTime<Utc>,
+) -> Result<(), RRuleExportError> {
+    let task = entry.task;
+    let uid = format!("task-{}@{}", entry.key, UID_DOMAIN);
+    let instances = Instances::new(task, user);
+
+    // Anchored like the occurrence generator
+    let mut periodicity = task.periodicity().clone();
+    if periodicity.reference_date.is_none() && periodicity.timeframe.is_none() {
+        periodicity.reference_date = Some(task.created_at());
+    }
+    // Exceptions are EXDATEs beside the rule; a roll policy stays, so that
+    // moved occurrences fall back to RDATEs
+    let unexcepted = Periodicity {
+        exceptions: PeriodicityExceptions { roll: periodicity.exceptions.roll, ..Default::default() },
+        ..periodicity.clone()
+    };
+    let rule = unexcepted.to_rrule_for(user);
+
+    // ── Recurrence set ──────────────────────────────────────
+    let (days, rrule, exdates) = match &rule {
+        Ok(rule) => {
+            let from = rule.dtstart.unwrap_or(task.created_at());
+            let first = unexcepted.occurrences_between_for(from, far_future(), user).next();
+            let days: Vec<_> = first.map(|day| user.logical_date(&day)).into_iter().collect();
+            let exdates = Instances::excluded_days(&periodicity.exceptions, &unexcepted, user, days.first().copied());
+            (days, Some(rule.rrule.as_str()), exdates)
+        }
+        Err(_) if periodicity.after_completion.is_some() => {
+            let last_completed = entry.occurrences.iter()
+                .filter(|o| o.is_completed())
+                .filter_map(|o| o.last_completed_at())
+                .max();
+            let due = periodicity.next_due_for(last_completed.as_ref(), user);
+            (due.map(|day| user.logical_date(&day)).into_iter().collect(), None, Vec::new())
+        }
+        Err(_) => (Instances::expanded_days(&periodicity, user, stamp), None, Vec::new()),
+    };
+
+    lines.push("BEGIN", "VTODO");
+    lines.push("UID", &uid);
+    lines.push("DTSTAMP", &format_utc(&stamp));
+    lines.push("CREATED", &format_utc(&task.created_at()));
+    lines.push("LAST-MODIFIED", &format_utc(&task.updated_at()));
+    lines.push("SUMMARY", &escape_text(task.title()));
+    if let Some(description) = task.description() {
+        lines.push("DESCRIPTION", &escape_text(description));
+    }
+    lines.push("PRIORITY", priority(task.priority()));
+    let status = if task.status() == TaskStatus::Archived { "CANCELLED" } else { "NEEDS-ACTION" };
+    lines.push("STATUS", status);
+
+    if let Some((first, rest)) = days.split_first() {
+        instances.push_start(lines, "DTSTART", *first);
+        if let Some(duration) = &instances.duration {
+            lines.push("DURATION", duration);
+        }
+        if let Some(rrule) = rrule {
+            lines.push("RRULE", rrule);
+            if !exdates.is_empty() {
+                let dates: Vec<String> = exdates.iter().map(|day| instances.local(*day)).collect();
+                lines.push(&format!("EXDATE;TZID={}", instances.tzid), &dates.join(","));
+            }
+        } else if !rest.is_empty() {
+            let dates: Vec<String> = rest.iter().map(|day| instances.local(*day)).collect();
+            lines.push(&format!("RDATE;TZID={}", instances.tzid), &dates.join(","));
+        }
+    }
+    lines.push("END", "VTODO");
+
+    // ── Completion overrides ────────────────────────────────
+    // (past completions of a completion-relative task aren't instances)
+    if !days.is_empty() && periodicity.after_completion.is_none() {
+        for occurrence in &entry.occurrences {
+            if occurrence.status() == OccurrenceStatus::NotStarted {
+                continue;
+            }
+            let Some(day) = Instances::day_of(&periodicity, user, occurrence) else { continue };
+
+            lines.push("BEGIN", "VTODO");
+            lines.push("UID", &uid);
+            lines.push("DTSTAMP", &format_utc(&stamp));
+            instances.push_start(lines, "RECURRENCE-ID", day);
+            instances.push_start(lines, "DTSTART", day);
+            if let Some(duration) = &instances.duration {
+                lines.push("DURATION", duration);
+            }
+            lines.push("SUMMARY", &escape_text(task.title()));
+            match occurrence.last_completed_at() {
+                Some(completed) if occurrence.is_completed() => {
+                    lines.push("STATUS", "COMPLETED");
+                    lines.push("COMPLETED", &format_utc(&completed));
+                }
+                _ => lines.push("STATUS", "IN-PROCESS"),
+            }
+            lines.push("PERCENT-COMPLETE", &format!("{:.0}", occurrence.progress() * 100.0));
+            if let Some(notes) = occurrence.notes() {
+                lines.push("DESCRIPTION", &escape_text(notes));
+            }
+            lines.push("END", "VTODO");
+        }
+    }
+
+    rule.map(|_| ())
+}
+
+// ========================================================================
+// INSTANCES
+// ========================================================================
+
+/// How a task's instances are written in the user's calendar
+struct Instances {
+    tzid: String,
+    /// Local time of day instances start at, and days to add to the logical
+    /// day (1 when it is before the user's day_start)
+    start_time: (NaiveTime, i64),
+    /// DURATION value ("PT30M")
+    duration: Option<String>,
+}
+
+impl Instances {
+    fn new(task: &Task, user: &User) -> Self {
+        let settings = task.periodicity().occurrence_settings.as_ref();
+        let time = settings.and_then(|s| s.not_before).unwrap_or(user.day_start());
+        let next_day = if time < user.day_start() { 1 } else { 0 };
+        Self {
+            tzid: user.timezone().as_str().to_string(),
+            start_time: (time, next_day),
+            duration: settings.and_then(|s| s.duration).map(format_duration),
+        }
+    }
+
+    /// Local start of the instance on a logical day ("20260105T080000")
+    fn local(&self, day: NaiveDate) -> String {
+        let (time, next_day) = self.start_time;
+        let start: NaiveDateTime = (day + Duration::days(next_day)).and_time(time);
+        start.format("%Y%m%dT%H%M%S").to_string()
+    }
+
+    /// "DTSTART;TZID=Europe/Paris:20260105T080000"
+    fn push_start(&self, lines: &mut ContentLines, name: &str, day: NaiveDate) {
+        lines.push(&format!("{};TZID={}", name, self.tzid), &self.local(day));
+    }
+
+    /// Matching logical days around `stamp` (all of them for special patterns)
+    fn expanded_days(periodicity: &Periodicity, user: &User, stamp: DateTime<Utc>) -> Vec<NaiveDate> {
+        let (from, to) = match periodicity.special_pattern {
+            Some(_) => (far_past(), far_future()),
+            None => (
+                stamp - Duration::days(RDATE_HORIZON_DAYS),
+                stamp + Duration::days(RDATE_HORIZON_DAYS),
+            ),
+        };
+        let mut days: Vec<NaiveDate> = periodicity
+            .occurrences_between_for(from, to, user)
+            .map(|day| user.logical_date(&day))
+            .collect();
+        days.dedup();
+        days
+    }
+
+    /// Excluded days the rule would otherwise yield, from its first instance on
+    fn excluded_days(
+        exceptions: &PeriodicityExceptions,
+        rule: &Periodicity,
+        user: &User,
+        first: Option<NaiveDate>,
+    ) -> Vec<NaiveDate> {
+        let Some(first) = first else { return Vec::new() };
+        let holidays = exceptions.holidays.iter().flat_map(|calendar| calendar.dates.iter());
+        let mut days: Vec<NaiveDate> = exceptions.dates.iter()
+            .chain(holidays)
+            .copied()
+            .filter(|day| *day >= first)
+            .filter(|day| {
+                let start = user.day_start_instant(*day);
+                rule.is_within_timeframe(&start) && rule.matches_constraints_for(&start, user)
+            })
+            .collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// The instance an occurrence stands for: the first matching day of its window
+    fn day_of(periodicity: &Periodicity, user: &User, occurrence: &TaskOccurrence) -> Option<NaiveDate> {
+        let end = occurrence.window_end() + Duration::seconds(1);
+        periodicity
+            .occurrences_between_for(occurrence.window_start(), end, user)
+            .next()
+            .map(|day| user.logical_date(&day))
+    }
+}
+
+// ========================================================================
+// CONTENT LINES
+// ========================================================================
+
+/// iCalendar text: CRLF line endings, lines folded at 75 octets
+#[derive(Default)]
+struct ContentLines {
+    text: String,
+}
+
+impl ContentLines {
+    /// Maximum octets per line, without the CRLF
+    const MAX_OCTETS: usize = 75;
+
+    fn push(&mut self, name: &str, value: &str) {
+        let line = format!("{}:{}", name, value);
+        let mut width = 0;
+        for c in line.chars() {
+            if width + c.len_utf8() > Self::MAX_OCTETS {
+                // Continuation lines start with a space, which counts
+                self.text.push_str("\r\n ");
+                width = 1;
+            }
+            self.text.push(c);
+            width += c.len_utf8();
+        }
+        self.text.push_str("\r\n");
+    }
+}
+
+/// TEXT value: backslash, semicolon, comma and newline escaped
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+/// RFC 5545 PRIORITY: 1 is the highest, 9 the lowest
+fn priority(priority: TaskPriority) -> &'static str {
+    match priority {
+        TaskPriority::Urgent => "1",
+        TaskPriority::High => "3",
+        TaskPriority::Medium => "5",
+        TaskPriority::Low => "9",
+    }
+}
+
+/// Minutes as a DURATION value ("PT1H30M")
+fn format_duration(minutes: u16) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("PT{}M", minutes),
+        (hours, 0) => format!("PT{}H", hours),
+        (hours, minutes) => format!("PT{}H{}M", hours, minutes),
+    }
+}
+
+fn format_utc(date: &DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Bounds of the expansion, as for `Periodicity::next_after` / `previous_before`
+fn far_past() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap()
+}
+
+fn far_future() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2201, 1, 1, 0, 0, 0).unwrap()
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::builders::PeriodicityBuilder;
+    use crate::domain::entities::task::periodicity::{OccurrenceTimingSettings, RepetitionUnit};
+    use crate::domain::entities::task::OccurenceRep;
+    use crate::domain::entities::user::{PasswordHash, Timezone};
+    use chrono::Weekday;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap()
+    }
+
+    fn paris_user() -> User {
+        User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        ).unwrap()
+    }
+
+    fn task(title: &str, periodicity: Periodicity) -> Task {
+        let created = utc(2026, 1, 5, 12);
+        Task::with_timestamps(title.to_string(), periodicity, created, created).unwrap()
+    }
+
+    /// Unfolded content lines
+    fn lines(calendar: &IcsCalendar<i64>) -> Vec<String> {
+        calendar.text.replace("\r\n ", "").split("\r\n").map(String::from).collect()
+    }
+
+    #[test]
+    fn test_vtodo_with_rrule_duration_and_completion() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(2)
+            .on_weekdays(vec![Weekday::Tue, Weekday::Thu])
+            .with_occurrence_settings(OccurrenceTimingSettings {
+                duration: Some(90),
+                not_before: NaiveTime::from_hms_opt(8, 0, 0),
+                best_before: None,
+                rep_timing_settings: None,
+            })
+            .build()
+            .unwrap();
+        let created = utc(2026, 1, 5, 12);
+        let stretch = Task::restore(
+            "Stretch; then rest".to_string(), None, TaskStatus::Active, TaskPriority::High, periodicity, created, created,
+        ).unwrap();
+
+        // Tuesday Jan 6 in Paris: both reps done; Thursday Jan 8: one of two
+        let done_at = utc(2026, 1, 6, 9);
+        let done = TaskOccurrence::restore(
+            utc(2026, 1, 5, 23),
+            utc(2026, 1, 6, 23) - Duration::seconds(1),
+            vec![OccurenceRep::restore(0, Some(done_at), None).unwrap(), OccurenceRep::restore(1, Some(done_at), None).unwrap()],
+            None,
+        ).unwrap();
+        let mut half = TaskOccurrence::new(utc(2026, 1, 7, 23), utc(2026, 1, 8, 23) - Duration::seconds(1), 2).unwrap();
+        half.mark_rep_complete(0).unwrap();
+
+        let tasks = vec![CalendarTask { key: 7, task: &stretch, occurrences: vec![done, half] }];
+        let calendar = write_calendar(&tasks, &paris_user(), utc(2026, 2, 1, 0));
+        assert!(calendar.expanded.is_empty());
+        assert_eq!(
+            lines(&calendar),
+            vec![
+                "BEGIN:VCALENDAR",
+                "VERSION:2.0",
+                "PRODID:-//tsadaash//tsadaash//EN",
+                "BEGIN:VTODO",
+                "UID:task-7@tsadaash",
+                "DTSTAMP:20260201T000000Z",
+                "CREATED:20260105T120000Z",
+                "LAST-MODIFIED:20260105T120000Z",
+                "SUMMARY:Stretch\\; then rest",
+                "PRIORITY:3",
+                "STATUS:NEEDS-ACTION",
+                "DTSTART;TZID=Europe/Paris:20260106T080000",
+                "DURATION:PT1H30M",
+                "RRULE:FREQ=DAILY;BYDAY=TU,TH",
+                "END:VTODO",
+                "BEGIN:VTODO",
+                "UID:task-7@tsadaash",
+                "DTSTAMP:20260201T000000Z",
+                "RECURRENCE-ID;TZID=Europe/Paris:20260106T080000",
+                "DTSTART;TZID=Europe/Paris:20260106T080000",
+                "DURATION:PT1H30M",
+                "SUMMARY:Stretch\\; then rest",
+                "STATUS:COMPLETED",
+                "COMPLETED:20260106T090000Z",
+                "PERCENT-COMPLETE:100",
+                "END:VTODO",
+                "BEGIN:VTODO",
+                "UID:task-7@tsadaash",
+                "DTSTAMP:20260201T000000Z",
+                "RECURRENCE-ID;TZID=Europe/Paris:20260108T080000",
+                "DTSTART;TZID=Europe/Paris:20260108T080000",
+                "DURATION:PT1H30M",
+                "SUMMARY:Stretch\\; then rest",
+                "STATUS:IN-PROCESS",
+                "PERCENT-COMPLETE:50",
+                "END:VTODO",
+                "END:VCALENDAR",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rdates_for_unique_dates_and_unsupported_rules() {
+        let dentist = task("Dentist", Periodicity::unique(utc(2026, 3, 10, 0)).unwrap());
+        let first_week = task(
+            "Budget",
+            PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Mon]).on_weeks_of_month(vec![1]).build().unwrap(),
+        );
+        let tasks = vec![
+            CalendarTask { key: 1, task: &dentist, occurrences: Vec::new() },
+            CalendarTask { key: 2, task: &first_week, occurrences: Vec::new() },
+        ];
+        let calendar = write_calendar(&tasks, &paris_user(), utc(2026, 2, 1, 0));
+        let lines = lines(&calendar);
+
+        assert!(lines.contains(&"DTSTART;TZID=Europe/Paris:20260310T000000".to_string()));
+        assert!(!lines.iter().any(|line| line.starts_with("RRULE")));
+
+        // Only the rule RRULE can't express is reported
+        assert_eq!(calendar.expanded.len(), 1);
+        assert_eq!(calendar.expanded[0].0, 2);
+        let rdate = lines.iter().find(|line| line.starts_with("RDATE;TZID=Europe/Paris:")).unwrap();
+        assert!(rdate.contains("20260202T000000,20260302T000000"));
+    }
+
+    #[test]
+    fn test_exceptions_become_exdates() {
+        let day = |month, day| NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+        let thursdays = task(
+            "Choir",
+            PeriodicityBuilder::new()
+                .daily(1)
+                .on_weekdays(vec![Weekday::Thu])
+                .between(utc(2026, 1, 1, 0), utc(2026, 2, 1, 0))
+                // Only Jan 8 is an occurrence: Jan 9 isn't a Thursday and Feb 5 is after the end
+                .except(vec![day(1, 8), day(1, 9), day(2, 5)])
+                .build()
+                .unwrap(),
+        );
+        let tasks = vec![CalendarTask { key: 3, task: &thursdays, occurrences: Vec::new() }];
+        let calendar = write_calendar(&tasks, &paris_user(), utc(2026, 2, 1, 0));
+        let lines = lines(&calendar);
+
+        assert!(calendar.expanded.is_empty());
+        let rrule = lines.iter().position(|line| line.starts_with("RRULE:FREQ=DAILY;")).unwrap();
+        assert_eq!(lines[rrule + 1], "EXDATE;TZID=Europe/Paris:20260108T000000");
+        assert_eq!(lines.iter().filter(|line| line.starts_with("EXDATE")).count(), 1);
+
+        // Read back on the same calendar
+        let import = crate::infrastructure::ical::read_calendar(&calendar.text, &paris_user()).unwrap();
+        assert_eq!(import.tasks[0].task.periodicity().exceptions.dates, vec![day(1, 8)]);
+    }
+
+    #[test]
+    fn test_read_back_gives_the_same_days() {
+        let at_eight = OccurrenceTimingSettings {
+            duration: None,
+            not_before: NaiveTime::from_hms_opt(8, 0, 0),
+            best_before: None,
+            rep_timing_settings: None,
+        };
+        let tuesdays_thursdays = || PeriodicityBuilder::new().daily(1).on_weekdays(vec![Weekday::Tue, Weekday::Thu]);
+        // DTSTART;TZID=Europe/Paris:20260106T080000, and one between Paris days
+        let open = task("Stretch", tuesdays_thursdays().with_occurrence_settings(at_eight.clone()).build().unwrap());
+        let bounded = task(
+            "Choir",
+            tuesdays_thursdays()
+                .every_n_weeks(2)
+                .between(utc(2026, 1, 11, 23), utc(2026, 2, 28, 23))
+                .with_occurrence_settings(at_eight)
+                .build()
+                .unwrap(),
+        );
+        let tasks = vec![
+            CalendarTask { key: 5, task: &open, occurrences: Vec::new() },
+            CalendarTask { key: 6, task: &bounded, occurrences: Vec::new() },
+        ];
+        let user = paris_user();
+        let calendar = write_calendar(&tasks, &user, utc(2026, 2, 1, 0));
+        assert!(calendar.expanded.is_empty());
+        assert!(lines(&calendar).contains(&"DTSTART;TZID=Europe/Paris:20260106T080000".to_string()));
+
+        let import = crate::infrastructure::ical::read_calendar(&calendar.text, &user).unwrap();
+        assert_eq!(import.tasks.len(), 2);
+        // From Paris' Jan 6, the first day written
+        let (from, to) = (utc(2026, 1, 5, 23), utc(2026, 4, 1, 0));
+        for (written, read) in [&open, &bounded].into_iter().zip(&import.tasks) {
+            let expected: Vec<_> = written.periodicity().occurrences_between_for(from, to, &user).collect();
+            let actual: Vec<_> = read.task.periodicity().occurrences_between_for(from, to, &user).collect();
+            assert!(!expected.is_empty());
+            assert_eq!(actual, expected, "{}", written.title());
+        }
+    }
+
+    #[test]
+    fn test_completion_relative_task_starts_on_its_next_due_day() {
+        let watering = task(
+            "Water plants",
+            PeriodicityBuilder::new().daily(1).after_completion(4, RepetitionUnit::Day).build().unwrap(),
+        );
+        let watered = TaskOccurrence::restore(
+            utc(2026, 1, 9, 23),
+            utc(2026, 1, 10, 23) - Duration::seconds(1),
+            vec![OccurenceRep::restore(0, Some(utc(2026, 1, 10, 8)), None).unwrap()],
+            None,
+        ).unwrap();
+        let tasks = vec![CalendarTask { key: 4, task: &watering, occurrences: vec![watered] }];
+        let calendar = write_calendar(&tasks, &paris_user(), utc(2026, 2, 1, 0));
+        let lines = lines(&calendar);
+
+        assert!(calendar.expanded.is_empty());
+        assert!(lines.contains(&"DTSTART;TZID=Europe/Paris:20260114T000000".to_string()));
+        assert!(!lines.iter().any(|line| line.starts_with("RRULE") || line.starts_with("RDATE")));
+        assert!(!lines.iter().any(|line| line.starts_with("RECURRENCE-ID")));
+    }
+
+    #[test]
+    fn test_text_is_escaped_and_folded() {
+        assert_eq!(escape_text("a,b;c\\d\r\ne"), "a\\,b\\;c\\\\d\\ne");
+
+        let mut lines = ContentLines::default();
+        lines.push("DESCRIPTION", &"é".repeat(50));
+        let physical: Vec<&str> = lines.text.trim_end().split("\r\n").collect();
+        assert_eq!(physical.len(), 2);
+        assert!(physical.iter().all(|line| line.len() <= ContentLines::MAX_OCTETS));
+        assert!(physical[1].starts_with(' '));
+        assert_eq!(format!("{}{}", physical[0], &physical[1][1..]), format!("DESCRIPTION:{}", "é".repeat(50)));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod sqlite;
+pub mod ical;
+pub mod holidays;
@@ -0,0 +1,142 @@
+use chrono::{DateTime, Month, NaiveDate, NaiveTime, SecondsFormat, Utc, Weekday};
+use serde_json::{json, Value};
+use crate::domain::entities::task::{TaskPriority, TaskStatus};
+use crate::domain::entities::user::{GeoCoordinates, Location};
+
+// ========================================================================
+// COLUMN CONVERSIONS
+// Domain values <-> SQLite column values (decoding errors are plain strings,
+// wrapped into StoreError::Corrupt by the repositories)
+// ========================================================================
+
+// ── INSTANTS ────────────────────────────────────────────────
+
+pub fn datetime_to_sql(date: &DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+pub fn datetime_from_sql(text: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(text)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|e| format!("invalid instant '{}': {}", text, e))
+}
+
+pub fn optional_datetime_from_sql(text: Option<&str>) -> Result<Option<DateTime<Utc>>, String> {
+    text.map(datetime_from_sql).transpose()
+}
+
+// ── CALENDAR DAYS ───────────────────────────────────────────
+
+pub fn date_to_sql(date: &NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+pub fn date_from_sql(text: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").map_err(|e| format!("invalid date '{}': {}", text, e))
+}
+
+pub fn optional_date_from_sql(text: Option<&str>) -> Result<Option<NaiveDate>, String> {
+    text.map(date_from_sql).transpose()
+}
+
+// ── CALENDAR SETTINGS ───────────────────────────────────────
+
+pub fn weekday_to_sql(weekday: Weekday) -> i64 {
+    weekday.num_days_from_monday() as i64
+}
+
+pub fn weekday_from_sql(value: i64) -> Result<Weekday, String> {
+    u8::try_from(value)
+        .ok()
+        .and_then(|v| Weekday::try_from(v).ok())
+        .ok_or_else(|| format!("invalid weekday {}", value))
+}
+
+pub fn month_to_sql(month: Month) -> i64 {
+    month.number_from_month() as i64
+}
+
+pub fn month_from_sql(value: i64) -> Result<Month, String> {
+    u8::try_from(value)
+        .ok()
+        .and_then(|v| Month::try_from(v).ok())
+        .ok_or_else(|| format!("invalid month {}", value))
+}
+
+pub fn time_to_sql(time: &NaiveTime) -> String {
+    time.format("%H:%M:%S").to_string()
+}
+
+pub fn time_from_sql(text: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(text, "%H:%M:%S").map_err(|e| format!("invalid time '{}': {}", text, e))
+}
+
+// ── TASK ENUMS ──────────────────────────────────────────────
+
+pub fn status_to_sql(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Active => "active",
+        TaskStatus::Paused => "paused",
+        TaskStatus::Archived => "archived",
+    }
+}
+
+pub fn status_from_sql(text: &str) -> Result<TaskStatus, String> {
+    match text {
+        "active" => Ok(TaskStatus::Active),
+        "paused" => Ok(TaskStatus::Paused),
+        "archived" => Ok(TaskStatus::Archived),
+        other => Err(format!("invalid status '{}'", other)),
+    }
+}
+
+pub fn priority_to_sql(priority: TaskPriority) -> i64 {
+    priority as i64
+}
+
+pub fn priority_from_sql(value: i64) -> Result<TaskPriority, String> {
+    match value {
+        1 => Ok(TaskPriority::Low),
+        2 => Ok(TaskPriority::Medium),
+        3 => Ok(TaskPriority::High),
+        4 => Ok(TaskPriority::Urgent),
+        other => Err(format!("invalid priority {}", other)),
+    }
+}
+
+// ── LOCATIONS ───────────────────────────────────────────────
+
+pub fn locations_to_sql(locations: &[Location]) -> String {
+    let items: Vec<Value> = locations.iter()
+        .map(|l| json!({
+            "name": l.name(),
+            "city": l.city(),
+            "country": l.country(),
+            "latitude": l.geoloc().latitude(),
+            "longitude": l.geoloc().longitude(),
+        }))
+        .collect();
+    Value::Array(items).to_string()
+}
+
+pub fn locations_from_sql(text: &str) -> Result<Vec<Location>, String> {
+    let value: Value = serde_json::from_str(text).map_err(|e| format!("invalid locations JSON: {}", e))?;
+    let items = value.as_array().ok_or("locations JSON must be an array")?;
+
+    items.iter()
+        .map(|item| {
+            let text_field = |key: &str| item[key].as_str().map(str::to_string);
+            let number_field = |key: &str| item[key].as_f64().ok_or(format!("location {} must be a number", key));
+
+            let geoloc = GeoCoordinates::new(number_field("latitude")?, number_field("longitude")?)
+                .map_err(|e| e.to_string())?;
+            Location::new(
+                text_field("name"),
+                text_field("city").ok_or("location city must be a string")?,
+                text_field("country").ok_or("location country must be a string")?,
+                geoloc,
+            )
+            .map_err(|e| e.to_string())
+        })
+        .collect()
+}
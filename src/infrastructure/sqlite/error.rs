@@ -0,0 +1,59 @@
+use std::fmt;
+
+// ========================================================================
+// STORE ERRORS
+// ========================================================================
+
+#[derive(Debug)]
+pub enum StoreError {
+    /// Error reported by SQLite itself (I/O, constraint violation, ...)
+    Sqlite(rusqlite::Error),
+
+    /// No row with this id
+    NotFound { entity: &'static str, id: i64 },
+
+    /// A stored row can't be turned back into a valid domain value
+    Corrupt { entity: &'static str, id: i64, reason: String },
+
+    /// The database was written by a newer version of the application
+    SchemaTooNew { found: u32, supported: u32 },
+
+    /// A schema migration failed (and was rolled back)
+    Migration { version: u32, description: &'static str, source: rusqlite::Error },
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Sqlite(error) => write!(f, "Database error: {}", error),
+            StoreError::NotFound { entity, id } => write!(f, "No {} with id {}", entity, id),
+            StoreError::Corrupt { entity, id, reason } => {
+                write!(f, "Stored {} {} is invalid: {}", entity, id, reason)
+            }
+            StoreError::SchemaTooNew { found, supported } => write!(
+                f,
+                "Database schema version {} is newer than this application supports ({}); please upgrade",
+                found, supported
+            ),
+            StoreError::Migration { version, description, source } => {
+                write!(f, "Migration to schema version {} ({}) failed: {}", version, description, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StoreError::Sqlite(error) => Some(error),
+            StoreError::Migration { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<rusqlite::Error> for StoreError {
+    fn from(error: rusqlite::Error) -> Self {
+        StoreError::Sqlite(error)
+    }
+}
@@ -0,0 +1,63 @@
+-- Database at schema version 1, as written by the first SQLite release.
+-- Frozen: do not update when the schema changes.
+
+CREATE TABLE users (
+    id              INTEGER PRIMARY KEY,
+    username        TEXT NOT NULL UNIQUE,
+    email           TEXT NOT NULL,
+    password_hash   TEXT NOT NULL,
+    timezone        TEXT NOT NULL,
+    locations_json  TEXT,
+    week_start      INTEGER NOT NULL,
+    year_start      INTEGER NOT NULL,
+    day_start       TEXT NOT NULL
+);
+
+CREATE TABLE tasks (
+    id                INTEGER PRIMARY KEY,
+    user_id           INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title             TEXT NOT NULL,
+    description       TEXT,
+    status            TEXT NOT NULL,
+    priority          INTEGER NOT NULL,
+    periodicity_json  TEXT NOT NULL,
+    timeframe_start   TEXT,
+    timeframe_end     TEXT,
+    created_at        TEXT NOT NULL,
+    updated_at        TEXT NOT NULL
+);
+
+CREATE TABLE task_occurrences (
+    id            INTEGER PRIMARY KEY,
+    task_id       INTEGER NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    window_start  TEXT NOT NULL,
+    window_end    TEXT NOT NULL,
+    notes         TEXT,
+    UNIQUE (task_id, window_start)
+);
+
+CREATE TABLE occurrence_reps (
+    occurrence_id  INTEGER NOT NULL REFERENCES task_occurrences(id) ON DELETE CASCADE,
+    rep_index      INTEGER NOT NULL,
+    completed_at   TEXT,
+    notes          TEXT,
+    PRIMARY KEY (occurrence_id, rep_index)
+);
+
+INSERT INTO users (id, username, email, password_hash, timezone, locations_json, week_start, year_start, day_start)
+VALUES (1, 'alice', 'alice@example.com', 'password_hash', 'Europe/Paris', NULL, 0, 1, '00:00:00');
+
+INSERT INTO tasks (id, user_id, title, description, status, priority, periodicity_json,
+                   timeframe_start, timeframe_end, created_at, updated_at)
+VALUES (1, 1, 'Stretch', NULL, 'active', 2,
+        '{"version":1,"rep_unit":"day","rep_per_unit":2,"occurrence_settings":null,"constraints":{"day":{"type":"every_day"},"week":null,"month":null,"year":null},"timeframe":null,"special_pattern":null,"reference_date":null}',
+        NULL, NULL, '2026-01-01T00:00:00.000000000Z', '2026-01-01T00:00:00.000000000Z');
+
+INSERT INTO task_occurrences (id, task_id, window_start, window_end, notes)
+VALUES (1, 1, '2026-02-07T00:00:00.000000000Z', '2026-02-07T23:59:59.000000000Z', NULL);
+
+INSERT INTO occurrence_reps (occurrence_id, rep_index, completed_at, notes)
+VALUES (1, 0, '2026-02-07T08:15:00.000000000Z', 'Morning'),
+       (1, 1, NULL, NULL);
+
+PRAGMA user_version = 1;
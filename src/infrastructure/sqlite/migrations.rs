@@ -0,0 +1,311 @@
+use rusqlite::{Connection, Transaction};
+use crate::domain::entities::user::{Password, PasswordHash};
+use super::StoreError;
+
+// ========================================================================
+// SCHEMA MIGRATIONS
+// Identities (id, user_id, task_id) live here, not in the domain entities
+// ========================================================================
+//
+// The schema version is SQLite's `PRAGMA user_version` (0 = empty database).
+// Each migration runs in its own transaction together with the version bump,
+// so a failed step leaves the database at the previous version.
+//
+// Conventions:
+// - Instants are RFC 3339 UTC text with nanoseconds ("2026-02-07T00:00:00.000000000Z"),
+//   fixed width so that text order is chronological order
+// - Calendar days are "YYYY-MM-DD" text
+// - Periodicity is stored as its versioned JSON representation; its timeframe
+//   is copied into columns for date-range queries
+// - Weekdays are 0-6 from Monday, months 1-12
+//
+// Steps are SQL, or Rust for data changes SQL can't express (hashing).
+//
+// Never edit a released migration: append a new one.
+
+/// One upgrade step, from `version - 1` to `version`
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub version: u32,
+    pub description: &'static str,
+    pub step: MigrationStep,
+}
+
+/// What a migration runs, inside its transaction
+#[derive(Debug, Clone, Copy)]
+pub enum MigrationStep {
+    Sql(&'static str),
+    Rust(fn(&Transaction<'_>) -> rusqlite::Result<()>),
+}
+
+/// All migrations, in order (versions are 1, 2, 3, ...)
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "users, tasks, task_occurrences and occurrence_reps",
+        // IF NOT EXISTS: databases created before versioning have the tables but user_version 0
+        step: MigrationStep::Sql("
+CREATE TABLE IF NOT EXISTS users (
+    id              INTEGER PRIMARY KEY,
+    username        TEXT NOT NULL UNIQUE,
+    email           TEXT NOT NULL,
+    password_hash   TEXT NOT NULL,
+    timezone        TEXT NOT NULL,
+    locations_json  TEXT,
+    week_start      INTEGER NOT NULL,
+    year_start      INTEGER NOT NULL,
+    day_start       TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS tasks (
+    id                INTEGER PRIMARY KEY,
+    user_id           INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title             TEXT NOT NULL,
+    description       TEXT,
+    status            TEXT NOT NULL,
+    priority          INTEGER NOT NULL,
+    periodicity_json  TEXT NOT NULL,
+    timeframe_start   TEXT,
+    timeframe_end     TEXT,
+    created_at        TEXT NOT NULL,
+    updated_at        TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS task_occurrences (
+    id            INTEGER PRIMARY KEY,
+    task_id       INTEGER NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    window_start  TEXT NOT NULL,
+    window_end    TEXT NOT NULL,
+    notes         TEXT,
+    UNIQUE (task_id, window_start)
+);
+
+CREATE TABLE IF NOT EXISTS occurrence_reps (
+    occurrence_id  INTEGER NOT NULL REFERENCES task_occurrences(id) ON DELETE CASCADE,
+    rep_index      INTEGER NOT NULL,
+    completed_at   TEXT,
+    notes          TEXT,
+    PRIMARY KEY (occurrence_id, rep_index)
+);
+"),
+    },
+    Migration {
+        version: 2,
+        description: "indexes for per-user and date-range queries",
+        step: MigrationStep::Sql("
+CREATE INDEX idx_tasks_user_status ON tasks (user_id, status);
+CREATE INDEX idx_task_occurrences_window ON task_occurrences (window_start, window_end);
+"),
+    },
+    Migration {
+        version: 3,
+        description: "argon2-hash plaintext passwords stored in password_hash",
+        step: MigrationStep::Rust(hash_plaintext_passwords),
+    },
+    Migration {
+        version: 4,
+        description: "nominal_date of occurrences moved by a roll policy",
+        step: MigrationStep::Sql("
+ALTER TABLE task_occurrences ADD COLUMN nominal_date TEXT;
+"),
+    },
+];
+
+/// Schema version this binary writes
+pub const LATEST_VERSION: u32 = MIGRATIONS[MIGRATIONS.len() - 1].version;
+
+/// Current schema version of the database
+pub fn schema_version(conn: &Connection) -> Result<u32, StoreError> {
+    Ok(conn.pragma_query_value(None, "user_version", |row| row.get(0))?)
+}
+
+/// Brings the database up to `LATEST_VERSION` and returns the version it started at
+///
+/// Fails with `StoreError::SchemaTooNew` if the database was written by a
+/// newer binary, without touching it.
+pub fn migrate(conn: &mut Connection) -> Result<u32, StoreError> {
+    apply(conn, MIGRATIONS)
+}
+
+fn apply(conn: &mut Connection, migrations: &[Migration]) -> Result<u32, StoreError> {
+    let initial = schema_version(conn)?;
+    let latest = migrations.last().map_or(0, |m| m.version);
+    if initial > latest {
+        return Err(StoreError::SchemaTooNew { found: initial, supported: latest });
+    }
+
+    for migration in migrations.iter().filter(|m| m.version > initial) {
+        let step = |conn: &mut Connection| -> rusqlite::Result<()> {
+            let tx = conn.transaction()?;
+            match migration.step {
+                MigrationStep::Sql(sql) => tx.execute_batch(sql)?,
+                MigrationStep::Rust(step) => step(&tx)?,
+            }
+            tx.pragma_update(None, "user_version", migration.version)?;
+            tx.commit()
+        };
+        step(conn).map_err(|source| StoreError::Migration {
+            version: migration.version,
+            description: migration.description,
+            source,
+        })?;
+    }
+
+    Ok(initial)
+}
+
+/// Version 3: rows written before passwords were hashed hold the password
+/// itself; replace it with its hash, so the same password still logs in
+fn hash_plaintext_passwords(tx: &Transaction<'_>) -> rusqlite::Result<()> {
+    let rows: Vec<(i64, String)> = tx
+        .prepare("SELECT id, password_hash FROM users")?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut update = tx.prepare("UPDATE users SET password_hash = ?1 WHERE id = ?2")?;
+    for (id, stored) in rows {
+        if PasswordHash::from_phc(stored.clone()).is_ok() {
+            continue;
+        }
+        // No length rules: the legacy password is kept as it was
+        let hash = Password::attempt(stored)
+            .hash()
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+        update.execute(rusqlite::params![hash.as_str(), id])?;
+    }
+    Ok(())
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use crate::infrastructure::sqlite::{TaskId, TaskOccurrenceRepository, TaskRepository, UserRepository};
+
+    /// A database as written by schema version 1, with one user, task and occurrence
+    const V1_FIXTURE: &str = include_str!("fixtures/v1.sql");
+
+    fn v1_database() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(V1_FIXTURE).unwrap();
+        conn
+    }
+
+    fn index_names(conn: &Connection) -> Vec<String> {
+        let mut stmt = conn
+            .prepare("SELECT name FROM sqlite_master WHERE type = 'index' AND name LIKE 'idx_%' ORDER BY name")
+            .unwrap();
+        stmt.query_map([], |row| row.get(0)).unwrap().collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn test_versions_are_sequential() {
+        for (i, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_migrate_empty_database() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        assert_eq!(migrate(&mut conn).unwrap(), 0);
+        assert_eq!(schema_version(&conn).unwrap(), LATEST_VERSION);
+
+        // Running again is a no-op
+        assert_eq!(migrate(&mut conn).unwrap(), LATEST_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v1_fixture_to_latest() {
+        let mut conn = v1_database();
+        assert_eq!(schema_version(&conn).unwrap(), 1);
+        assert!(index_names(&conn).is_empty());
+
+        assert_eq!(migrate(&mut conn).unwrap(), 1);
+        assert_eq!(schema_version(&conn).unwrap(), LATEST_VERSION);
+        assert_eq!(index_names(&conn), vec!["idx_task_occurrences_window", "idx_tasks_user_status"]);
+
+        // Fixture data is still readable through the repositories
+        let (user_id, user) = UserRepository::new(&conn).find_by_username("alice").unwrap().unwrap();
+        assert_eq!(user.timezone(), "Europe/Paris");
+        // The fixture's plaintext password was hashed, and still logs in
+        assert!(user.password_hash().verify(&Password::attempt("password_hash".to_string())));
+        assert!(!user.password_hash().verify(&Password::attempt("wrong".to_string())));
+
+        let tasks = TaskRepository::new(&conn).list_for_user(user_id).unwrap();
+        assert_eq!(tasks.len(), 1);
+        let (task_id, task) = &tasks[0];
+        assert_eq!(*task_id, TaskId(1));
+        assert_eq!(task.title(), "Stretch");
+        assert_eq!(task.periodicity().rep_per_unit, Some(2));
+
+        let occurrences = TaskOccurrenceRepository::new(&conn).list_for_task(*task_id).unwrap();
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].1.window_start(), Utc.with_ymd_and_hms(2026, 2, 7, 0, 0, 0).unwrap());
+        assert_eq!(occurrences[0].1.progress(), 0.5);
+    }
+
+    #[test]
+    fn test_hashed_passwords_are_kept() {
+        let mut conn = v1_database();
+        let phc = Password::attempt("correct horse battery staple".to_string()).hash().unwrap();
+        conn.execute("UPDATE users SET password_hash = ?1 WHERE id = 1", [phc.as_str()]).unwrap();
+
+        migrate(&mut conn).unwrap();
+        let (_, user) = UserRepository::new(&conn).find_by_username("alice").unwrap().unwrap();
+        assert_eq!(user.password_hash(), &phc);
+    }
+
+    #[test]
+    fn test_unversioned_database_with_tables() {
+        // Created before migrations existed: tables present, user_version 0
+        let mut conn = v1_database();
+        conn.pragma_update(None, "user_version", 0).unwrap();
+
+        migrate(&mut conn).unwrap();
+        assert_eq!(schema_version(&conn).unwrap(), LATEST_VERSION);
+        assert!(UserRepository::new(&conn).find_by_username("alice").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_newer_database_is_rejected() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", LATEST_VERSION + 1).unwrap();
+
+        match migrate(&mut conn) {
+            Err(StoreError::SchemaTooNew { found, supported }) => {
+                assert_eq!(found, LATEST_VERSION + 1);
+                assert_eq!(supported, LATEST_VERSION);
+            }
+            other => panic!("expected SchemaTooNew, got {:?}", other),
+        }
+        assert_eq!(schema_version(&conn).unwrap(), LATEST_VERSION + 1);
+    }
+
+    #[test]
+    fn test_failed_step_is_rolled_back() {
+        let migrations = [
+            MIGRATIONS[0],
+            Migration {
+                version: 2,
+                description: "broken",
+                step: MigrationStep::Sql("CREATE TABLE extra (id INTEGER); INSERT INTO missing_table VALUES (1);"),
+            },
+        ];
+        let mut conn = Connection::open_in_memory().unwrap();
+
+        let result = apply(&mut conn, &migrations);
+        assert!(matches!(result, Err(StoreError::Migration { version: 2, .. })));
+
+        // Version 1 was committed, version 2 left no trace
+        assert_eq!(schema_version(&conn).unwrap(), 1);
+        let extra: i64 = conn
+            .query_row("SELECT COUNT(*) FROM sqlite_master WHERE name = 'extra'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(extra, 0);
+    }
+}
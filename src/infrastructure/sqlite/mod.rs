@@ -0,0 +1,36 @@
+pub mod error;
+pub use error::StoreError;
+
+pub mod migrations;
+pub use migrations::{migrate, LATEST_VERSION};
+
+mod columns;
+
+pub mod user_repository;
+pub use user_repository::{UserId, UserRepository};
+
+pub mod task_repository;
+pub use task_repository::{TaskId, TaskRepository};
+
+pub mod task_occurrence_repository;
+pub use task_occurrence_repository::{OccurrenceId, TaskOccurrenceRepository};
+
+use std::path::Path;
+use rusqlite::Connection;
+
+/// Opens (or creates) the database file at `path`, migrated to the latest schema
+/// and ready for the repositories
+pub fn open(path: impl AsRef<Path>) -> Result<Connection, StoreError> {
+    prepare(Connection::open(path)?)
+}
+
+/// Opens a fresh in-memory database (tests, dry runs)
+pub fn open_in_memory() -> Result<Connection, StoreError> {
+    prepare(Connection::open_in_memory()?)
+}
+
+fn prepare(mut conn: Connection) -> Result<Connection, StoreError> {
+    conn.pragma_update(None, "foreign_keys", true)?;
+    migrate(&mut conn)?;
+    Ok(conn)
+}
@@ -0,0 +1,365 @@
+use std::fmt;
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use crate::domain::entities::task::{OccurenceRep, TaskOccurrence};
+use super::columns::*;
+use super::{StoreError, TaskId, UserId};
+
+// ========================================================================
+// OCCURRENCE IDENTITY
+// ========================================================================
+
+/// Database identity of a TaskOccurrence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OccurrenceId(pub i64);
+
+impl fmt::Display for OccurrenceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// ========================================================================
+// TASK OCCURRENCE REPOSITORY
+// ========================================================================
+
+/// Stores TaskOccurrences (`task_occurrences`) and their reps (`occurrence_reps`)
+///
+/// A task has at most one occurrence per window start.
+pub struct TaskOccurrenceRepository<'c> {
+    conn: &'c Connection,
+}
+
+const COLUMNS: &str = "o.id, o.task_id, o.window_start, o.window_end, o.notes, o.nominal_date";
+
+impl<'c> TaskOccurrenceRepository<'c> {
+    pub fn new(conn: &'c Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Inserts a new occurrence (with its reps) for `task_id` and returns its id
+    /// Fails if the task already has an occurrence starting at the same instant
+    pub fn create(&self, task_id: TaskId, occurrence: &TaskOccurrence) -> Result<OccurrenceId, StoreError> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO task_occurrences (task_id, window_start, window_end, notes, nominal_date)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                task_id.0,
+                datetime_to_sql(&occurrence.window_start()),
+                datetime_to_sql(&occurrence.window_end()),
+                occurrence.notes(),
+                occurrence.nominal_date().as_ref().map(date_to_sql),
+            ],
+        )?;
+        let id = OccurrenceId(tx.last_insert_rowid());
+        insert_reps(&tx, id, occurrence)?;
+        tx.commit()?;
+        Ok(id)
+    }
+
+    pub fn find(&self, id: OccurrenceId) -> Result<Option<(TaskId, TaskOccurrence)>, StoreError> {
+        let sql = format!("SELECT {} FROM task_occurrences o WHERE o.id = ?1", COLUMNS);
+        let row = self.conn.query_row(&sql, [id.0], OccurrenceRow::read).optional()?;
+        row.map(|r| self.load(r).map(|(_, task_id, occurrence)| (task_id, occurrence))).transpose()
+    }
+
+    /// The occurrence of `task_id` whose window starts at `window_start`
+    pub fn find_by_window(
+        &self,
+        task_id: TaskId,
+        window_start: DateTime<Utc>,
+    ) -> Result<Option<(OccurrenceId, TaskOccurrence)>, StoreError> {
+        let sql = format!(
+            "SELECT {} FROM task_occurrences o WHERE o.task_id = ?1 AND o.window_start = ?2",
+            COLUMNS
+        );
+        let row = self.conn
+            .query_row(&sql, params![task_id.0, datetime_to_sql(&window_start)], OccurrenceRow::read)
+            .optional()?;
+        row.map(|r| self.load(r).map(|(id, _, occurrence)| (id, occurrence))).transpose()
+    }
+
+    /// All occurrences of a task, in window order
+    pub fn list_for_task(&self, task_id: TaskId) -> Result<Vec<(OccurrenceId, TaskOccurrence)>, StoreError> {
+        let sql = format!(
+            "SELECT {} FROM task_occurrences o WHERE o.task_id = ?1 ORDER BY o.window_start",
+            COLUMNS
+        );
+        let occurrences = self.query(&sql, params![task_id.0])?;
+        Ok(occurrences.into_iter().map(|(id, _, occurrence)| (id, occurrence)).collect())
+    }
+
+    /// Occurrences of all the user's tasks whose window overlaps `[from, to)`,
+    /// in window order
+    pub fn list_between(
+        &self,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<(OccurrenceId, TaskId, TaskOccurrence)>, StoreError> {
+        let sql = format!(
+            "SELECT {} FROM task_occurrences o
+             JOIN tasks t ON t.id = o.task_id
+             WHERE t.user_id = ?1 AND o.window_start < ?3 AND o.window_end >= ?2
+             ORDER BY o.window_start, o.task_id",
+            COLUMNS
+        );
+        self.query(&sql, params![user_id.0, datetime_to_sql(&from), datetime_to_sql(&to)])
+    }
+
+    /// Replaces the stored occurrence (window, nominal date, notes and all reps)
+    pub fn update(&self, id: OccurrenceId, occurrence: &TaskOccurrence) -> Result<(), StoreError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let changed = tx.execute(
+            "UPDATE task_occurrences SET window_start = ?1, window_end = ?2, notes = ?3, nominal_date = ?4 WHERE id = ?5",
+            params![
+                datetime_to_sql(&occurrence.window_start()),
+                datetime_to_sql(&occurrence.window_end()),
+                occurrence.notes(),
+                occurrence.nominal_date().as_ref().map(date_to_sql),
+                id.0,
+            ],
+        )?;
+        if changed == 0 {
+            return Err(StoreError::NotFound { entity: "occurrence", id: id.0 });
+        }
+        tx.execute("DELETE FROM occurrence_reps WHERE occurrence_id = ?1", [id.0])?;
+        insert_reps(&tx, id, occurrence)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn delete(&self, id: OccurrenceId) -> Result<(), StoreError> {
+        let changed = self.conn.execute("DELETE FROM task_occurrences WHERE id = ?1", [id.0])?;
+        if changed == 0 {
+            return Err(StoreError::NotFound { entity: "occurrence", id: id.0 });
+        }
+        Ok(())
+    }
+
+    fn query(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> Result<Vec<(OccurrenceId, TaskId, TaskOccurrence)>, StoreError> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let rows = stmt.query_map(params, OccurrenceRow::read)?.collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter().map(|row| self.load(row)).collect()
+    }
+
+    /// Loads the reps of a row and rebuilds the occurrence
+    fn load(&self, row: OccurrenceRow) -> Result<(OccurrenceId, TaskId, TaskOccurrence), StoreError> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT rep_index, completed_at, notes FROM occurrence_reps
+             WHERE occurrence_id = ?1 ORDER BY rep_index",
+        )?;
+        let reps = stmt
+            .query_map([row.id], |r| Ok((r.get::<_, i64>(0)?, r.get::<_, Option<String>>(1)?, r.get(2)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        row.into_occurrence(reps)
+    }
+}
+
+fn insert_reps(conn: &Connection, id: OccurrenceId, occurrence: &TaskOccurrence) -> Result<(), StoreError> {
+    let mut stmt = conn.prepare_cached(
+        "INSERT INTO occurrence_reps (occurrence_id, rep_index, completed_at, notes) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for rep in occurrence.repetitions() {
+        stmt.execute(params![
+            id.0,
+            rep.rep_index(),
+            rep.completed_at().as_ref().map(datetime_to_sql),
+            rep.notes(),
+        ])?;
+    }
+    Ok(())
+}
+
+// ── ROW MAPPING ─────────────────────────────────────────────
+
+/// Raw `task_occurrences` row, before domain validation
+struct OccurrenceRow {
+    id: i64,
+    task_id: i64,
+    window_start: String,
+    window_end: String,
+    notes: Option<String>,
+    nominal_date: Option<String>,
+}
+
+/// Raw `occurrence_reps` row: (rep_index, completed_at, notes)
+type RepRow = (i64, Option<String>, Option<String>);
+
+impl OccurrenceRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            task_id: row.get(1)?,
+            window_start: row.get(2)?,
+            window_end: row.get(3)?,
+            notes: row.get(4)?,
+            nominal_date: row.get(5)?,
+        })
+    }
+
+    fn into_occurrence(self, reps: Vec<RepRow>) -> Result<(OccurrenceId, TaskId, TaskOccurrence), StoreError> {
+        let id = self.id;
+        let corrupt = |reason: String| StoreError::Corrupt { entity: "occurrence", id, reason };
+
+        let repetitions = reps.into_iter()
+            .map(|(index, completed_at, notes)| {
+                let index = u8::try_from(index).map_err(|_| format!("invalid rep index {}", index))?;
+                let completed_at = optional_datetime_from_sql(completed_at.as_deref())?;
+                OccurenceRep::restore(index, completed_at, notes).map_err(|e| e.to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(corrupt)?;
+
+        let mut occurrence = TaskOccurrence::restore(
+            datetime_from_sql(&self.window_start).map_err(corrupt)?,
+            datetime_from_sql(&self.window_end).map_err(corrupt)?,
+            repetitions,
+            self.notes,
+        )
+        .map_err(|e| corrupt(e.to_string()))?;
+        occurrence.set_nominal_date(optional_date_from_sql(self.nominal_date.as_deref()).map_err(corrupt)?);
+        Ok((OccurrenceId(id), TaskId(self.task_id), occurrence))
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::domain::entities::task::{Periodicity, Task};
+    use crate::domain::entities::user::{PasswordHash, Timezone, User};
+    use crate::infrastructure::sqlite::{open_in_memory, TaskRepository, UserRepository};
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap()
+    }
+
+    fn day(year: i32, month: u32, day: u32) -> TaskOccurrence {
+        TaskOccurrence::new(utc(year, month, day, 0), utc(year, month, day, 23) + chrono::Duration::seconds(3599), 2).unwrap()
+    }
+
+    /// Creates a user with one daily task
+    fn setup(conn: &Connection) -> (UserId, TaskId) {
+        let user = User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        ).unwrap();
+        let user_id = UserRepository::new(conn).create(&user).unwrap();
+        let task = Task::new("Stretch".to_string(), Periodicity::daily().unwrap()).unwrap();
+        let task_id = TaskRepository::new(conn).create(user_id, &task).unwrap();
+        (user_id, task_id)
+    }
+
+    #[test]
+    fn test_create_and_find_with_reps() {
+        let conn = open_in_memory().unwrap();
+        let (_, task_id) = setup(&conn);
+        let repo = TaskOccurrenceRepository::new(&conn);
+
+        let mut occurrence = day(2026, 2, 7);
+        occurrence.mark_rep_complete(1).unwrap();
+        occurrence.set_rep_notes(1, Some("Evening".to_string())).unwrap();
+        occurrence.set_notes(Some("Good day".to_string())).unwrap();
+
+        let id = repo.create(task_id, &occurrence).unwrap();
+        assert_eq!(repo.find(id).unwrap(), Some((task_id, occurrence.clone())));
+        assert_eq!(repo.find_by_window(task_id, utc(2026, 2, 7, 0)).unwrap(), Some((id, occurrence)));
+        assert_eq!(repo.find_by_window(task_id, utc(2026, 2, 8, 0)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_one_occurrence_per_window() {
+        let conn = open_in_memory().unwrap();
+        let (_, task_id) = setup(&conn);
+        let repo = TaskOccurrenceRepository::new(&conn);
+
+        repo.create(task_id, &day(2026, 2, 7)).unwrap();
+        assert!(matches!(repo.create(task_id, &day(2026, 2, 7)), Err(StoreError::Sqlite(_))));
+    }
+
+    #[test]
+    fn test_update_replaces_reps() {
+        let conn = open_in_memory().unwrap();
+        let (_, task_id) = setup(&conn);
+        let repo = TaskOccurrenceRepository::new(&conn);
+        let id = repo.create(task_id, &day(2026, 2, 7)).unwrap();
+
+        let (_, mut occurrence) = repo.find(id).unwrap().unwrap();
+        occurrence.mark_all_complete();
+        repo.update(id, &occurrence).unwrap();
+
+        let (_, stored) = repo.find(id).unwrap().unwrap();
+        assert!(stored.is_completed());
+        assert_eq!(stored, occurrence);
+    }
+
+    #[test]
+    fn test_nominal_date_round_trip() {
+        let conn = open_in_memory().unwrap();
+        let (_, task_id) = setup(&conn);
+        let repo = TaskOccurrenceRepository::new(&conn);
+
+        // Friday Jul 31, moved from Saturday Aug 1
+        let mut occurrence = day(2026, 7, 31);
+        occurrence.set_nominal_date(chrono::NaiveDate::from_ymd_opt(2026, 8, 1));
+        let id = repo.create(task_id, &occurrence).unwrap();
+        assert_eq!(repo.find(id).unwrap(), Some((task_id, occurrence.clone())));
+
+        occurrence.set_nominal_date(None);
+        repo.update(id, &occurrence).unwrap();
+        assert_eq!(repo.find(id).unwrap().unwrap().1.nominal_date(), None);
+    }
+
+    #[test]
+    fn test_list_for_task_and_between() {
+        let conn = open_in_memory().unwrap();
+        let (user_id, task_id) = setup(&conn);
+        let repo = TaskOccurrenceRepository::new(&conn);
+
+        let feb8 = repo.create(task_id, &day(2026, 2, 8)).unwrap();
+        let feb7 = repo.create(task_id, &day(2026, 2, 7)).unwrap();
+        let feb9 = repo.create(task_id, &day(2026, 2, 9)).unwrap();
+
+        let ids: Vec<_> = repo.list_for_task(task_id).unwrap().into_iter().map(|(id, _)| id).collect();
+        assert_eq!(ids, vec![feb7, feb8, feb9]);
+
+        // Windows overlapping [Feb 7 12:00, Feb 9 00:00)
+        let between: Vec<_> = repo
+            .list_between(user_id, utc(2026, 2, 7, 12), utc(2026, 2, 9, 0))
+            .unwrap()
+            .into_iter()
+            .map(|(id, task, _)| (id, task))
+            .collect();
+        assert_eq!(between, vec![(feb7, task_id), (feb8, task_id)]);
+    }
+
+    #[test]
+    fn test_delete_cascades_from_task() {
+        let conn = open_in_memory().unwrap();
+        let (_, task_id) = setup(&conn);
+        let repo = TaskOccurrenceRepository::new(&conn);
+        let kept = repo.create(task_id, &day(2026, 2, 7)).unwrap();
+        let deleted = repo.create(task_id, &day(2026, 2, 8)).unwrap();
+
+        repo.delete(deleted).unwrap();
+        assert_eq!(repo.find(deleted).unwrap(), None);
+        assert!(matches!(repo.delete(deleted), Err(StoreError::NotFound { .. })));
+
+        TaskRepository::new(&conn).delete(task_id).unwrap();
+        assert_eq!(repo.find(kept).unwrap(), None);
+        let reps: i64 = conn.query_row("SELECT COUNT(*) FROM occurrence_reps", [], |r| r.get(0)).unwrap();
+        assert_eq!(reps, 0);
+    }
+}
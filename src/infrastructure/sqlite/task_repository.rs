@@ -0,0 +1,375 @@
+use std::fmt;
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use crate::domain::entities::task::periodicity::{PeriodicityExceptions, RollPolicy};
+use crate::domain::entities::task::{Periodicity, Task, TaskStatus};
+use super::columns::*;
+use super::{StoreError, UserId};
+
+// ========================================================================
+// TASK IDENTITY
+// ========================================================================
+
+/// Database identity of a Task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaskId(pub i64);
+
+impl fmt::Display for TaskId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// ========================================================================
+// TASK REPOSITORY
+// ========================================================================
+
+/// Stores Tasks in the `tasks` table, each owned by a user
+pub struct TaskRepository<'c> {
+    conn: &'c Connection,
+}
+
+const COLUMNS: &str =
+    "id, title, description, status, priority, periodicity_json, created_at, updated_at";
+
+impl<'c> TaskRepository<'c> {
+    pub fn new(conn: &'c Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Inserts a new task for `user_id` and returns its id
+    pub fn create(&self, user_id: UserId, task: &Task) -> Result<TaskId, StoreError> {
+        let (timeframe_start, timeframe_end) = timeframe_columns(task.periodicity());
+        self.conn.execute(
+            "INSERT INTO tasks (user_id, title, description, status, priority, periodicity_json,
+                 timeframe_start, timeframe_end, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                user_id.0,
+                task.title(),
+                task.description(),
+                status_to_sql(task.status()),
+                priority_to_sql(task.priority()),
+                task.periodicity().to_json_string(),
+                timeframe_start,
+                timeframe_end,
+                datetime_to_sql(&task.created_at()),
+                datetime_to_sql(&task.updated_at()),
+            ],
+        )?;
+        Ok(TaskId(self.conn.last_insert_rowid()))
+    }
+
+    pub fn find(&self, id: TaskId) -> Result<Option<Task>, StoreError> {
+        let sql = format!("SELECT {} FROM tasks WHERE id = ?1", COLUMNS);
+        let row = self.conn.query_row(&sql, [id.0], TaskRow::read).optional()?;
+        row.map(|r| r.into_task().map(|(_, task)| task)).transpose()
+    }
+
+    /// Returns the user owning a task
+    pub fn owner(&self, id: TaskId) -> Result<Option<UserId>, StoreError> {
+        let owner = self.conn
+            .query_row("SELECT user_id FROM tasks WHERE id = ?1", [id.0], |row| row.get(0))
+            .optional()?;
+        Ok(owner.map(UserId))
+    }
+
+    /// All tasks of a user, oldest first
+    pub fn list_for_user(&self, user_id: UserId) -> Result<Vec<(TaskId, Task)>, StoreError> {
+        let sql = format!("SELECT {} FROM tasks WHERE user_id = ?1 ORDER BY created_at, id", COLUMNS);
+        self.query(&sql, params![user_id.0])
+    }
+
+    /// Active tasks of a user whose timeframe overlaps `[from, to)`
+    ///
+    /// Tasks without timeframe are always included, and a roll policy widens
+    /// the timeframe by the days it can move occurrences. Constraints are not
+    /// evaluated: expand the periodicity to know the actual dates.
+    pub fn list_active_between(
+        &self,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<(TaskId, Task)>, StoreError> {
+        let sql = format!(
+            "SELECT {} FROM tasks
+             WHERE user_id = ?1 AND status = ?2
+               AND (timeframe_start IS NULL OR timeframe_start < ?4)
+               AND (timeframe_end IS NULL OR timeframe_end > ?3)
+             ORDER BY created_at, id",
+            COLUMNS
+        );
+        // Roll policies aren't columns: widen the query, then check each task
+        let reach = Duration::days(PeriodicityExceptions::MAX_ROLL_DAYS);
+        let tasks = self.query(&sql, params![
+            user_id.0,
+            status_to_sql(TaskStatus::Active),
+            datetime_to_sql(&(from - reach)),
+            datetime_to_sql(&(to + reach)),
+        ])?;
+        Ok(tasks.into_iter().filter(|(_, task)| can_occur_between(task.periodicity(), from, to)).collect())
+    }
+
+    pub fn update(&self, id: TaskId, task: &Task) -> Result<(), StoreError> {
+        let (timeframe_start, timeframe_end) = timeframe_columns(task.periodicity());
+        let changed = self.conn.execute(
+            "UPDATE tasks SET title = ?1, description = ?2, status = ?3, priority = ?4,
+                 periodicity_json = ?5, timeframe_start = ?6, timeframe_end = ?7,
+                 created_at = ?8, updated_at = ?9
+             WHERE id = ?10",
+            params![
+                task.title(),
+                task.description(),
+                status_to_sql(task.status()),
+                priority_to_sql(task.priority()),
+                task.periodicity().to_json_string(),
+                timeframe_start,
+                timeframe_end,
+                datetime_to_sql(&task.created_at()),
+                datetime_to_sql(&task.updated_at()),
+                id.0,
+            ],
+        )?;
+        if changed == 0 {
+            return Err(StoreError::NotFound { entity: "task", id: id.0 });
+        }
+        Ok(())
+    }
+
+    /// Deletes the task with all its occurrences
+    pub fn delete(&self, id: TaskId) -> Result<(), StoreError> {
+        let changed = self.conn.execute("DELETE FROM tasks WHERE id = ?1", [id.0])?;
+        if changed == 0 {
+            return Err(StoreError::NotFound { entity: "task", id: id.0 });
+        }
+        Ok(())
+    }
+
+    fn query(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<(TaskId, Task)>, StoreError> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let rows = stmt.query_map(params, TaskRow::read)?.collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter().map(TaskRow::into_task).collect()
+    }
+}
+
+/// Timeframe copied into columns for range queries
+fn timeframe_columns(periodicity: &Periodicity) -> (Option<String>, Option<String>) {
+    match periodicity.timeframe {
+        Some((start, end)) => (Some(datetime_to_sql(&start)), Some(datetime_to_sql(&end))),
+        None => (None, None),
+    }
+}
+
+/// Whether the timeframe, widened by how far a roll policy moves
+/// occurrences, overlaps `[from, to)`
+fn can_occur_between(periodicity: &Periodicity, from: DateTime<Utc>, to: DateTime<Utc>) -> bool {
+    let Some((start, end)) = periodicity.timeframe else {
+        return true;
+    };
+    let reach = match periodicity.exceptions.roll {
+        RollPolicy::Skip => Duration::zero(),
+        _ => Duration::days(PeriodicityExceptions::MAX_ROLL_DAYS),
+    };
+    start - reach < to && end + reach > from
+}
+
+// ── ROW MAPPING ─────────────────────────────────────────────
+
+/// Raw `tasks` row, before domain validation
+struct TaskRow {
+    id: i64,
+    title: String,
+    description: Option<String>,
+    status: String,
+    priority: i64,
+    periodicity_json: String,
+    created_at: String,
+    updated_at: String,
+}
+
+impl TaskRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            title: row.get(1)?,
+            description: row.get(2)?,
+            status: row.get(3)?,
+            priority: row.get(4)?,
+            periodicity_json: row.get(5)?,
+            created_at: row.get(6)?,
+            updated_at: row.get(7)?,
+        })
+    }
+
+    fn into_task(self) -> Result<(TaskId, Task), StoreError> {
+        let id = self.id;
+        let corrupt = |reason: String| StoreError::Corrupt { entity: "task", id, reason };
+
+        let periodicity = Periodicity::from_json_str(&self.periodicity_json)
+            .map_err(|e| corrupt(e.to_string()))?;
+        let task = Task::restore(
+            self.title,
+            self.description,
+            status_from_sql(&self.status).map_err(corrupt)?,
+            priority_from_sql(self.priority).map_err(corrupt)?,
+            periodicity,
+            datetime_from_sql(&self.created_at).map_err(corrupt)?,
+            datetime_from_sql(&self.updated_at).map_err(corrupt)?,
+        )
+        .map_err(|e| corrupt(e.to_string()))?;
+        Ok((TaskId(id), task))
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Weekday};
+    use crate::domain::builders::PeriodicityBuilder;
+    use crate::domain::entities::task::TaskPriority;
+    use crate::domain::entities::user::{PasswordHash, Timezone, User};
+    use crate::infrastructure::sqlite::{open_in_memory, UserRepository};
+
+    fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn setup(conn: &Connection) -> UserId {
+        let user = User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            PasswordHash::locked(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        ).unwrap();
+        UserRepository::new(conn).create(&user).unwrap()
+    }
+
+    fn task(title: &str, periodicity: Periodicity) -> Task {
+        Task::with_timestamps(title.to_string(), periodicity, utc(2026, 1, 1), utc(2026, 1, 1)).unwrap()
+    }
+
+    #[test]
+    fn test_create_and_find_round_trip() {
+        let conn = open_in_memory().unwrap();
+        let user_id = setup(&conn);
+        let repo = TaskRepository::new(&conn);
+
+        let periodicity = PeriodicityBuilder::new()
+            .daily(2)
+            .on_weekdays(vec![Weekday::Mon, Weekday::Thu])
+            .between(utc(2026, 1, 1), utc(2026, 7, 1))
+            .build()
+            .unwrap();
+        let task = Task::restore(
+            "Stretch".to_string(),
+            Some("10 minutes".to_string()),
+            TaskStatus::Paused,
+            TaskPriority::High,
+            periodicity,
+            utc(2026, 1, 1),
+            utc(2026, 1, 2),
+        ).unwrap();
+
+        let id = repo.create(user_id, &task).unwrap();
+        assert_eq!(repo.find(id).unwrap(), Some(task));
+        assert_eq!(repo.owner(id).unwrap(), Some(user_id));
+    }
+
+    #[test]
+    fn test_list_for_user() {
+        let conn = open_in_memory().unwrap();
+        let user_id = setup(&conn);
+        let repo = TaskRepository::new(&conn);
+
+        let first = repo.create(user_id, &task("First", Periodicity::daily().unwrap())).unwrap();
+        let second = repo.create(user_id, &task("Second", Periodicity::weekly().unwrap())).unwrap();
+
+        let tasks = repo.list_for_user(user_id).unwrap();
+        assert_eq!(tasks.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![first, second]);
+        assert!(repo.list_for_user(UserId(user_id.0 + 1)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_list_active_between() {
+        let conn = open_in_memory().unwrap();
+        let user_id = setup(&conn);
+        let repo = TaskRepository::new(&conn);
+
+        let open_ended = repo.create(user_id, &task("Always", Periodicity::daily().unwrap())).unwrap();
+        let spring = repo.create(user_id, &task(
+            "Spring",
+            PeriodicityBuilder::new().daily(1).between(utc(2026, 3, 1), utc(2026, 6, 1)).build().unwrap(),
+        )).unwrap();
+        let mut paused = task("Paused", Periodicity::daily().unwrap());
+        paused.pause();
+        repo.create(user_id, &paused).unwrap();
+
+        let ids = |from, to| -> Vec<TaskId> {
+            repo.list_active_between(user_id, from, to).unwrap().into_iter().map(|(id, _)| id).collect()
+        };
+        assert_eq!(ids(utc(2026, 1, 1), utc(2026, 2, 1)), vec![open_ended]);
+        assert_eq!(ids(utc(2026, 5, 1), utc(2026, 7, 1)), vec![open_ended, spring]);
+        // Timeframe end is exclusive
+        assert_eq!(ids(utc(2026, 6, 1), utc(2026, 7, 1)), vec![open_ended]);
+    }
+
+    #[test]
+    fn test_rolled_occurrences_are_listed_past_the_timeframe() {
+        let conn = open_in_memory().unwrap();
+        let user_id = setup(&conn);
+        let repo = TaskRepository::new(&conn);
+
+        // Saturday Jan 31 moves to Monday Feb 2, after the timeframe
+        let month_end = |roll| {
+            PeriodicityBuilder::new()
+                .daily(1)
+                .on_month_days(vec![31])
+                .between(utc(2026, 1, 1), utc(2026, 2, 1))
+                .with_roll_policy(roll)
+                .build()
+                .unwrap()
+        };
+        let rolled = month_end(RollPolicy::NextBusinessDay);
+        assert_eq!(rolled.occurrences_between(utc(2026, 2, 2), utc(2026, 2, 3), Weekday::Mon).collect::<Vec<_>>(), vec![utc(2026, 2, 2)]);
+        let rent = repo.create(user_id, &task("Rent", rolled)).unwrap();
+        repo.create(user_id, &task("Skipped", month_end(RollPolicy::Skip))).unwrap();
+
+        let ids: Vec<TaskId> = repo.list_active_between(user_id, utc(2026, 2, 2), utc(2026, 2, 9)).unwrap()
+            .into_iter().map(|(id, _)| id).collect();
+        assert_eq!(ids, vec![rent]);
+        assert!(repo.list_active_between(user_id, utc(2026, 4, 1), utc(2026, 5, 1)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_update_and_delete() {
+        let conn = open_in_memory().unwrap();
+        let user_id = setup(&conn);
+        let repo = TaskRepository::new(&conn);
+        let id = repo.create(user_id, &task("Read", Periodicity::daily().unwrap())).unwrap();
+
+        let mut stored = repo.find(id).unwrap().unwrap();
+        stored.set_title("Read a chapter".to_string()).unwrap();
+        stored.archive();
+        repo.update(id, &stored).unwrap();
+        assert_eq!(repo.find(id).unwrap(), Some(stored));
+
+        repo.delete(id).unwrap();
+        assert_eq!(repo.find(id).unwrap(), None);
+        assert!(matches!(repo.update(id, &task("Gone", Periodicity::daily().unwrap())), Err(StoreError::NotFound { .. })));
+    }
+
+    #[test]
+    fn test_corrupt_periodicity_is_reported() {
+        let conn = open_in_memory().unwrap();
+        let user_id = setup(&conn);
+        let repo = TaskRepository::new(&conn);
+        let id = repo.create(user_id, &task("Read", Periodicity::daily().unwrap())).unwrap();
+
+        conn.execute("UPDATE tasks SET periodicity_json = '{}' WHERE id = ?1", [id.0]).unwrap();
+        assert!(matches!(repo.find(id), Err(StoreError::Corrupt { entity: "task", .. })));
+    }
+}
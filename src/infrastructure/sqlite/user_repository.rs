@@ -0,0 +1,267 @@
+use std::fmt;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use crate::domain::entities::user::{PasswordHash, Timezone, User};
+use super::columns::*;
+use super::StoreError;
+
+// ========================================================================
+// USER IDENTITY
+// ========================================================================
+
+/// Database identity of a User
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserId(pub i64);
+
+impl fmt::Display for UserId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// ========================================================================
+// USER REPOSITORY
+// ========================================================================
+
+/// Stores Users in the `users` table
+pub struct UserRepository<'c> {
+    conn: &'c Connection,
+}
+
+const COLUMNS: &str =
+    "id, username, email, password_hash, timezone, locations_json, week_start, year_start, day_start";
+
+impl<'c> UserRepository<'c> {
+    pub fn new(conn: &'c Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Inserts a new user and returns its id
+    /// Fails if the username is already taken
+    pub fn create(&self, user: &User) -> Result<UserId, StoreError> {
+        self.conn.execute(
+            "INSERT INTO users (username, email, password_hash, timezone, locations_json, week_start, year_start, day_start)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                user.username(),
+                user.email(),
+                user.password_hash().as_str(),
+                user.timezone().as_str(),
+                user.location().map(locations_to_sql),
+                weekday_to_sql(user.week_start()),
+                month_to_sql(user.year_start()),
+                time_to_sql(&user.day_start()),
+            ],
+        )?;
+        Ok(UserId(self.conn.last_insert_rowid()))
+    }
+
+    pub fn find(&self, id: UserId) -> Result<Option<User>, StoreError> {
+        let sql = format!("SELECT {} FROM users WHERE id = ?1", COLUMNS);
+        let row = self.conn.query_row(&sql, [id.0], UserRow::read).optional()?;
+        row.map(|r| r.into_user().map(|(_, user)| user)).transpose()
+    }
+
+    pub fn find_by_username(&self, username: &str) -> Result<Option<(UserId, User)>, StoreError> {
+        let sql = format!("SELECT {} FROM users WHERE username = ?1", COLUMNS);
+        let row = self.conn.query_row(&sql, [username], UserRow::read).optional()?;
+        row.map(UserRow::into_user).transpose()
+    }
+
+    pub fn list(&self) -> Result<Vec<(UserId, User)>, StoreError> {
+        let sql = format!("SELECT {} FROM users ORDER BY id", COLUMNS);
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map([], UserRow::read)?.collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter().map(UserRow::into_user).collect()
+    }
+
+    pub fn update(&self, id: UserId, user: &User) -> Result<(), StoreError> {
+        let changed = self.conn.execute(
+            "UPDATE users SET username = ?1, email = ?2, password_hash = ?3, timezone = ?4,
+                 locations_json = ?5, week_start = ?6, year_start = ?7, day_start = ?8
+             WHERE id = ?9",
+            params![
+                user.username(),
+                user.email(),
+                user.password_hash().as_str(),
+                user.timezone().as_str(),
+                user.location().map(locations_to_sql),
+                weekday_to_sql(user.week_start()),
+                month_to_sql(user.year_start()),
+                time_to_sql(&user.day_start()),
+                id.0,
+            ],
+        )?;
+        not_found_if_zero(changed, id)
+    }
+
+    /// Deletes the user with all their tasks and occurrences
+    pub fn delete(&self, id: UserId) -> Result<(), StoreError> {
+        let changed = self.conn.execute("DELETE FROM users WHERE id = ?1", [id.0])?;
+        not_found_if_zero(changed, id)
+    }
+}
+
+fn not_found_if_zero(changed: usize, id: UserId) -> Result<(), StoreError> {
+    if changed == 0 {
+        return Err(StoreError::NotFound { entity: "user", id: id.0 });
+    }
+    Ok(())
+}
+
+// ── ROW MAPPING ─────────────────────────────────────────────
+
+/// Raw `users` row, before domain validation
+struct UserRow {
+    id: i64,
+    username: String,
+    email: String,
+    password_hash: String,
+    timezone: String,
+    locations_json: Option<String>,
+    week_start: i64,
+    year_start: i64,
+    day_start: String,
+}
+
+impl UserRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            username: row.get(1)?,
+            email: row.get(2)?,
+            password_hash: row.get(3)?,
+            timezone: row.get(4)?,
+            locations_json: row.get(5)?,
+            week_start: row.get(6)?,
+            year_start: row.get(7)?,
+            day_start: row.get(8)?,
+        })
+    }
+
+    fn into_user(self) -> Result<(UserId, User), StoreError> {
+        let id = self.id;
+        let corrupt = |reason: String| StoreError::Corrupt { entity: "user", id, reason };
+
+        let password_hash = PasswordHash::from_phc(self.password_hash).map_err(|e| corrupt(e.to_string()))?;
+        let timezone = Timezone::new(self.timezone).map_err(|e| corrupt(e.to_string()))?;
+        let location = self.locations_json
+            .as_deref()
+            .map(locations_from_sql)
+            .transpose()
+            .map_err(corrupt)?;
+
+        let user = User::with_all_settings(
+            self.username,
+            self.email,
+            password_hash,
+            timezone,
+            location,
+            weekday_from_sql(self.week_start).map_err(corrupt)?,
+            month_from_sql(self.year_start).map_err(corrupt)?,
+            time_from_sql(&self.day_start).map_err(corrupt)?,
+        )
+        .map_err(|e| corrupt(e.to_string()))?;
+        Ok((UserId(id), user))
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Month, NaiveTime, Weekday};
+    use crate::domain::entities::user::{GeoCoordinates, Location};
+    use crate::infrastructure::sqlite::open_in_memory;
+
+    fn user(username: &str) -> User {
+        User::new(
+            username.to_string(),
+            format!("{}@example.com", username),
+            PasswordHash::locked(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_create_and_find() {
+        let conn = open_in_memory().unwrap();
+        let repo = UserRepository::new(&conn);
+
+        let mut alice = user("alice");
+        alice.set_week_start(Weekday::Sun);
+        alice.set_year_start(Month::April);
+        alice.set_day_start(NaiveTime::from_hms_opt(5, 0, 0).unwrap());
+        alice.set_location(Some(vec![Location::new(
+            Some("Home".to_string()),
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(48.8566, 2.3522).unwrap(),
+        ).unwrap()]));
+
+        let id = repo.create(&alice).unwrap();
+        let found = repo.find(id).unwrap().unwrap();
+
+        assert_eq!(found.username(), "alice");
+        assert_eq!(found.week_start(), Weekday::Sun);
+        assert_eq!(found.year_start(), Month::April);
+        assert_eq!(found.day_start(), alice.day_start());
+        assert_eq!(found.location(), alice.location());
+    }
+
+    #[test]
+    fn test_find_by_username_and_list() {
+        let conn = open_in_memory().unwrap();
+        let repo = UserRepository::new(&conn);
+        let alice = repo.create(&user("alice")).unwrap();
+        let bob = repo.create(&user("bob")).unwrap();
+
+        let (id, found) = repo.find_by_username("bob").unwrap().unwrap();
+        assert_eq!(id, bob);
+        assert_eq!(found.email(), "bob@example.com");
+        assert!(repo.find_by_username("carol").unwrap().is_none());
+
+        let ids: Vec<_> = repo.list().unwrap().into_iter().map(|(id, _)| id).collect();
+        assert_eq!(ids, vec![alice, bob]);
+    }
+
+    #[test]
+    fn test_username_is_unique() {
+        let conn = open_in_memory().unwrap();
+        let repo = UserRepository::new(&conn);
+        repo.create(&user("alice")).unwrap();
+
+        assert!(matches!(repo.create(&user("alice")), Err(StoreError::Sqlite(_))));
+    }
+
+    #[test]
+    fn test_update_and_delete() {
+        let conn = open_in_memory().unwrap();
+        let repo = UserRepository::new(&conn);
+        let id = repo.create(&user("alice")).unwrap();
+
+        let mut alice = repo.find(id).unwrap().unwrap();
+        alice.set_email("alice@example.org".to_string()).unwrap();
+        repo.update(id, &alice).unwrap();
+        assert_eq!(repo.find(id).unwrap().unwrap().email(), "alice@example.org");
+
+        repo.delete(id).unwrap();
+        assert!(repo.find(id).unwrap().is_none());
+        assert!(matches!(repo.delete(id), Err(StoreError::NotFound { entity: "user", .. })));
+    }
+
+    #[test]
+    fn test_unknown_timezone_is_reported() {
+        let conn = open_in_memory().unwrap();
+        let repo = UserRepository::new(&conn);
+        let id = repo.create(&user("alice")).unwrap();
+
+        conn.execute("UPDATE users SET timezone = 'Europe/Pari' WHERE id = ?1", [id.0]).unwrap();
+        match repo.find(id) {
+            Err(StoreError::Corrupt { entity: "user", reason, .. }) => assert!(reason.contains("did you mean 'Europe/Paris'")),
+            other => panic!("expected a corrupt user, got {:?}", other.map(|user| user.is_some())),
+        }
+    }
+}
@@ -1 +1,3 @@
-pub mod domain;
\ No newline at end of file
+pub mod domain;
+pub mod infrastructure;
+pub mod application;
\ No newline at end of file
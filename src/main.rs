@@ -1,5 +1,15 @@
-fn main() -> rusqlite::Result<()> {
+mod cli;
 
-    Ok(())
+use std::process::ExitCode;
+use cli::CliError;
 
+fn main() -> ExitCode {
+    match cli::run(std::env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Cancelled) => ExitCode::from(130),
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
 }